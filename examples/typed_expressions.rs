@@ -29,7 +29,7 @@ fn main() -> PolarsResult<()> {
     println!("Original DataFrame:");
     println!("{}\n", typed_df);
     
-    // Use lazy evaluation with type-safe expressions
+    // Use lazy evaluation with type-safe expressions; the query keeps the schema
     let lazy_df = typed_df.lazy();
     
     // Type-safe column reference
@@ -39,7 +39,7 @@ fn main() -> PolarsResult<()> {
     // Filter for Engineering department
     let engineering = lazy_df
         .clone()
-        .filter(dept_expr.clone().into_inner().eq(polars::prelude::lit("Engineering")))
+        .filter(dept_expr.clone().eq(typed_polars::expr::lit("Engineering".to_string())))
         .collect()?;
     
     println!("Engineering Department:");
@@ -47,7 +47,7 @@ fn main() -> PolarsResult<()> {
     
     // Calculate statistics
     let stats = lazy_df
-        .clone()
+        .into_inner()
         .group_by([EmployeeSchema::department.name()])
         .agg([
             salary_expr.clone().mean().alias("avg_salary").into_inner(),
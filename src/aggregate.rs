@@ -0,0 +1,262 @@
+//! Constant-memory aggregation over streams of typed batches.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::quantile::{TDigest, DEFAULT_COMPRESSION};
+use crate::stream::TypedBatchSource;
+use std::marker::PhantomData;
+
+/// Accumulates summary statistics of typed batches without keeping the batches.
+///
+/// Every schema column gets a value and null count; numeric columns also
+/// get a sum, minimum, maximum and a t-digest for approximate quantiles. The
+/// state is a fixed size per column, so aggregating an unbounded stream
+/// takes constant memory. Aggregators of separate partitions
+/// [`merge`](Self::merge) into one.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut aggregator = TypedAggregator::<EventSchema>::new();
+/// aggregator.update_from(&mut source)?;
+/// let summary = aggregator.finalize();
+/// println!("p95 latency: {:?}", summary.quantile(EventSchema::latency, 0.95));
+/// ```
+pub struct TypedAggregator<S: Schema> {
+    rows: u64,
+    columns: Vec<ColumnState>,
+    _phantom: PhantomData<S>,
+}
+
+#[derive(Clone, Debug)]
+struct ColumnState {
+    name: &'static str,
+    count: u64,
+    null_count: u64,
+    numeric: Option<NumericState>,
+}
+
+#[derive(Clone, Debug)]
+struct NumericState {
+    sum: f64,
+    min: f64,
+    max: f64,
+    digest: TDigest,
+}
+
+impl<S: Schema> TypedAggregator<S> {
+    /// An empty aggregator.
+    pub fn new() -> Self {
+        Self::with_compression(DEFAULT_COMPRESSION)
+    }
+    
+    /// An empty aggregator whose quantile digests use the given compression.
+    pub fn with_compression(compression: f64) -> Self {
+        let columns = S::column_names()
+            .into_iter()
+            .zip(S::schema().iter_values())
+            .map(|(name, dtype)| ColumnState {
+                name,
+                count: 0,
+                null_count: 0,
+                numeric: dtype.is_numeric().then(|| NumericState {
+                    sum: 0.0,
+                    min: f64::INFINITY,
+                    max: f64::NEG_INFINITY,
+                    digest: TDigest::new(compression),
+                }),
+            })
+            .collect();
+        Self {
+            rows: 0,
+            columns,
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// Fold a batch into the statistics.
+    pub fn update(&mut self, batch: &TypedDataFrame<S>) -> PolarsResult<()> {
+        for state in &mut self.columns {
+            let column = batch.inner().column(state.name)?;
+            let nulls = column.null_count() as u64;
+            state.null_count += nulls;
+            state.count += column.len() as u64 - nulls;
+            if let Some(numeric) = &mut state.numeric {
+                let values = column.as_materialized_series().cast(&DataType::Float64)?;
+                for value in values.f64()?.into_iter().flatten() {
+                    numeric.sum += value;
+                    numeric.min = numeric.min.min(value);
+                    numeric.max = numeric.max.max(value);
+                    numeric.digest.add(value);
+                }
+            }
+        }
+        self.rows += batch.height() as u64;
+        Ok(())
+    }
+    
+    /// Fold every remaining batch of a source into the statistics.
+    pub fn update_from<B: TypedBatchSource<Schema = S>>(&mut self, source: &mut B) -> PolarsResult<()> {
+        while let Some(batch) = source.next_batch() {
+            self.update(&batch?)?;
+        }
+        Ok(())
+    }
+    
+    /// Fold the statistics of another aggregator, e.g. of another partition, into this one.
+    pub fn merge(&mut self, other: &Self) {
+        self.rows += other.rows;
+        for (state, other) in self.columns.iter_mut().zip(&other.columns) {
+            state.count += other.count;
+            state.null_count += other.null_count;
+            if let (Some(numeric), Some(other)) = (&mut state.numeric, &other.numeric) {
+                numeric.sum += other.sum;
+                numeric.min = numeric.min.min(other.min);
+                numeric.max = numeric.max.max(other.max);
+                numeric.digest.merge(&other.digest);
+            }
+        }
+    }
+    
+    /// The statistics of everything folded in so far.
+    pub fn finalize(self) -> AggregateSummary<S> {
+        AggregateSummary {
+            rows: self.rows,
+            columns: self.columns,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> Default for TypedAggregator<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Schema> Clone for TypedAggregator<S> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows,
+            columns: self.columns.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for TypedAggregator<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TypedAggregator")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+/// The final statistics of a [`TypedAggregator`], looked up by typed column.
+///
+/// Numeric statistics are `None` for non-numeric columns and for columns
+/// without a single non-null value.
+pub struct AggregateSummary<S: Schema> {
+    rows: u64,
+    columns: Vec<ColumnState>,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> AggregateSummary<S> {
+    /// The number of rows aggregated.
+    pub fn rows(&self) -> u64 {
+        self.rows
+    }
+    
+    /// The number of non-null values of a column.
+    pub fn count<T: ColumnType>(&self, column: Column<T>) -> u64 {
+        self.state(column).map_or(0, |state| state.count)
+    }
+    
+    /// The number of nulls of a column.
+    pub fn null_count<T: ColumnType>(&self, column: Column<T>) -> u64 {
+        self.state(column).map_or(0, |state| state.null_count)
+    }
+    
+    /// The sum of a numeric column.
+    pub fn sum<T: ColumnType>(&self, column: Column<T>) -> Option<f64> {
+        self.numeric(column).map(|numeric| numeric.sum)
+    }
+    
+    /// The mean of a numeric column.
+    pub fn mean<T: ColumnType>(&self, column: Column<T>) -> Option<f64> {
+        let count = self.count(column);
+        self.numeric(column).map(|numeric| numeric.sum / count as f64)
+    }
+    
+    /// The minimum of a numeric column.
+    pub fn min<T: ColumnType>(&self, column: Column<T>) -> Option<f64> {
+        self.numeric(column).map(|numeric| numeric.min)
+    }
+    
+    /// The maximum of a numeric column.
+    pub fn max<T: ColumnType>(&self, column: Column<T>) -> Option<f64> {
+        self.numeric(column).map(|numeric| numeric.max)
+    }
+    
+    /// The approximate `q` quantile of a numeric column.
+    pub fn quantile<T: ColumnType>(&self, column: Column<T>, q: f64) -> Option<f64> {
+        self.numeric(column)?.digest.quantile(q)
+    }
+    
+    /// The summary as a frame with one row per schema column: `column`,
+    /// `count`, `null_count`, `sum`, `mean`, `min`, `max` and the `p25`,
+    /// `p50` and `p75` quantiles.
+    pub fn to_frame(&self) -> PolarsResult<DataFrame> {
+        let numeric = |f: &dyn Fn(&ColumnState, &NumericState) -> Option<f64>| -> Vec<Option<f64>> {
+            self.columns
+                .iter()
+                .map(|state| state.numeric.as_ref().filter(|_| state.count > 0).and_then(|numeric| f(state, numeric)))
+                .collect()
+        };
+        let names: Vec<&str> = self.columns.iter().map(|state| state.name).collect();
+        let counts: Vec<u64> = self.columns.iter().map(|state| state.count).collect();
+        let null_counts: Vec<u64> = self.columns.iter().map(|state| state.null_count).collect();
+        DataFrame::new(vec![
+            Series::new("column".into(), names).into_column(),
+            Series::new("count".into(), counts).into_column(),
+            Series::new("null_count".into(), null_counts).into_column(),
+            Series::new("sum".into(), numeric(&|_, n| Some(n.sum))).into_column(),
+            Series::new("mean".into(), numeric(&|s, n| Some(n.sum / s.count as f64))).into_column(),
+            Series::new("min".into(), numeric(&|_, n| Some(n.min))).into_column(),
+            Series::new("max".into(), numeric(&|_, n| Some(n.max))).into_column(),
+            Series::new("p25".into(), numeric(&|_, n| n.digest.quantile(0.25))).into_column(),
+            Series::new("p50".into(), numeric(&|_, n| n.digest.quantile(0.5))).into_column(),
+            Series::new("p75".into(), numeric(&|_, n| n.digest.quantile(0.75))).into_column(),
+        ])
+    }
+    
+    fn state<T: ColumnType>(&self, column: Column<T>) -> Option<&ColumnState> {
+        self.columns.iter().find(|state| state.name == column.name())
+    }
+    
+    fn numeric<T: ColumnType>(&self, column: Column<T>) -> Option<&NumericState> {
+        self.state(column).filter(|state| state.count > 0)?.numeric.as_ref()
+    }
+}
+
+impl<S: Schema> Clone for AggregateSummary<S> {
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows,
+            columns: self.columns.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for AggregateSummary<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AggregateSummary")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
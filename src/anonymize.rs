@@ -0,0 +1,109 @@
+//! PII masking of typed frames, driven by `#[pii(...)]` schema attributes.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+
+/// The text that redacted string values are replaced with.
+pub const REDACTED: &str = "[REDACTED]";
+
+/// How [`TypedDataFrame::anonymize`] masks a column, declared in
+/// `define_schema!` with `#[pii(...)]`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PiiStrategy {
+    /// `#[pii(hash)]`: replace strings with a hex keyed BLAKE3 MAC, keeping equal values equal
+    Hash,
+    /// `#[pii(truncate = N)]`: keep the first `N` characters of strings
+    Truncate(usize),
+    /// `#[pii(redact)]`: replace strings with [`REDACTED`] and other values with null
+    Redact,
+    /// `#[pii(bucket = W)]`: round numbers down to a multiple of `W`
+    Bucket(f64),
+}
+
+/// Maps the tokens of a `#[pii(...)]` attribute to a [`PiiStrategy`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pii_strategy {
+    (hash) => { $crate::anonymize::PiiStrategy::Hash };
+    (truncate = $n:literal) => { $crate::anonymize::PiiStrategy::Truncate($n) };
+    (redact) => { $crate::anonymize::PiiStrategy::Redact };
+    (bucket = $width:literal) => { $crate::anonymize::PiiStrategy::Bucket($width as f64) };
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Mask every column declared with `#[pii(...)]`, keeping the schema.
+    ///
+    /// Hashed columns are keyed with the secret `key`, so without it values
+    /// cannot be recovered by hashing every candidate, e.g. all phone numbers.
+    /// The same key maps equal values to equal digests across frames; keep it
+    /// out of the shared data, and use a new random key to make frames
+    /// unlinkable.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// define_schema! {
+    ///     PatientSchema {
+    ///         #[pii(hash)]
+    ///         patient_id: String,
+    ///         #[pii(truncate = 3)]
+    ///         zip: String,
+    ///         #[pii(bucket = 10)]
+    ///         age: i32,
+    ///         #[pii(redact)]
+    ///         notes: String,
+    ///         diagnosis: String,
+    ///     }
+    /// }
+    ///
+    /// let key: [u8; 32] = load_secret_key()?;
+    /// let shareable = patients.anonymize(&key)?;
+    /// ```
+    pub fn anonymize(&self, key: &[u8; 32]) -> PolarsResult<Self> {
+        let mut df = self.inner().clone();
+        for (name, strategy) in S::column_pii() {
+            let series = df.column(name)?.as_materialized_series();
+            let masked = mask(series, strategy, key).map_err(|error| match error {
+                PolarsError::InvalidOperation(message) => PolarsError::InvalidOperation(
+                    format!("Cannot apply #[pii] to column '{}': {}", name, message).into()
+                ),
+                error => error,
+            })?;
+            df.with_column(masked)?;
+        }
+        // Redacted non-string columns are all null
+        TypedDataFrame::new_lenient(df)
+    }
+}
+
+fn mask(series: &Series, strategy: PiiStrategy, key: &[u8; 32]) -> PolarsResult<Series> {
+    let strings = || {
+        series.str().map_err(|_| PolarsError::InvalidOperation(
+            format!("{:?} needs a String column, not {:?}", strategy, series.dtype()).into()
+        ))
+    };
+    let masked = match strategy {
+        PiiStrategy::Hash => strings()?
+            .apply_values(|value| blake3::keyed_hash(key, value.as_bytes()).to_hex().to_string().into())
+            .into_series(),
+        PiiStrategy::Truncate(length) => strings()?
+            .apply_values(|value| value.chars().take(length).collect::<String>().into())
+            .into_series(),
+        PiiStrategy::Redact if series.dtype() == &DataType::String => strings()?
+            .apply_values(|_| REDACTED.into())
+            .into_series(),
+        PiiStrategy::Redact => Series::full_null(series.name().clone(), series.len(), series.dtype()),
+        PiiStrategy::Bucket(width) => {
+            if !series.dtype().is_numeric() || width.is_nan() || width <= 0.0 {
+                return Err(PolarsError::InvalidOperation(
+                    format!("{:?} needs a numeric column and a positive width", strategy).into()
+                ));
+            }
+            let values = series.cast(&DataType::Float64)?;
+            let bucketed: Float64Chunked = values.f64()?.apply_values(|value| (value / width).floor() * width);
+            bucketed.into_series().cast(series.dtype())?
+        }
+    };
+    Ok(masked.with_name(series.name().clone()))
+}
@@ -0,0 +1,130 @@
+//! Arrow interop: record batches and the Arrow C Data Interface.
+//!
+//! Frames cross the C Data Interface as one struct array per chunk, the
+//! layout arrow-rs, DataFusion and pyarrow use for record batches.
+
+use polars::export::arrow::array::Array;
+use polars::export::arrow::ffi;
+use polars::export::arrow::record_batch::RecordBatch;
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// The Arrow schema of `S`, as used by [`to_record_batches`](Self::to_record_batches).
+    pub fn arrow_schema() -> ArrowSchema {
+        S::schema().to_arrow(CompatLevel::newest())
+    }
+    
+    /// Build a frame from Arrow record batches and validate it against the schema.
+    ///
+    /// `schema` describes the batches; columns not declared in `S` are dropped.
+    pub fn from_record_batches(
+        batches: impl IntoIterator<Item = RecordBatch>,
+        schema: &ArrowSchema,
+    ) -> PolarsResult<Self> {
+        let mut frames = batches
+            .into_iter()
+            .map(|batch| DataFrame::try_from((batch, schema)));
+        let Some(first) = frames.next() else {
+            return Self::new(DataFrame::empty_with_schema(&S::schema()));
+        };
+        let mut df = first?;
+        for frame in frames {
+            df.vstack_mut(&frame?)?;
+        }
+        
+        Self::new_projected(df)
+    }
+    
+    /// Export the frame as Arrow record batches, one per chunk.
+    pub fn to_record_batches(&self) -> Vec<RecordBatch> {
+        self.inner()
+            .iter_chunks(CompatLevel::newest(), false)
+            .collect()
+    }
+    
+    /// Import a frame exported through the Arrow C Data Interface.
+    ///
+    /// Each pair is one chunk: a struct array and its schema, as produced by
+    /// arrow-rs' `FFI_ArrowArray`/`FFI_ArrowSchema` for a record batch.
+    ///
+    /// # Safety
+    ///
+    /// The pointers must be valid C Data Interface structures whose array
+    /// matches its schema. Ownership of the arrays moves into the frame.
+    pub unsafe fn from_ffi(chunks: Vec<(ffi::ArrowArray, ffi::ArrowSchema)>) -> PolarsResult<Self> {
+        let mut df: Option<DataFrame> = None;
+        for (array, schema) in chunks {
+            let field = ffi::import_field_from_c(&schema)?;
+            let array = ffi::import_array_from_c(array, field.dtype().clone())?;
+            let chunk = Series::try_from((PlSmallStr::EMPTY, array))?
+                .struct_()?
+                .clone()
+                .unnest();
+            match df.as_mut() {
+                Some(df) => {
+                    df.vstack_mut(&chunk)?;
+                }
+                None => df = Some(chunk),
+            }
+        }
+        
+        match df {
+            Some(df) => Self::new_projected(df),
+            None => Self::new(DataFrame::empty_with_schema(&S::schema())),
+        }
+    }
+    
+    /// Export the frame through the Arrow C Data Interface, one struct array per chunk.
+    ///
+    /// The returned structures own their buffers until the consumer imports them.
+    pub fn to_ffi(&self) -> Vec<(ffi::ArrowArray, ffi::ArrowSchema)> {
+        let series = self.inner().clone().into_struct(PlSmallStr::EMPTY).into_series();
+        let field = series.field().to_arrow(CompatLevel::newest());
+        let schema_ffi = || ffi::export_field_to_c(&field);
+        
+        (0..series.n_chunks())
+            .map(|chunk| {
+                let array: Box<dyn Array> = series.to_arrow(chunk, CompatLevel::newest());
+                (ffi::export_array_to_c(array), schema_ffi())
+            })
+            .collect()
+    }
+}
+
+/// Move an arrow-rs record batch into a typed frame through the C Data Interface.
+///
+/// Used by the integrations whose clients produce arrow-rs batches.
+#[cfg(any(feature = "flight", feature = "orc"))]
+pub(crate) fn from_arrow_rs_batch<Sch: Schema>(
+    batch: ::arrow::record_batch::RecordBatch,
+) -> PolarsResult<TypedDataFrame<Sch>> {
+    let array = ::arrow::array::StructArray::from(batch);
+    let (array, schema) = ::arrow::ffi::to_ffi(&array.into_data())
+        .map_err(|err| PolarsError::ComputeError(format!("Arrow error: {}", err).into()))?;
+    // Move the structures across as a C consumer would: read them through a
+    // pointer, then free the allocations without releasing what they own
+    let array = Box::into_raw(Box::new(std::mem::ManuallyDrop::new(array)));
+    let schema = Box::into_raw(Box::new(std::mem::ManuallyDrop::new(schema)));
+    let chunk = unsafe {
+        let chunk = (
+            std::ptr::read(array.cast::<ffi::ArrowArray>()),
+            std::ptr::read(schema.cast::<ffi::ArrowSchema>()),
+        );
+        drop(Box::from_raw(array));
+        drop(Box::from_raw(schema));
+        chunk
+    };
+    unsafe { TypedDataFrame::from_ffi(vec![chunk]) }
+}
+
+// Both libraries define the C Data Interface structures with `#[repr(C)]`
+#[cfg(any(feature = "flight", feature = "orc"))]
+const _: () = {
+    use std::mem::{align_of, size_of};
+    assert!(size_of::<::arrow::ffi::FFI_ArrowArray>() == size_of::<ffi::ArrowArray>());
+    assert!(align_of::<::arrow::ffi::FFI_ArrowArray>() == align_of::<ffi::ArrowArray>());
+    assert!(size_of::<::arrow::ffi::FFI_ArrowSchema>() == size_of::<ffi::ArrowSchema>());
+    assert!(align_of::<::arrow::ffi::FFI_ArrowSchema>() == align_of::<ffi::ArrowSchema>());
+};
@@ -0,0 +1,251 @@
+//! Confluent Schema Registry integration for Avro payloads.
+//!
+//! Requires the `avro` feature.
+
+use apache_avro::schema::Schema as AvroSchema;
+use apache_avro::types::Value;
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::error::TypedPolarsError;
+use std::collections::HashMap;
+
+/// First byte of every registry-framed payload.
+const MAGIC_BYTE: u8 = 0;
+
+/// Client for a Confluent Schema Registry that decodes registry-framed Avro into typed frames.
+///
+/// Payloads use the registry wire format: a zero magic byte, the writer
+/// schema's 4-byte big-endian id and the Avro-encoded record. Writer schemas
+/// are fetched once per id and cached.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut registry = SchemaRegistryClient::new("http://localhost:8081");
+/// registry.check_compatibility::<OrderSchema>("orders-value")?;
+///
+/// let payloads: Vec<Vec<u8>> = consumer.poll_batch()?;
+/// let orders = registry.decode::<OrderSchema>(&payloads)?;
+/// ```
+pub struct SchemaRegistryClient {
+    url: String,
+    headers: Vec<(String, String)>,
+    schemas: HashMap<u32, AvroSchema>,
+}
+
+impl SchemaRegistryClient {
+    /// Create a client for the registry at `url`.
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.trim_end_matches('/').to_string(),
+            headers: Vec::new(),
+            schemas: HashMap::new(),
+        }
+    }
+    
+    /// Send an additional request header, e.g. for authentication.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+    
+    /// Fetch the latest schema registered under `subject`, with its id.
+    pub fn latest_schema(&mut self, subject: &str) -> PolarsResult<(u32, AvroSchema)> {
+        let response = self.get(&format!("/subjects/{}/versions/latest", encode_path_segment(subject)))?;
+        let id = response["id"]
+            .as_u64()
+            .and_then(|id| u32::try_from(id).ok())
+            .ok_or_else(|| registry_error("response has no schema id"))?;
+        let schema = parse_schema(&response)?;
+        self.schemas.insert(id, schema.clone());
+        Ok((id, schema))
+    }
+    
+    /// Fetch the schema with the given id.
+    pub fn schema_by_id(&mut self, id: u32) -> PolarsResult<&AvroSchema> {
+        if !self.schemas.contains_key(&id) {
+            let response = self.get(&format!("/schemas/ids/{}", id))?;
+            self.schemas.insert(id, parse_schema(&response)?);
+        }
+        Ok(&self.schemas[&id])
+    }
+    
+    /// Check the latest schema of `subject` against the typed schema.
+    pub fn check_compatibility<Sch: Schema>(&mut self, subject: &str) -> PolarsResult<()> {
+        let (_, schema) = self.latest_schema(subject)?;
+        check_avro_schema::<Sch>(&schema)
+    }
+    
+    /// Decode registry-framed Avro records and validate them against the schema.
+    ///
+    /// Each record is decoded with its own writer schema, so payloads written
+    /// under different schema versions can be mixed.
+    pub fn decode<Sch: Schema>(
+        &mut self,
+        payloads: impl IntoIterator<Item = impl AsRef<[u8]>>,
+    ) -> PolarsResult<TypedDataFrame<Sch>> {
+        let schema = Sch::schema();
+        let mut columns: Vec<Vec<AnyValue<'static>>> = vec![Vec::new(); schema.len()];
+        
+        for (index, payload) in payloads.into_iter().enumerate() {
+            let payload = payload.as_ref();
+            let (id, mut datum) = match payload {
+                [MAGIC_BYTE, a, b, c, d, datum @ ..] => (u32::from_be_bytes([*a, *b, *c, *d]), datum),
+                _ => {
+                    return Err(PolarsError::ComputeError(
+                        format!("Payload {} is not in the schema registry wire format", index).into()
+                    ));
+                }
+            };
+            let writer_schema = self.schema_by_id(id)?;
+            let record = apache_avro::from_avro_datum(writer_schema, &mut datum, None)
+                .map_err(|err| registry_error(format!("Cannot decode payload {}: {}", index, err)))?;
+            let Value::Record(fields) = record else {
+                return Err(PolarsError::SchemaMismatch(
+                    format!("Payload {} is not an Avro record", index).into()
+                ));
+            };
+            
+            for (values, name) in columns.iter_mut().zip(schema.iter_names()) {
+                let value = fields
+                    .iter()
+                    .find(|(field, _)| field == name.as_str())
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| TypedPolarsError::ColumnNotFound {
+                        column: name.to_string(),
+                        context: Some(format!("Avro payload {}", index)),
+                    })?;
+                values.push(avro_value(value)?);
+            }
+        }
+        
+        let columns = schema
+            .iter()
+            .zip(columns)
+            .map(|((name, dtype), values)| {
+                Series::from_any_values(name.clone(), &values, true)?
+                    .strict_cast(dtype)
+                    .map(|s| s.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        
+        TypedDataFrame::from_source(DataFrame::new(columns)?, "Avro payloads")
+    }
+    
+    fn get(&self, path: &str) -> PolarsResult<serde_json::Value> {
+        let mut request = ureq::get(&format!("{}{}", self.url, path))
+            .set("Accept", "application/vnd.schemaregistry.v1+json");
+        for (key, value) in &self.headers {
+            request = request.set(key, value);
+        }
+        let body = request
+            .call()
+            .map_err(registry_error)?
+            .into_string()?;
+        serde_json::from_str(&body).map_err(registry_error)
+    }
+}
+
+/// Check that an Avro record schema provides every column of `Sch` with a compatible type.
+///
+/// Avro `int`/`long` fields are compatible with any integer column and
+/// `float`/`double` with any float column; values are range-checked on decode.
+pub fn check_avro_schema<Sch: Schema>(avro: &AvroSchema) -> PolarsResult<()> {
+    let AvroSchema::Record(record) = avro else {
+        return Err(PolarsError::SchemaMismatch("Avro schema is not a record".into()));
+    };
+    
+    for (name, expected) in Sch::schema().iter() {
+        let field = record
+            .fields
+            .iter()
+            .find(|field| field.name == name.as_str())
+            .ok_or_else(|| TypedPolarsError::ColumnNotFound {
+                column: name.to_string(),
+                context: Some("Avro schema".to_string()),
+            })?;
+        let compatible = avro_dtype(&field.schema).is_some_and(|actual| {
+            &actual == expected
+                || (actual.is_integer() && expected.is_integer())
+                || (actual.is_float() && expected.is_float())
+        });
+        if !compatible {
+            return Err(PolarsError::SchemaMismatch(
+                format!("Field '{}' has Avro type {:?}, expected {:?}", name, field.schema, expected).into()
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// The Polars type an Avro field decodes to; nullable unions map to their non-null branch.
+fn avro_dtype(schema: &AvroSchema) -> Option<DataType> {
+    match schema {
+        AvroSchema::Boolean => Some(DataType::Boolean),
+        AvroSchema::Int => Some(DataType::Int32),
+        AvroSchema::Long => Some(DataType::Int64),
+        AvroSchema::Float => Some(DataType::Float32),
+        AvroSchema::Double => Some(DataType::Float64),
+        AvroSchema::String | AvroSchema::Enum(_) | AvroSchema::Uuid => Some(DataType::String),
+        AvroSchema::Date => Some(DataType::Date),
+        AvroSchema::TimestampMillis => Some(DataType::Datetime(TimeUnit::Milliseconds, None)),
+        AvroSchema::TimestampMicros => Some(DataType::Datetime(TimeUnit::Microseconds, None)),
+        AvroSchema::TimeMillis | AvroSchema::TimeMicros => Some(DataType::Time),
+        AvroSchema::Union(union) => match union.variants() {
+            [AvroSchema::Null, inner] | [inner, AvroSchema::Null] => avro_dtype(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn avro_value(value: &Value) -> PolarsResult<AnyValue<'static>> {
+    let value = match value {
+        Value::Null => AnyValue::Null,
+        Value::Boolean(b) => AnyValue::Boolean(*b),
+        Value::Int(i) => AnyValue::Int32(*i),
+        Value::Long(l) => AnyValue::Int64(*l),
+        Value::Float(f) => AnyValue::Float32(*f),
+        Value::Double(d) => AnyValue::Float64(*d),
+        Value::String(s) | Value::Enum(_, s) => AnyValue::StringOwned(s.as_str().into()),
+        Value::Uuid(uuid) => AnyValue::StringOwned(uuid.to_string().into()),
+        Value::Date(days) => AnyValue::Date(*days),
+        Value::TimestampMillis(ms) => AnyValue::Datetime(*ms, TimeUnit::Milliseconds, None),
+        Value::TimestampMicros(us) => AnyValue::Datetime(*us, TimeUnit::Microseconds, None),
+        Value::TimeMillis(ms) => AnyValue::Time(*ms as i64 * 1_000_000),
+        Value::TimeMicros(us) => AnyValue::Time(us * 1_000),
+        Value::Union(_, inner) => avro_value(inner)?,
+        other => {
+            return Err(PolarsError::ComputeError(
+                format!("Unsupported Avro value {:?}", other).into()
+            ));
+        }
+    };
+    Ok(value)
+}
+
+/// Percent-encode everything but unreserved characters, so a subject such
+/// as `orders/value` stays one path segment.
+fn encode_path_segment(segment: &str) -> String {
+    let mut encoded = String::with_capacity(segment.len());
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+fn parse_schema(response: &serde_json::Value) -> PolarsResult<AvroSchema> {
+    let schema = response["schema"]
+        .as_str()
+        .ok_or_else(|| registry_error("response has no schema"))?;
+    AvroSchema::parse_str(schema).map_err(registry_error)
+}
+
+fn registry_error(err: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(format!("Schema registry error: {}", err).into())
+}
@@ -0,0 +1,121 @@
+//! Binning: discretizing numeric columns into labelled intervals.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use num_traits::NumCast;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Append a `<column>_bin` column labelling the interval of `breaks` each
+    /// value falls in, into the schema `Out`.
+    ///
+    /// Intervals are closed on the right, as in Polars and pandas: breaks
+    /// `[18, 65]` give `(-inf, 18]`, `(18, 65]` and `(65, inf]`. `labels` name
+    /// the intervals in order, one more than there are breaks; pass `&[]` to
+    /// use the interval notation. `Out` declares the bin column as `String` or
+    /// as a categorical type. Nulls and NaNs get a null bin.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// define_schema! {
+    ///     BinnedUsers {
+    ///         id: i64,
+    ///         age: i32,
+    ///         age_bin: String,
+    ///     }
+    /// }
+    ///
+    /// let binned: TypedDataFrame<BinnedUsers> =
+    ///     users.cut(UserSchema::age, &[18.0, 65.0], &["minor", "adult", "senior"])?;
+    /// ```
+    pub fn cut<T: ColumnType + NumCast, Out: Schema>(
+        &self,
+        column: Column<T>,
+        breaks: &[f64],
+        labels: &[&str],
+    ) -> PolarsResult<TypedDataFrame<Out>> {
+        if breaks.iter().any(|value| value.is_nan()) || breaks.windows(2).any(|pair| pair[0] >= pair[1]) {
+            return Err(PolarsError::InvalidOperation(
+                format!("Breaks must be strictly increasing numbers, got {:?}", breaks).into()
+            ));
+        }
+        if !labels.is_empty() && labels.len() != breaks.len() + 1 {
+            return Err(PolarsError::InvalidOperation(
+                format!("{} breaks need {} labels, got {}", breaks.len(), breaks.len() + 1, labels.len()).into()
+            ));
+        }
+        let labels: Vec<String> = if labels.is_empty() {
+            let bounds: Vec<f64> = std::iter::once(f64::NEG_INFINITY)
+                .chain(breaks.iter().copied())
+                .chain(std::iter::once(f64::INFINITY))
+                .collect();
+            bounds.windows(2).map(|pair| format!("({}, {}]", pair[0], pair[1])).collect()
+        } else {
+            labels.iter().map(|label| label.to_string()).collect()
+        };
+        
+        let values = self.float_values(column.name())?;
+        let bins: Vec<Option<&str>> = values
+            .iter()
+            .map(|value| {
+                let value = value.filter(|value| !value.is_nan())?;
+                let bin = breaks.partition_point(|bound| *bound < value);
+                Some(labels[bin].as_str())
+            })
+            .collect();
+        
+        let name = format!("{}_bin", column.name());
+        let mut bins = Series::new(name.as_str().into(), bins);
+        if let Some(dtype) = Out::schema().get(name.as_str()) {
+            bins = bins.cast(dtype)?;
+        }
+        let mut df = self.inner().clone();
+        df.with_column(bins)?;
+        TypedDataFrame::new(df)
+    }
+    
+    /// Append a `<column>_bin` column splitting the values into `n_quantiles`
+    /// bins of about equal size, into the schema `Out`.
+    ///
+    /// The breaks are the quantiles of the non-null values, interpolated
+    /// linearly, and the bins are labelled with their intervals as in
+    /// [`cut`](Self::cut). With many equal values some quantiles coincide,
+    /// and their bins are merged.
+    pub fn qcut<T: ColumnType + NumCast, Out: Schema>(
+        &self,
+        column: Column<T>,
+        n_quantiles: usize,
+    ) -> PolarsResult<TypedDataFrame<Out>> {
+        if n_quantiles == 0 {
+            return Err(PolarsError::InvalidOperation("qcut needs at least one quantile".into()));
+        }
+        let mut sorted: Vec<f64> = self
+            .float_values(column.name())?
+            .into_iter()
+            .flatten()
+            .filter(|value| !value.is_nan())
+            .collect();
+        sorted.sort_by(f64::total_cmp);
+        
+        let mut breaks: Vec<f64> = Vec::new();
+        if !sorted.is_empty() {
+            for i in 1..n_quantiles {
+                let position = i as f64 / n_quantiles as f64 * (sorted.len() - 1) as f64;
+                let (below, fraction) = (position.floor() as usize, position.fract());
+                let above = (below + 1).min(sorted.len() - 1);
+                let quantile = sorted[below] + (sorted[above] - sorted[below]) * fraction;
+                if !breaks.last().is_some_and(|last| *last >= quantile) {
+                    breaks.push(quantile);
+                }
+            }
+        }
+        self.cut(column, &breaks, &[])
+    }
+    
+    /// The values of a numeric column as `f64`s.
+    fn float_values(&self, name: &str) -> PolarsResult<Vec<Option<f64>>> {
+        let series = self.inner().column(name)?.as_materialized_series().cast(&DataType::Float64)?;
+        Ok(series.f64()?.into_iter().collect())
+    }
+}
@@ -0,0 +1,131 @@
+//! Result caching of typed lazy pipelines, keyed by plan and schema fingerprint.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::lazy::TypedLazyFrame;
+use crate::io::{ParquetReader, ParquetWriter};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+impl<S: Schema> TypedLazyFrame<S> {
+    /// Execute the query, or reuse its result from an earlier run.
+    ///
+    /// Results are stored as Parquet files in `cache_dir`, named by a hash of
+    /// the serialized logical plan, the schema's
+    /// [`fingerprint`](Schema::fingerprint) and the size and modification
+    /// time of every file the plan scans, so editing the query or the schema,
+    /// or rewriting a source file, misses the cache. A cached file is
+    /// revalidated against the schema when read; one that fails is recomputed.
+    ///
+    /// Plans over in-memory frames or sources without a local modification
+    /// time, such as cloud URLs, are never cached, since their data is not
+    /// part of the key.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Only the first run of the notebook cell pays for the aggregation
+    /// let totals = TypedLazyFrame::<StoreTotals>::new(
+    ///     scan_parquet::<SaleSchema>("sales/*.parquet")?
+    ///         .into_inner()
+    ///         .group_by([col("store")])
+    ///         .agg([col("amount").sum()]),
+    /// )
+    /// .collect_cached("target/query-cache")?;
+    /// ```
+    pub fn collect_cached(self, cache_dir: impl AsRef<Path>) -> PolarsResult<TypedDataFrame<S>> {
+        let Some(path) = self.cache_path(cache_dir.as_ref())? else {
+            return self.collect();
+        };
+        if path.exists() {
+            if let Ok(df) = ParquetReader::<S>::new(&path).finish() {
+                return Ok(df);
+            }
+        }
+        
+        let df = self.collect()?;
+        std::fs::create_dir_all(cache_dir.as_ref())?;
+        // Write beside the final name and rename, so no reader sees a partial file
+        let partial = path.with_extension("parquet.partial");
+        ParquetWriter::new(&df).finish(&partial)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(df)
+    }
+    
+    /// The cache file for this plan, or `None` if its data is not covered by the key.
+    fn cache_path(&self, cache_dir: &Path) -> PolarsResult<Option<PathBuf>> {
+        let plan = self.inner().describe_plan()?;
+        if plan.lines().any(|line| line.trim_start().starts_with("DF [")) {
+            return Ok(None);
+        }
+        let plan = serde_json::to_value(&self.inner().logical_plan)
+            .map_err(|e| PolarsError::ComputeError(format!("Failed to serialize the plan: {}", e).into()))?;
+        let mut sources = Vec::new();
+        scanned_paths(&plan, &mut sources);
+        
+        let mut key = format!("{}\n{}", S::fingerprint(), plan);
+        for source in sources {
+            let Some(files) = source_files(&source) else {
+                return Ok(None);
+            };
+            for file in files {
+                let Ok(metadata) = std::fs::metadata(&file) else {
+                    return Ok(None);
+                };
+                let modified = metadata.modified()?.duration_since(UNIX_EPOCH).unwrap_or_default();
+                key.push_str(&format!("\n{} {} {}", file.display(), metadata.len(), modified.as_nanos()));
+            }
+        }
+        let hash = blake3::hash(key.as_bytes()).to_hex();
+        Ok(Some(cache_dir.join(format!("{}.parquet", &hash[..32]))))
+    }
+}
+
+/// Collect the paths of the scans in a serialized plan.
+fn scanned_paths(value: &serde_json::Value, paths: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (name, value) in fields {
+                match (name.as_str(), value) {
+                    ("Paths", serde_json::Value::Array(items)) => {
+                        paths.extend(items.iter().filter_map(|item| item.as_str().map(String::from)));
+                    }
+                    _ => scanned_paths(value, paths),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|item| scanned_paths(item, paths)),
+        _ => {}
+    }
+}
+
+/// The files a scan source reads, expanding globs and directories; `None`
+/// if they cannot be listed.
+fn source_files(source: &str) -> Option<Vec<PathBuf>> {
+    let matched: Vec<PathBuf> = if source.contains(['*', '?', '[']) {
+        #[cfg(feature = "fs")]
+        {
+            glob::glob(source).ok()?.collect::<Result<_, _>>().ok()?
+        }
+        #[cfg(not(feature = "fs"))]
+        {
+            return None;
+        }
+    } else {
+        vec![PathBuf::from(source)]
+    };
+    let mut files = Vec::new();
+    let mut pending = matched;
+    while let Some(path) = pending.pop() {
+        if path.is_dir() {
+            for entry in std::fs::read_dir(&path).ok()? {
+                pending.push(entry.ok()?.path());
+            }
+        } else {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Some(files)
+}
@@ -0,0 +1,131 @@
+//! Change data capture: applying insert, update and delete events to a typed table.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::keyed::Keyed;
+use std::marker::PhantomData;
+
+/// The column of a [`CdcSchema`] holding each event's operation.
+pub const OP_COLUMN: &str = "op";
+
+/// The operation of a change event, stored as text in the [`OP_COLUMN`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CdcOp {
+    /// `"insert"`: a new row
+    Insert,
+    /// `"update"`: new values for an existing row
+    Update,
+    /// `"delete"`: the row is removed; only its key columns matter
+    Delete,
+}
+
+impl CdcOp {
+    /// The text stored in the [`OP_COLUMN`].
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CdcOp::Insert => "insert",
+            CdcOp::Update => "update",
+            CdcOp::Delete => "delete",
+        }
+    }
+}
+
+/// The schema of change events for tables of the schema `S`: an `op`
+/// column followed by every column of `S`.
+///
+/// `S` itself must not have a column named `op`.
+pub struct CdcSchema<S: Schema>(PhantomData<S>);
+
+#[allow(non_upper_case_globals)]
+impl<S: Schema> CdcSchema<S> {
+    /// The operation column.
+    pub const op: Column<String> = Column::new(OP_COLUMN);
+}
+
+impl<S: Schema> Schema for CdcSchema<S> {
+    type Columns = (Column<String>, S::Columns);
+    
+    fn schema() -> polars::prelude::Schema {
+        let payload = S::schema();
+        polars::prelude::Schema::from_iter(
+            std::iter::once(Field::new(OP_COLUMN.into(), DataType::String))
+                .chain(payload.iter().map(|(name, dtype)| Field::new(name.clone(), dtype.clone())))
+        )
+    }
+    
+    fn column_names() -> Vec<&'static str> {
+        std::iter::once(OP_COLUMN).chain(S::column_names()).collect()
+    }
+    
+    fn column_formats() -> Vec<(&'static str, &'static str)> {
+        S::column_formats()
+    }
+    
+    fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+        S::column_ranges()
+    }
+    
+    fn column_pii() -> Vec<(&'static str, crate::anonymize::PiiStrategy)> {
+        S::column_pii()
+    }
+    
+    fn nullable_columns() -> Vec<&'static str> {
+        S::nullable_columns()
+    }
+    
+    fn version() -> u32 {
+        S::version()
+    }
+}
+
+impl<S: Schema, K: ColumnSet + Copy> Keyed<S, K> {
+    /// Apply change events in order, returning the updated table.
+    ///
+    /// The last event for a key decides its row: `insert` and `update` set
+    /// the row to the event's values, adding it if the key is new, and
+    /// `delete` removes it if present. Replaying events, as at-least-once
+    /// change streams do, therefore gives the same table. Changed rows move
+    /// after the unchanged ones, as in [`upsert`](Self::upsert).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let mut customers = customers.validate_keys(CustomerSchema::id)?;
+    /// while let Some(events) = changes.next_batch() {
+    ///     customers = customers.apply_cdc(&events?)?;
+    /// }
+    /// ```
+    pub fn apply_cdc(&self, events: &TypedDataFrame<CdcSchema<S>>) -> PolarsResult<Self> {
+        if S::column_names().contains(&OP_COLUMN) {
+            return Err(PolarsError::InvalidOperation(
+                format!("Schemas with a column named '{}' cannot be used with change events", OP_COLUMN).into()
+            ));
+        }
+        let ops = events.inner().column(OP_COLUMN)?.str()?;
+        let known = [CdcOp::Insert, CdcOp::Update, CdcOp::Delete].map(CdcOp::as_str);
+        if let Some((row, op)) = ops.into_iter().enumerate().find(|(_, op)| !op.is_some_and(|op| known.contains(&op))) {
+            return Err(PolarsError::ComputeError(
+                format!("Change event {} has operation {:?}, expected insert, update or delete", row, op).into()
+            ));
+        }
+        
+        let columns = CdcSchema::<S>::column_names();
+        let mut state = self.inner().select(S::column_names())?;
+        state.insert_column(0, Series::new(OP_COLUMN.into(), vec![CdcOp::Insert.as_str(); state.height()]))?;
+        let combined = state.vstack(&events.inner().select(columns)?)?;
+        let subset: Vec<String> = self.keys().names().into_iter().map(String::from).collect();
+        let latest = combined.unique_stable(Some(&subset), UniqueKeepStrategy::Last, None)?;
+        let kept = latest.column(OP_COLUMN)?.str()?.not_equal(CdcOp::Delete.as_str());
+        let mut applied = latest.filter(&kept)?;
+        applied.drop_in_place(OP_COLUMN)?;
+        Ok(unsafe { Keyed::new_unchecked(TypedDataFrame::new_unchecked(applied), *self.keys()) })
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Apply change events in order to a table unique on `keys`; see [`Keyed::apply_cdc`].
+    pub fn apply_cdc<K: ColumnSet + Copy>(&self, events: &TypedDataFrame<CdcSchema<S>>, keys: K) -> PolarsResult<Self> {
+        Ok(Keyed::new(self.clone(), keys)?.apply_cdc(events)?.into_inner())
+    }
+}
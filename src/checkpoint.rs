@@ -0,0 +1,88 @@
+//! Checkpoints: persisted intermediate results that long pipelines resume from.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::lazy::TypedLazyFrame;
+use crate::io::{ParquetReader, ParquetWriter};
+use std::path::{Path, PathBuf};
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Persist the frame as the checkpoint `name` in `dir`, replacing any earlier one.
+    ///
+    /// The checkpoint is a Parquet file whose name includes a hash of the
+    /// schema's [`fingerprint`](Schema::fingerprint). It is written under a temporary
+    /// name and renamed, so a crash mid-write leaves no checkpoint to resume
+    /// from rather than a truncated one.
+    pub fn checkpoint(&self, name: &str, dir: impl AsRef<Path>) -> PolarsResult<()> {
+        let path = checkpoint_path::<S>(name, dir.as_ref())?;
+        std::fs::create_dir_all(dir.as_ref())?;
+        let partial = path.with_extension("parquet.partial");
+        ParquetWriter::new(self).finish(&partial)?;
+        std::fs::rename(&partial, &path)?;
+        Ok(())
+    }
+    
+    /// The checkpoint `name` in `dir`, unless there is none or it was written
+    /// under a different schema fingerprint.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let cleaned = match TypedDataFrame::<CleanSchema>::resume("cleaned", "target/etl")? {
+    ///     Some(cleaned) => cleaned,
+    ///     None => {
+    ///         let cleaned = clean(&raw)?;
+    ///         cleaned.checkpoint("cleaned", "target/etl")?;
+    ///         cleaned
+    ///     }
+    /// };
+    /// ```
+    pub fn resume(name: &str, dir: impl AsRef<Path>) -> PolarsResult<Option<Self>> {
+        let path = checkpoint_path::<S>(name, dir.as_ref())?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        ParquetReader::new(&path).finish().map(Some)
+    }
+}
+
+impl<S: Schema> TypedLazyFrame<S> {
+    /// Continue the pipeline from the checkpoint `name` in `dir`, computing
+    /// and persisting it first unless an earlier run already did.
+    ///
+    /// Everything upstream of the checkpoint is skipped when it is resumed, so
+    /// a job that fails late restarts from its last checkpoint. A checkpoint
+    /// written under a different schema fingerprint is recomputed; delete the
+    /// directory to recompute after the upstream data or logic changed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let valid = scan_parquet::<RawEvents>("raw/*.parquet")?
+    ///     .filter(valid_events())
+    ///     .checkpoint("valid", "target/etl")?;
+    /// ```
+    pub fn checkpoint(self, name: &str, dir: impl AsRef<Path>) -> PolarsResult<Self> {
+        let path = checkpoint_path::<S>(name, dir.as_ref())?;
+        if !path.exists() {
+            self.collect()?.checkpoint(name, dir.as_ref())?;
+        }
+        ParquetReader::new(&path).scan()
+    }
+}
+
+/// The file of the checkpoint `name` in `dir` for the schema `S`.
+///
+/// Parquet files written by Polars 0.44 cannot carry custom metadata, so the
+/// fingerprint goes into the name: a checkpoint written under another schema
+/// or version is simply not found.
+fn checkpoint_path<S: Schema>(name: &str, dir: &Path) -> PolarsResult<PathBuf> {
+    if name.is_empty() || name.contains(['/', '\\']) {
+        return Err(PolarsError::InvalidOperation(
+            format!("Invalid checkpoint name '{}'", name).into()
+        ));
+    }
+    let fingerprint = blake3::hash(S::fingerprint().as_bytes()).to_hex();
+    Ok(dir.join(format!("{}.{}.parquet", name, &fingerprint[..16])))
+}
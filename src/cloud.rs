@@ -0,0 +1,439 @@
+//! Cloud object store support (`s3://`, `gs://`, `az://`) for the typed readers and writers.
+//!
+//! Requires the `cloud` feature.
+
+use polars::io::cloud::{CloudOptions, CloudWriter};
+use polars::io::pl_async::get_runtime;
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// URL schemes routed to the object store instead of the local filesystem.
+const CLOUD_SCHEMES: &[&str] = &[
+    "s3://", "s3a://", "gs://", "gcs://", "az://", "azure://", "abfs://", "abfss://",
+];
+
+/// Credentials and connection options for cloud object stores.
+///
+/// The typed settings are translated to the configuration keys of the store
+/// behind each URL, so one configuration can be shared by all readers and
+/// writers. [`option`](Self::option) sets raw provider keys as understood by
+/// Polars, e.g. `aws_access_key_id` or `google_service_account`.
+///
+/// # Example
+///
+/// ```ignore
+/// let config = CloudConfig::new()
+///     .region("eu-central-1")
+///     .access_key("AKIA...", "...")
+///     .endpoint("http://localhost:9000");
+///
+/// let df = ParquetReader::<SalesSchema>::new("s3://bucket/sales.parquet")
+///     .cloud_config(config)
+///     .finish()?;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CloudConfig {
+    options: Vec<(String, String)>,
+    access_key: Option<(String, String)>,
+    session_token: Option<String>,
+    endpoint: Option<String>,
+    anonymous: bool,
+}
+
+/// The object store providers, as selected by URL scheme.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Provider {
+    Aws,
+    Gcp,
+    Azure,
+}
+
+impl CloudConfig {
+    /// Create an empty configuration; credentials are then taken from the environment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the AWS region.
+    pub fn region(self, region: &str) -> Self {
+        self.option("aws_region", region)
+    }
+
+    /// Set static credentials: an access key id and secret for S3, or an
+    /// account name and key for Azure.
+    pub fn access_key(mut self, key_id: &str, secret: &str) -> Self {
+        self.access_key = Some((key_id.to_string(), secret.to_string()));
+        self
+    }
+    
+    /// Set the session token accompanying temporary S3 credentials.
+    pub fn session_token(mut self, token: &str) -> Self {
+        self.session_token = Some(token.to_string());
+        self
+    }
+    
+    /// Load credentials and region of a named AWS profile.
+    ///
+    /// Reads the shared credentials and config files (`~/.aws/credentials` and
+    /// `~/.aws/config`, or the paths in `AWS_SHARED_CREDENTIALS_FILE` and
+    /// `AWS_CONFIG_FILE`). Profiles that delegate to SSO or a role are not
+    /// resolved.
+    pub fn profile(mut self, name: &str) -> PolarsResult<Self> {
+        let home = std::env::var("HOME").unwrap_or_default();
+        let credentials_path = std::env::var("AWS_SHARED_CREDENTIALS_FILE")
+            .unwrap_or_else(|_| format!("{}/.aws/credentials", home));
+        let config_path = std::env::var("AWS_CONFIG_FILE")
+            .unwrap_or_else(|_| format!("{}/.aws/config", home));
+        
+        let credentials = read_ini_section(&credentials_path, name);
+        // The config file prefixes every profile except the default with `profile`
+        let config_section = if name == "default" { name.to_string() } else { format!("profile {}", name) };
+        let config = read_ini_section(&config_path, &config_section);
+        let setting = |key: &str| {
+            credentials.iter().chain(&config).find(|(k, _)| k == key).map(|(_, v)| v.clone())
+        };
+        
+        match (setting("aws_access_key_id"), setting("aws_secret_access_key")) {
+            (Some(key_id), Some(secret)) => self.access_key = Some((key_id, secret)),
+            _ => {
+                return Err(PolarsError::ComputeError(
+                    format!("AWS profile '{}' has no static credentials", name).into()
+                ));
+            }
+        }
+        if let Some(token) = setting("aws_session_token") {
+            self.session_token = Some(token);
+        }
+        if let Some(region) = setting("region") {
+            self = self.region(&region);
+        }
+        Ok(self)
+    }
+    
+    /// Send requests to a custom endpoint, e.g. MinIO or another S3-compatible store.
+    ///
+    /// Plain `http://` endpoints are allowed for local development.
+    pub fn endpoint(mut self, url: &str) -> Self {
+        self.endpoint = Some(url.to_string());
+        self
+    }
+    
+    /// Access public buckets without signing requests or looking up credentials.
+    pub fn anonymous(mut self) -> Self {
+        self.anonymous = true;
+        self
+    }
+    
+    /// Set an arbitrary provider configuration key.
+    pub fn option(mut self, key: &str, value: &str) -> Self {
+        self.options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Build Polars cloud options for the store behind `url`.
+    pub(crate) fn to_cloud_options(&self, url: &str) -> PolarsResult<CloudOptions> {
+        let options = self.provider_options(url)?;
+        CloudOptions::from_untyped_config(
+            url,
+            options.iter().chain(&self.options).map(|(key, value)| (key.as_str(), value.clone())),
+        )
+    }
+    
+    /// The settings as object store configuration keys for `url`, e.g. for
+    /// the Delta Lake reader.
+    pub(crate) fn storage_options(&self, url: &str) -> PolarsResult<Vec<(String, String)>> {
+        let mut options = self.provider_options(url)?;
+        options.extend(self.options.iter().cloned());
+        Ok(options)
+    }
+    
+    /// The typed settings as configuration keys of the provider behind `url`.
+    fn provider_options(&self, url: &str) -> PolarsResult<Vec<(String, String)>> {
+        let provider = if url.starts_with("s3") {
+            Provider::Aws
+        } else if url.starts_with("gs") {
+            Provider::Gcp
+        } else {
+            Provider::Azure
+        };
+        let unsupported = |setting: &str| PolarsError::InvalidOperation(
+            format!("{} is not supported for '{}'", setting, url).into()
+        );
+        let mut options = Vec::new();
+        let mut push = |key: &str, value: &str| options.push((key.to_string(), value.to_string()));
+        
+        if let Some((key_id, secret)) = &self.access_key {
+            match provider {
+                Provider::Aws => {
+                    push("aws_access_key_id", key_id);
+                    push("aws_secret_access_key", secret);
+                }
+                Provider::Azure => {
+                    push("azure_storage_account_name", key_id);
+                    push("azure_storage_account_key", secret);
+                }
+                Provider::Gcp => return Err(unsupported("An access key")),
+            }
+        }
+        if let Some(token) = &self.session_token {
+            match provider {
+                Provider::Aws => push("aws_session_token", token),
+                _ => return Err(unsupported("A session token")),
+            }
+        }
+        if let Some(endpoint) = &self.endpoint {
+            match provider {
+                Provider::Aws => push("aws_endpoint_url", endpoint),
+                Provider::Azure => push("azure_endpoint", endpoint),
+                Provider::Gcp => return Err(unsupported("An endpoint override")),
+            }
+            if endpoint.starts_with("http://") {
+                match provider {
+                    Provider::Aws => push("aws_allow_http", "true"),
+                    _ => push("azure_allow_http", "true"),
+                }
+            }
+        }
+        if self.anonymous {
+            match provider {
+                Provider::Aws => push("aws_skip_signature", "true"),
+                Provider::Azure => push("azure_skip_signature", "true"),
+                Provider::Gcp => return Err(unsupported("Anonymous access")),
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// The `key = value` pairs of one `[section]` of an INI file; empty if the file or section is missing.
+fn read_ini_section(path: &str, section: &str) -> Vec<(String, String)> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let mut in_section = false;
+    let mut values = Vec::new();
+    for line in contents.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            in_section = name.trim() == section;
+        } else if let Some((key, value)) = line.split_once('=').filter(|_| in_section) {
+            values.push((key.trim().to_string(), value.trim().to_string()));
+        }
+    }
+    values
+}
+
+/// Retry behaviour for reads from cloud object stores.
+///
+/// A failed read is retried with exponential backoff: the wait starts at the
+/// initial backoff and doubles after every attempt, up to the maximum. Schema
+/// validation failures are never retried.
+///
+/// # Example
+///
+/// ```ignore
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .backoff(Duration::from_secs(1), Duration::from_secs(60))
+///     .timeout(Duration::from_secs(120));
+///
+/// let df = ParquetReader::<SalesSchema>::new("s3://bucket/sales.parquet")
+///     .retry(policy)
+///     .finish()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    timeout: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            timeout: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Create a policy with 3 attempts and backoff from 500ms up to 30s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set how often a read is attempted in total (default: 3, at least 1).
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+    
+    /// Set the wait before the first retry and the cap for later ones.
+    pub fn backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.initial_backoff = initial;
+        self.max_backoff = max.max(initial);
+        self
+    }
+    
+    /// Set a timeout for each request to the object store.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    
+    /// Run `read`, retrying transient failures.
+    pub(crate) fn run<T>(&self, mut read: impl FnMut() -> PolarsResult<T>) -> PolarsResult<T> {
+        let mut backoff = self.initial_backoff;
+        let mut attempt = 1;
+        loop {
+            match read() {
+                Err(err) if attempt < self.max_attempts && is_transient(&err) => {
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(self.max_backoff);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Whether a failed read may succeed when attempted again.
+fn is_transient(err: &PolarsError) -> bool {
+    matches!(err, PolarsError::IO { .. } | PolarsError::ComputeError(_))
+}
+
+/// Whether `path` points into a cloud object store.
+pub(crate) fn is_cloud_url(path: &str) -> bool {
+    CLOUD_SCHEMES.iter().any(|scheme| path.starts_with(scheme))
+}
+
+/// Polars cloud options for `url`, if a configuration was supplied.
+pub(crate) fn cloud_options(url: &str, config: Option<&CloudConfig>) -> PolarsResult<Option<CloudOptions>> {
+    config.map(|config| config.to_cloud_options(url)).transpose()
+}
+
+/// Polars cloud options for a read of `url`, with the retry policy's timeout applied.
+pub(crate) fn read_options(
+    url: &str,
+    config: Option<&CloudConfig>,
+    retry: Option<&RetryPolicy>,
+) -> PolarsResult<Option<CloudOptions>> {
+    match retry.and_then(|retry| retry.timeout) {
+        Some(timeout) => {
+            let config = config
+                .cloned()
+                .unwrap_or_default()
+                .option("timeout", &format!("{}ms", timeout.as_millis()));
+            cloud_options(url, Some(&config))
+        }
+        None => cloud_options(url, config),
+    }
+}
+
+/// Open a blocking writer that uploads to `url`.
+///
+/// The upload is completed when the writer is dropped.
+pub(crate) fn open_writer(url: &str, config: Option<&CloudConfig>) -> PolarsResult<CloudWriter> {
+    let options = cloud_options(url, config)?;
+    get_runtime().block_on_potential_spawn(CloudWriter::new(url, options.as_ref()))
+}
+
+/// Writes a stream of typed batches straight into a multipart upload, without local scratch space.
+///
+/// Each call to [`write`](Self::write) encodes a Parquet row group or a chunk
+/// of CSV rows, which is uploaded in parts as the buffer fills, so only the
+/// part being uploaded is held in memory. [`finish`](Self::finish) writes the
+/// Parquet footer and completes the upload.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut upload = MultipartUploadWriter::<EventSchema>::parquet("s3://exports/events.parquet", &config)?;
+/// for batch in CsvReader::<EventSchema>::new("events.csv").batched(500_000)? {
+///     upload.write(&batch?)?;
+/// }
+/// upload.finish()?;
+/// ```
+pub struct MultipartUploadWriter<Sch: Schema> {
+    writer: UploadEncoder,
+    upload: Arc<Mutex<CloudWriter>>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+enum UploadEncoder {
+    Parquet(polars::io::parquet::write::BatchedWriter<SharedUpload>),
+    Csv(polars::io::csv::write::BatchedWriter<SharedUpload>),
+}
+
+/// The encoders' handle on an upload, which [`MultipartUploadWriter::finish`]
+/// takes back to complete it.
+struct SharedUpload(Arc<Mutex<CloudWriter>>);
+
+impl Write for SharedUpload {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).write(buf)
+    }
+    
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).flush()
+    }
+}
+
+impl<Sch: Schema> MultipartUploadWriter<Sch> {
+    /// Start a Parquet upload to `url`.
+    pub fn parquet(url: &str, config: &CloudConfig) -> PolarsResult<Self> {
+        let upload = Arc::new(Mutex::new(open_writer(url, Some(config))?));
+        let writer = polars::prelude::ParquetWriter::new(SharedUpload(upload.clone()))
+            .batched(&Sch::schema())?;
+        Ok(Self {
+            writer: UploadEncoder::Parquet(writer),
+            upload,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Start a CSV upload to `url`, with a header row.
+    pub fn csv(url: &str, config: &CloudConfig) -> PolarsResult<Self> {
+        let upload = Arc::new(Mutex::new(open_writer(url, Some(config))?));
+        let writer = polars::prelude::CsvWriter::new(SharedUpload(upload.clone()))
+            .include_header(true)
+            .batched(&Sch::schema())?;
+        Ok(Self {
+            writer: UploadEncoder::Csv(writer),
+            upload,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Encode the schema columns of a frame and upload them as they fill a part.
+    pub fn write(&mut self, df: &TypedDataFrame<Sch>) -> PolarsResult<()> {
+        let batch = df.inner().select(Sch::column_names())?;
+        match &mut self.writer {
+            UploadEncoder::Parquet(writer) => writer.write_batch(&batch),
+            UploadEncoder::Csv(writer) => writer.write_batch(&batch),
+        }
+    }
+    
+    /// Write any trailing metadata, upload the last part and complete the upload.
+    ///
+    /// Fails if any part or the completion of the upload fails; the object
+    /// then does not exist.
+    pub fn finish(self) -> PolarsResult<()> {
+        if let UploadEncoder::Parquet(writer) = &self.writer {
+            writer.finish()?;
+        }
+        drop(self.writer);
+        let upload = Arc::try_unwrap(self.upload)
+            .map_err(|_| PolarsError::ComputeError("The upload is still in use by its encoder".into()))?;
+        let mut upload = upload.into_inner().unwrap_or_else(|e| e.into_inner());
+        upload.close()
+    }
+}
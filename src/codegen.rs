@@ -0,0 +1,178 @@
+//! Generating `define_schema!` source from existing data files.
+
+use polars::prelude::*;
+use std::path::Path;
+
+/// Rows sampled when inferring types from CSV and JSON files.
+const INFER_ROWS: usize = 1000;
+
+/// Inspect a data file and generate the `define_schema!` invocation describing it.
+///
+/// The format is chosen by extension: `csv`, `parquet`, `json`, `ndjson`/`jsonl`
+/// and `arrow`/`ipc`/`feather`. CSV and JSON types are inferred from the first
+/// rows. The schema is named after the file, e.g. `order_lines.csv` becomes
+/// `OrderLinesSchema`.
+///
+/// Columns whose names are not valid Rust identifiers, and columns whose type
+/// has no [`ColumnType`](crate::schema::ColumnType), are flagged with comments
+/// so they can be fixed by hand.
+///
+/// # Example
+///
+/// ```ignore
+/// println!("{}", infer_schema_code("data/orders.parquet")?);
+/// ```
+pub fn infer_schema_code(path: impl AsRef<Path>) -> PolarsResult<String> {
+    let path = path.as_ref();
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let schema = match extension.as_str() {
+        "csv" => frame_schema(
+            CsvReadOptions::default()
+                .with_n_rows(Some(INFER_ROWS))
+                .try_into_reader_with_file_path(Some(path.to_path_buf()))?
+                .finish()?,
+        ),
+        #[cfg(feature = "parquet")]
+        "parquet" => {
+            let arrow_schema = polars::prelude::ParquetReader::new(std::fs::File::open(path)?).schema()?;
+            polars::prelude::Schema::from_arrow_schema(&arrow_schema)
+        }
+        #[cfg(feature = "json")]
+        "json" => frame_schema(
+            polars::prelude::JsonReader::new(std::fs::File::open(path)?)
+                .infer_schema_len(std::num::NonZeroUsize::new(INFER_ROWS))
+                .finish()?,
+        ),
+        #[cfg(feature = "json")]
+        "ndjson" | "jsonl" => frame_schema(
+            JsonLineReader::new(std::fs::File::open(path)?)
+                .with_n_rows(Some(INFER_ROWS))
+                .finish()?,
+        ),
+        #[cfg(feature = "ipc")]
+        "arrow" | "ipc" | "feather" => {
+            let arrow_schema = polars::prelude::IpcReader::new(std::fs::File::open(path)?).schema()?;
+            polars::prelude::Schema::from_arrow_schema(&arrow_schema)
+        }
+        other => {
+            return Err(PolarsError::InvalidOperation(
+                format!("Cannot infer a schema from '.{}' files", other).into()
+            ));
+        }
+    };
+    
+    let stem = path.file_stem().and_then(|stem| stem.to_str()).unwrap_or("Inferred");
+    Ok(schema_code(&format!("{}Schema", pascal_case(stem)), &schema))
+}
+
+/// Generate the `define_schema!` invocation for a Polars schema.
+pub fn schema_code(name: &str, schema: &polars::prelude::Schema) -> String {
+    let mut code = format!("typed_polars::define_schema! {{\n    {} {{\n", name);
+    for (column, dtype) in schema.iter() {
+        let Some(rust_type) = rust_type(dtype) else {
+            code.push_str(&format!(
+                "        // \"{}\": {:?} has no ColumnType and is omitted\n",
+                column, dtype
+            ));
+            continue;
+        };
+        let field = field_name(column);
+        if field != column.as_str() {
+            code.push_str(&format!(
+                "        // \"{}\" is not a valid field name; rename the column to `{}`\n",
+                column, field
+            ));
+        }
+        code.push_str(&format!("        {}: {},\n", field, rust_type));
+    }
+    code.push_str("    }\n}\n");
+    code
+}
+
+fn frame_schema(df: DataFrame) -> polars::prelude::Schema {
+    df.schema().iter_fields().collect()
+}
+
+/// The Rust type whose `ColumnType` is `dtype`.
+fn rust_type(dtype: &DataType) -> Option<&'static str> {
+    let rust_type = match dtype {
+        DataType::Int8 => "i8",
+        DataType::Int16 => "i16",
+        DataType::Int32 => "i32",
+        DataType::Int64 => "i64",
+        DataType::UInt8 => "u8",
+        DataType::UInt16 => "u16",
+        DataType::UInt32 => "u32",
+        DataType::UInt64 => "u64",
+        DataType::Float32 => "f32",
+        DataType::Float64 => "f64",
+        DataType::Boolean => "bool",
+        DataType::String => "String",
+        DataType::Date => "typed_polars::chrono::NaiveDate",
+        DataType::Datetime(TimeUnit::Microseconds, None) => "typed_polars::chrono::NaiveDateTime",
+        DataType::Time => "typed_polars::chrono::NaiveTime",
+        #[cfg(feature = "dtype-full")]
+        DataType::Duration(TimeUnit::Microseconds) => "typed_polars::chrono::TimeDelta",
+        #[cfg(feature = "dtype-full")]
+        DataType::Null => "typed_polars::schema::Null",
+        #[cfg(feature = "dtype-full")]
+        DataType::Categorical(_, CategoricalOrdering::Physical) => "typed_polars::schema::Categorical",
+        #[cfg(feature = "dtype-full")]
+        DataType::Binary => "typed_polars::schema::Binary",
+        _ => return None,
+    };
+    Some(rust_type)
+}
+
+/// A snake_case identifier for a column name.
+fn field_name(column: &str) -> String {
+    let mut field = String::new();
+    let mut previous_lower = false;
+    for c in column.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && previous_lower {
+                field.push('_');
+            }
+            field.push(c.to_ascii_lowercase());
+            previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else {
+            if !field.is_empty() && !field.ends_with('_') {
+                field.push('_');
+            }
+            previous_lower = false;
+        }
+    }
+    let field = field.trim_end_matches('_').to_string();
+    if field.is_empty() || field.starts_with(|c: char| c.is_ascii_digit()) || is_keyword(&field) {
+        format!("col_{}", field)
+    } else {
+        field
+    }
+}
+
+fn pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().map(|c| c.to_ascii_uppercase()).unwrap_or_default();
+            std::iter::once(first).chain(chars).collect::<String>()
+        })
+        .collect()
+}
+
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern" | "false"
+            | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod" | "move"
+            | "mut" | "pub" | "ref" | "return" | "self" | "static" | "struct" | "super"
+            | "trait" | "true" | "type" | "unsafe" | "use" | "where" | "while" | "async"
+            | "await" | "dyn" | "abstract" | "become" | "box" | "do" | "final" | "macro"
+            | "override" | "priv" | "typeof" | "unsized" | "virtual" | "yield" | "try"
+    )
+}
@@ -0,0 +1,90 @@
+//! Compile-time tests against your own schemas, built on trybuild.
+//!
+//! Requires the `trybuild` feature; add typed_polars with it as a
+//! dev-dependency.
+
+use std::path::{Path, PathBuf};
+
+/// A set of source snippets that must, or must not, compile against your schemas.
+///
+/// Each snippet is written to `<dir>/<name>.rs` after a shared preamble (usually
+/// the `define_schema!` invocations under test) and compiled by trybuild when
+/// the set is run. Expected compiler output for failing snippets lives next to
+/// them as `<name>.stderr`; run once with `TRYBUILD=overwrite` to record it.
+///
+/// # Example
+///
+/// ```ignore
+/// #[test]
+/// fn schema_contracts() {
+///     SchemaCompileTests::new("tests/schema_ui")
+///         .preamble(include_str!("../src/schemas.rs"))
+///         .passes("summary_fits_export", "typed_polars::assert_subschema!(OrderSummary, OrderExport);")
+///         .fails("export_is_wider", "typed_polars::assert_subschema!(OrderExport, OrderSummary);")
+///         .run()
+///         .unwrap();
+/// }
+/// ```
+pub struct SchemaCompileTests {
+    dir: PathBuf,
+    preamble: String,
+    cases: Vec<(String, String, bool)>,
+}
+
+impl SchemaCompileTests {
+    /// Create a test set whose snippets and snapshots are kept in `dir`.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+            preamble: String::new(),
+            cases: Vec::new(),
+        }
+    }
+    
+    /// Set source placed before every snippet, e.g. the schema definitions.
+    pub fn preamble(mut self, source: &str) -> Self {
+        self.preamble = source.to_string();
+        self
+    }
+    
+    /// Add a snippet of top-level items that must compile.
+    pub fn passes(mut self, name: &str, source: &str) -> Self {
+        self.cases.push((name.to_string(), source.to_string(), true));
+        self
+    }
+    
+    /// Add a snippet of top-level items that must fail to compile.
+    pub fn fails(mut self, name: &str, source: &str) -> Self {
+        self.cases.push((name.to_string(), source.to_string(), false));
+        self
+    }
+    
+    /// Write the snippets and compile them, panicking on any unexpected outcome.
+    ///
+    /// Files are only rewritten when their contents change, so trybuild's
+    /// build cache stays warm between runs.
+    pub fn run(self) -> std::io::Result<()> {
+        std::fs::create_dir_all(&self.dir)?;
+        let tests = trybuild::TestCases::new();
+        for (name, source, passes) in &self.cases {
+            let path = self.dir.join(format!("{}.rs", name));
+            let contents = format!(
+                "// Generated by typed_polars::compile_tests::SchemaCompileTests; edit the test declaring it instead.\n\
+                 #![allow(unused)]\n\
+                 use typed_polars::prelude::*;\n\n{}\n\n{}\n\nfn main() {{}}\n",
+                self.preamble, source
+            );
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+                std::fs::write(&path, contents)?;
+            }
+            if *passes {
+                tests.pass(&path);
+            } else {
+                tests.compile_fail(&path);
+            }
+        }
+        // trybuild compiles and checks every case when the set is dropped
+        drop(tests);
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+//! Thread-pool and parallelism configuration.
+
+use polars::prelude::*;
+
+/// Bounds on the threads and batch sizes typed operations use.
+///
+/// [`run`](Self::run) bounds a single operation: it runs on Polars' thread
+/// pool when that meets the bound, and on a pool of its own otherwise.
+///
+/// Polars sizes its global pool and streaming batches once, from the
+/// `POLARS_MAX_THREADS` and `POLARS_STREAMING_CHUNK_SIZE` environment
+/// variables. These must be set before the process starts, e.g.
+/// `POLARS_MAX_THREADS=1 cargo run`; setting them from a running program is
+/// not supported. [`install_global`](Self::install_global) checks that they
+/// match the configuration.
+///
+/// # Example
+///
+/// ```ignore
+/// // Started with POLARS_MAX_THREADS=1
+/// Config::new().sequential().install_global()?;
+///
+/// // Bounded on its own, whatever the global pool
+/// let users = Config::new().max_threads(2).run(|| TypedDataFrame::<UserSchema>::new(df))?;
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    max_threads: Option<usize>,
+    chunk_size: Option<usize>,
+    sequential: bool,
+}
+
+impl Config {
+    /// No bounds: one thread per core and Polars' own batch sizes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Use at most `threads` threads; at least one.
+    pub fn max_threads(mut self, threads: usize) -> Self {
+        self.max_threads = Some(threads.max(1));
+        self
+    }
+    
+    /// Process streaming collects in batches of about `rows` rows.
+    pub fn chunk_size(mut self, rows: usize) -> Self {
+        self.chunk_size = Some(rows.max(1));
+        self
+    }
+    
+    /// Run on a single thread, e.g. for deterministic tests.
+    pub fn sequential(mut self) -> Self {
+        self.sequential = true;
+        self
+    }
+    
+    /// The number of threads this configuration allows, if bounded.
+    pub fn threads(&self) -> Option<usize> {
+        if self.sequential {
+            Some(1)
+        } else {
+            self.max_threads
+        }
+    }
+    
+    /// The streaming batch size hint, if set.
+    pub fn chunk_size_hint(&self) -> Option<usize> {
+        self.chunk_size
+    }
+    
+    /// Check that the process was started with this configuration.
+    ///
+    /// Fails unless Polars' thread pool has exactly the configured number of
+    /// threads and `POLARS_STREAMING_CHUNK_SIZE` holds the configured chunk
+    /// size. Both come from environment variables read once at launch, so a
+    /// mismatch is fixed by restarting with them set.
+    pub fn install_global(&self) -> PolarsResult<()> {
+        if let Some(threads) = self.threads() {
+            let actual = polars_core::POOL.current_num_threads();
+            if actual != threads {
+                return Err(PolarsError::ComputeError(
+                    format!("Polars' thread pool runs with {} threads instead of {}; start the process with POLARS_MAX_THREADS={}", actual, threads, threads).into()
+                ));
+            }
+        }
+        self.check_chunk_size()
+    }
+    
+    /// Run `op` with at most the configured number of threads.
+    ///
+    /// Parallel work inside `op` runs on Polars' thread pool if it meets the
+    /// bound, and on a pool of the configured size otherwise. No global
+    /// installation is needed.
+    pub fn run<R: Send>(&self, op: impl FnOnce() -> PolarsResult<R> + Send) -> PolarsResult<R> {
+        match self.threads() {
+            Some(threads) if polars_core::POOL.current_num_threads() > threads => {
+                let pool = rayon::ThreadPoolBuilder::new()
+                    .num_threads(threads)
+                    .build()
+                    .map_err(|e| PolarsError::ComputeError(format!("Failed to start a pool of {} threads: {}", threads, e).into()))?;
+                pool.install(op)
+            }
+            _ => polars_core::POOL.install(op),
+        }
+    }
+    
+    /// Fail unless the process was started with the configured streaming chunk size.
+    pub(crate) fn check_chunk_size(&self) -> PolarsResult<()> {
+        if let Some(rows) = self.chunk_size {
+            let installed = std::env::var("POLARS_STREAMING_CHUNK_SIZE").ok();
+            if installed.as_deref() != Some(rows.to_string().as_str()) {
+                return Err(PolarsError::ComputeError(
+                    format!("A streaming chunk size of {} rows is not set; start the process with POLARS_STREAMING_CHUNK_SIZE={}", rows, rows).into()
+                ));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,197 @@
+//! Cross-tabulation: contingency tables of two categorical columns.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use num_traits::NumCast;
+use std::collections::HashMap;
+use std::marker::PhantomData;
+
+/// Label of the cells for rows where a category is null.
+const NULL_LABEL: &str = "null";
+
+/// What each cell of a [`Crosstab`] holds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CrosstabValue {
+    sum: Option<&'static str>,
+}
+
+impl CrosstabValue {
+    /// The number of rows in the cell.
+    pub fn count() -> Self {
+        Self { sum: None }
+    }
+    
+    /// The sum of a numeric column over the rows in the cell; nulls are skipped.
+    pub fn sum<T: ColumnType + NumCast>(column: Column<T>) -> Self {
+        Self { sum: Some(column.name()) }
+    }
+}
+
+/// What [`Crosstab::normalize`] divides each cell by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Normalize {
+    /// The total of the cell's row, so every row sums to 1
+    Rows,
+    /// The total of the cell's column, so every column sums to 1
+    Columns,
+    /// The total of all cells, so the table sums to 1
+    Total,
+}
+
+/// A contingency table of two columns of the schema `S`.
+///
+/// Rows and columns are the distinct values of the two columns as text, in
+/// ascending order of the values with null (labelled `null`) last. Built by
+/// [`TypedDataFrame::crosstab`].
+///
+/// # Example
+///
+/// ```ignore
+/// let table = survey.crosstab(SurveySchema::region, SurveySchema::answer, CrosstabValue::count())?;
+/// let shares = table.normalize(Normalize::Rows);
+/// println!("{}", shares.to_frame()?);
+/// ```
+pub struct Crosstab<S: Schema> {
+    row_name: &'static str,
+    rows: Vec<String>,
+    columns: Vec<String>,
+    values: Vec<f64>,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> Crosstab<S> {
+    /// The row labels.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+    
+    /// The column labels.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+    
+    /// The cell of a row and column label; `None` if either does not occur.
+    pub fn get(&self, row: &str, column: &str) -> Option<f64> {
+        let i = self.rows.iter().position(|label| label == row)?;
+        let j = self.columns.iter().position(|label| label == column)?;
+        Some(self.values[i * self.columns.len() + j])
+    }
+    
+    /// The table with every cell divided by its row, column or grand total.
+    ///
+    /// Cells whose total is 0 become `NaN`.
+    pub fn normalize(&self, by: Normalize) -> Self {
+        let width = self.columns.len();
+        let mut totals = match by {
+            Normalize::Rows => vec![0.0; self.rows.len()],
+            Normalize::Columns => vec![0.0; width],
+            Normalize::Total => vec![0.0],
+        };
+        let slot = |cell: usize| match by {
+            Normalize::Rows => cell / width,
+            Normalize::Columns => cell % width,
+            Normalize::Total => 0,
+        };
+        for (cell, value) in self.values.iter().enumerate() {
+            totals[slot(cell)] += value;
+        }
+        Self {
+            row_name: self.row_name,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+            values: self.values.iter().enumerate().map(|(cell, value)| value / totals[slot(cell)]).collect(),
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// The table as a frame: a column of row labels named after the row
+    /// column, followed by one `Float64` column per column label.
+    pub fn to_frame(&self) -> PolarsResult<DataFrame> {
+        let width = self.columns.len();
+        let mut columns = vec![Series::new(self.row_name.into(), &self.rows).into_column()];
+        for (j, label) in self.columns.iter().enumerate() {
+            let values: Vec<f64> = (0..self.rows.len()).map(|i| self.values[i * width + j]).collect();
+            columns.push(Series::new(label.as_str().into(), values).into_column());
+        }
+        DataFrame::new(columns)
+    }
+}
+
+impl<S: Schema> Clone for Crosstab<S> {
+    fn clone(&self) -> Self {
+        Self {
+            row_name: self.row_name,
+            rows: self.rows.clone(),
+            columns: self.columns.clone(),
+            values: self.values.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for Crosstab<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Crosstab")
+            .field("rows", &self.rows)
+            .field("columns", &self.columns)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Tabulate the rows by the values of two columns, counting them or
+    /// summing a numeric column per cell.
+    ///
+    /// Combinations that do not occur are 0.
+    pub fn crosstab<R: ColumnType, C: ColumnType>(
+        &self,
+        row: Column<R>,
+        column: Column<C>,
+        value: CrosstabValue,
+    ) -> PolarsResult<Crosstab<S>> {
+        let (rows, row_of) = self.categories(row.name())?;
+        let (columns, column_of) = self.categories(column.name())?;
+        let amounts: Vec<f64> = match value.sum {
+            Some(name) => self
+                .inner()
+                .column(name)?
+                .as_materialized_series()
+                .cast(&DataType::Float64)?
+                .f64()?
+                .into_iter()
+                .map(|amount| amount.unwrap_or(0.0))
+                .collect(),
+            None => vec![1.0; self.height()],
+        };
+        
+        let mut values = vec![0.0; rows.len() * columns.len()];
+        for ((i, j), amount) in row_of.iter().zip(&column_of).zip(amounts) {
+            values[i * columns.len() + j] += amount;
+        }
+        Ok(Crosstab {
+            row_name: row.name(),
+            rows,
+            columns,
+            values,
+            _phantom: PhantomData,
+        })
+    }
+    
+    /// The sorted distinct labels of a column and the label index of every row.
+    fn categories(&self, name: &str) -> PolarsResult<(Vec<String>, Vec<usize>)> {
+        let series = self.inner().column(name)?.as_materialized_series();
+        let options = SortOptions { nulls_last: true, ..Default::default() };
+        let distinct = series.unique()?.sort(options)?.cast(&DataType::String)?;
+        let labels: Vec<String> = distinct
+            .str()?
+            .into_iter()
+            .map(|label| label.unwrap_or(NULL_LABEL).to_string())
+            .collect();
+        let index: HashMap<Option<&str>, usize> = distinct.str()?.into_iter().enumerate().map(|(i, label)| (label, i)).collect();
+        let text = series.cast(&DataType::String)?;
+        let positions = text.str()?.into_iter().map(|label| index[&label]).collect();
+        Ok((labels, positions))
+    }
+}
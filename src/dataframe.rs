@@ -1,8 +1,14 @@
 //! Typed wrapper around Polars DataFrame with compile-time schema validation.
 
 use polars::prelude::*;
-use crate::schema::{Schema, Column, ColumnType};
+use crate::schema::{Schema, Column, ColumnSet, ColumnType};
+use crate::error::TypedResult;
+use crate::validation;
 use crate::series::TypedSeries;
+use crate::sorted::Sorted;
+use crate::non_empty::NonEmpty;
+use crate::keyed::Keyed;
+use crate::lazy::TypedLazyFrame;
 use std::marker::PhantomData;
 
 /// A typed wrapper around a Polars DataFrame that carries schema information at compile time.
@@ -19,9 +25,32 @@ impl<S: Schema> TypedDataFrame<S> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the DataFrame doesn't match the expected schema.
+    /// Returns an error if the DataFrame doesn't match the expected schema,
+    /// or if a column not declared as `Option<T>` holds nulls. Null counts
+    /// are kept per chunk by Polars, so the null check does not scan the data;
+    /// see [`new_lenient`](Self::new_lenient) to accept nulls anywhere.
+    ///
+    /// The built-in column comparison is skipped for frames whose column
+    /// names and types exactly match a frame that already passed on this
+    /// thread; an overridden [`Schema::validate`] and the null check always
+    /// run. See [`revalidate`](Self::revalidate).
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// define_schema! {
+    ///     Contacts {
+    ///         id: i64,
+    ///         phone: Option<String>,
+    ///     }
+    /// }
+    ///
+    /// // Fails if an id is null; phone numbers may be missing
+    /// let contacts = TypedDataFrame::<Contacts>::new(df)?;
+    /// ```
     pub fn new(df: DataFrame) -> PolarsResult<Self> {
         S::validate(&df)?;
+        S::check_nulls(&df)?;
         
         Ok(Self {
             inner: df,
@@ -29,6 +58,73 @@ impl<S: Schema> TypedDataFrame<S> {
         })
     }
     
+    /// Create a new TypedDataFrame, reporting a mismatch as a structured error.
+    ///
+    /// Same as [`new`](Self::new), but a missing or mistyped column comes back
+    /// as a [`TypedPolarsError`](crate::error::TypedPolarsError) naming the
+    /// column and both types, and nulls in a non-optional column as
+    /// [`UnexpectedNulls`](crate::error::TypedPolarsError::UnexpectedNulls).
+    pub fn try_new(df: DataFrame) -> TypedResult<Self> {
+        S::check(&df)?;
+        S::check_nulls(&df)?;
+        
+        Ok(Self {
+            inner: df,
+            _phantom: PhantomData,
+        })
+    }
+    
+    /// Create a new TypedDataFrame from data read from `source`, a path or
+    /// other input, validating it as [`new`](Self::new) does.
+    ///
+    /// Errors name `source` in their message and keep their kind, e.g. a
+    /// missing column is still [`PolarsError::ColumnNotFound`].
+    pub(crate) fn from_source(df: DataFrame, source: &str) -> PolarsResult<Self> {
+        S::validate(&df).map_err(|err| err.wrap_msg(|msg| format!("{}: {}", source, msg)))?;
+        S::check_nulls(&df).map_err(|err| err.with_context(source))?;
+        
+        Ok(Self {
+            inner: df,
+            _phantom: PhantomData,
+        })
+    }
+    
+    /// Create a new TypedDataFrame, accepting nulls in every column.
+    ///
+    /// Column names and types are validated as by [`new`](Self::new), but
+    /// columns not declared as `Option<T>` may hold nulls, e.g. for raw data
+    /// that is cleaned after loading.
+    pub fn new_lenient(df: DataFrame) -> PolarsResult<Self> {
+        S::validate(&df)?;
+        
+        Ok(Self {
+            inner: df,
+            _phantom: PhantomData,
+        })
+    }
+    
+    /// Validate the frame against the schema again, bypassing the validation cache.
+    ///
+    /// Only names and types are checked, so frames built with
+    /// [`new_lenient`](Self::new_lenient) pass; see [`Schema::check_nulls`].
+    pub fn revalidate(&self) -> PolarsResult<()> {
+        validation::forget::<S>();
+        S::validate(&self.inner)
+    }
+    
+    /// Create a new TypedDataFrame keeping only the columns declared in the schema.
+    ///
+    /// Columns not in the schema are dropped instead of being carried along
+    /// untyped, and the remaining columns are put in schema order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a schema column is missing or has the wrong type.
+    pub fn new_projected(df: DataFrame) -> PolarsResult<Self> {
+        let df = df.select(S::column_names())?;
+        Self::new(df)
+    }
+    
     /// Create a new TypedDataFrame without validating the schema.
     ///
     /// # Safety
@@ -77,6 +173,11 @@ impl<S: Schema> TypedDataFrame<S> {
         self.inner.is_empty()
     }
     
+    /// Certify that the frame has at least one row; see [`NonEmpty`].
+    pub fn non_empty(self) -> PolarsResult<NonEmpty<Self>> {
+        NonEmpty::new(self)
+    }
+    
     /// Get a typed column from the DataFrame.
     ///
     /// This method provides compile-time verification that the column exists
@@ -95,6 +196,30 @@ impl<S: Schema> TypedDataFrame<S> {
         self.inner.select(columns)
     }
     
+    /// Select columns as a frame of the schema `Out`.
+    ///
+    /// `Out` must declare the selected column types in the same order, which
+    /// is checked at compile time; its column names are checked when the
+    /// result is validated.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let contacts: TypedDataFrame<ContactSchema> = users.select_as((UserSchema::id, UserSchema::email))?;
+    /// ```
+    pub fn select_as<Out, C>(&self, columns: C) -> PolarsResult<TypedDataFrame<Out>>
+    where
+        Out: Schema<Columns = C::Columns>,
+        C: ColumnSet,
+    {
+        TypedDataFrame::new(self.inner.select(columns.names())?)
+    }
+    
+    /// Get several typed columns at once, e.g. `let (ids, names) = df.columns((S::id, S::name))?`.
+    pub fn columns<C: ColumnSet>(&self, columns: C) -> PolarsResult<C::Series> {
+        columns.series(&self.inner)
+    }
+    
     /// Filter the DataFrame using a boolean mask.
     pub fn filter(&self, mask: &ChunkedArray<BooleanType>) -> PolarsResult<Self> {
         let filtered = self.inner.filter(mask)?;
@@ -108,18 +233,50 @@ impl<S: Schema> TypedDataFrame<S> {
     }
     
     /// Sort the DataFrame by a column.
+    ///
+    /// The result is a [`Sorted`] witness that operations needing sorted
+    /// input can require; it derefs to the sorted frame.
     pub fn sort<T: ColumnType>(
         &self,
         col: Column<T>,
         descending: bool,
-    ) -> PolarsResult<Self> {
+    ) -> PolarsResult<Sorted<S, T>> {
         let sorted = self.inner.sort(
             vec![col.name()],
             SortMultipleOptions::default().with_order_descending(descending)
         )?;
+        unsafe { Ok(Sorted::new_unchecked(Self::new_unchecked(sorted), col, descending)) }
+    }
+    
+    /// Sort the DataFrame by several columns, e.g. `(S::region, S::date)`.
+    pub fn sort_by<C: ColumnSet>(&self, columns: C, descending: bool) -> PolarsResult<Self> {
+        let sorted = self.inner.sort(
+            columns.names(),
+            SortMultipleOptions::default().with_order_descending(descending)
+        )?;
         unsafe { Ok(Self::new_unchecked(sorted)) }
     }
     
+    /// Keep one row per distinct combination of the given columns, in row order.
+    ///
+    /// The result is a [`Keyed`] witness that the columns are now a unique key;
+    /// it derefs to the deduplicated frame.
+    pub fn unique_by<C: ColumnSet>(&self, columns: C, keep: UniqueKeepStrategy) -> PolarsResult<Keyed<S, C>> {
+        let subset: Vec<String> = columns.names().into_iter().map(String::from).collect();
+        let unique = self.inner.unique_stable(Some(&subset), keep, None)?;
+        unsafe { Ok(Keyed::new_unchecked(Self::new_unchecked(unique), columns)) }
+    }
+    
+    /// Certify that no two rows share a value of the key columns; see [`Keyed`].
+    pub fn validate_keys<C: ColumnSet>(self, keys: C) -> PolarsResult<Keyed<S, C>> {
+        Keyed::new(self, keys)
+    }
+    
+    /// Group the rows by the given key columns; see [`TypedGroupBy::agg`].
+    pub fn group_by<C: ColumnSet>(&self, keys: C) -> TypedGroupBy<'_, S, C> {
+        TypedGroupBy { df: self, keys }
+    }
+    
     /// Get the head of the DataFrame (first n rows).
     pub fn head(&self, n: Option<usize>) -> Self {
         let head = self.inner.head(n);
@@ -138,9 +295,60 @@ impl<S: Schema> TypedDataFrame<S> {
         unsafe { Self::new_unchecked(sliced) }
     }
     
-    /// Convert the DataFrame to a lazy DataFrame for query optimization.
-    pub fn lazy(self) -> LazyFrame {
-        self.inner.lazy()
+    /// Start a typed lazy query on the DataFrame, for query optimization.
+    ///
+    /// Use [`TypedLazyFrame::into_inner`] for operations the typed query does not cover.
+    pub fn lazy(self) -> TypedLazyFrame<S> {
+        TypedLazyFrame::new(self.inner.lazy())
+    }
+    
+    /// The values of numeric columns as `f64`, column after column, for
+    /// export into numeric containers. Fails on non-numeric columns and nulls.
+    #[allow(dead_code)]
+    pub(crate) fn numeric_values(&self, names: &[&str]) -> PolarsResult<Vec<f64>> {
+        let mut values = Vec::with_capacity(self.height() * names.len());
+        for name in names {
+            let column = self.inner.column(name)?;
+            if !column.dtype().is_numeric() {
+                return Err(PolarsError::SchemaMismatch(
+                    format!("Column '{}' has non-numeric type {:?}", name, column.dtype()).into()
+                ));
+            }
+            let series = column.as_materialized_series().cast(&DataType::Float64)?;
+            values.extend(crate::series::no_null_values::<f64>(&series)?);
+        }
+        Ok(values)
+    }
+}
+
+/// Rows of a [`TypedDataFrame`] grouped by typed key columns.
+pub struct TypedGroupBy<'a, S: Schema, C: ColumnSet> {
+    df: &'a TypedDataFrame<S>,
+    keys: C,
+}
+
+impl<S: Schema, C: ColumnSet> TypedGroupBy<'_, S, C> {
+    /// Aggregate each group into a row of the schema `Out`: the key columns
+    /// followed by the aggregations, in first-seen group order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let totals: TypedDataFrame<RegionTotals> = sales
+    ///     .group_by((SalesSchema::region, SalesSchema::year))
+    ///     .agg([col(SalesSchema::amount).sum().alias("total").into_inner()])?;
+    /// ```
+    pub fn agg<Out: Schema>(self, aggs: impl IntoIterator<Item = Expr>) -> PolarsResult<TypedDataFrame<Out>> {
+        let keys: Vec<Expr> = self.keys.names().into_iter().map(col).collect();
+        let df = self
+            .df
+            .inner()
+            .clone()
+            .lazy()
+            .group_by_stable(keys)
+            .agg(aggs.into_iter().collect::<Vec<_>>())
+            .collect()?;
+        TypedDataFrame::new(df)
     }
 }
 
@@ -164,3 +372,19 @@ impl<S: Schema> std::fmt::Display for TypedDataFrame<S> {
         self.inner.fmt(f)
     }
 }
+
+/// Append the columns of `right` to `left`, suffixing the names `left` already has
+/// with `_right`, as Polars joins do.
+pub(crate) fn hstack_suffixed(mut left: DataFrame, right: DataFrame) -> PolarsResult<DataFrame> {
+    for column in right.take_columns() {
+        let column = match left.get_column_index(column.name()) {
+            Some(_) => {
+                let name = format!("{}_right", column.name());
+                column.with_name(name.into())
+            }
+            None => column,
+        };
+        left.with_column(column)?;
+    }
+    Ok(left)
+}
@@ -0,0 +1,190 @@
+//! Rendered diagnostics for schema mismatches, through miette.
+//!
+//! Requires the `diagnostics` feature.
+
+use miette::Diagnostic;
+use polars::prelude::*;
+use crate::schema::{dtype_matches, Schema};
+use crate::error::TypedPolarsError;
+use std::fmt;
+
+/// One schema column that does not match the data.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ColumnIssue {
+    /// The column is missing; `suggestion` is the closest unexpected column name, if any is close
+    Missing { column: String, expected: DataType, suggestion: Option<String> },
+    /// The column has a different type
+    WrongType { column: String, expected: DataType, actual: DataType },
+}
+
+/// Every mismatch between a DataFrame and a schema, rendered as a table.
+///
+/// Unlike [`Schema::check`], which stops at the first failure, the report lists
+/// all mismatching columns and suggests near-miss names for missing ones.
+///
+/// # Example
+///
+/// ```ignore
+/// fn main() -> miette::Result<()> {
+///     let df = load_orders()?;
+///     SchemaReport::check::<OrderSchema>(&df)?;
+///     // Error: typed_polars::schema_mismatch
+///     //   × DataFrame does not match schema: 2 columns differ
+///     //   help: column    expected  found
+///     //         user_id   Int64     missing (did you mean `userid`?)
+///     //         amount    Float64   String
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SchemaReport {
+    /// The mismatching columns, in schema order
+    pub issues: Vec<ColumnIssue>,
+}
+
+impl SchemaReport {
+    /// Compare a DataFrame against the schema, returning the report if anything differs.
+    pub fn check<S: Schema>(df: &DataFrame) -> Result<(), SchemaReport> {
+        let schema = S::schema();
+        let actual = df.schema();
+        let unexpected: Vec<&str> = actual
+            .iter_names()
+            .map(|name| name.as_str())
+            .filter(|name| !schema.contains(name))
+            .collect();
+        
+        let issues: Vec<ColumnIssue> = schema
+            .iter()
+            .filter_map(|(name, expected)| match actual.get(name) {
+                Some(dtype) if dtype_matches(expected, dtype) => None,
+                Some(dtype) => Some(ColumnIssue::WrongType {
+                    column: name.to_string(),
+                    expected: expected.clone(),
+                    actual: dtype.clone(),
+                }),
+                None => Some(ColumnIssue::Missing {
+                    column: name.to_string(),
+                    expected: expected.clone(),
+                    suggestion: closest(name, &unexpected).map(String::from),
+                }),
+            })
+            .collect();
+        
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(SchemaReport { issues })
+        }
+    }
+    
+    /// The report as a table of column, expected type and what was found.
+    pub fn table(&self) -> String {
+        let rows: Vec<[String; 3]> = self
+            .issues
+            .iter()
+            .map(|issue| match issue {
+                ColumnIssue::Missing { column, expected, suggestion } => [
+                    column.clone(),
+                    format!("{:?}", expected),
+                    match suggestion {
+                        Some(suggestion) => format!("missing (did you mean `{}`?)", suggestion),
+                        None => "missing".to_string(),
+                    },
+                ],
+                ColumnIssue::WrongType { column, expected, actual } => {
+                    [column.clone(), format!("{:?}", expected), format!("{:?}", actual)]
+                }
+            })
+            .collect();
+        
+        let header = ["column".to_string(), "expected".to_string(), "found".to_string()];
+        let width = |i: usize| rows.iter().chain([&header]).map(|row| row[i].len()).max().unwrap_or(0);
+        let (column_width, expected_width) = (width(0), width(1));
+        std::iter::once(&header)
+            .chain(&rows)
+            .map(|row| format!("{:column_width$}  {:expected_width$}  {}", row[0], row[1], row[2]))
+            .map(|line| line.trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for SchemaReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.issues.len() {
+            1 => write!(f, "DataFrame does not match schema: 1 column differs"),
+            n => write!(f, "DataFrame does not match schema: {} columns differ", n),
+        }
+    }
+}
+
+impl std::error::Error for SchemaReport {}
+
+impl Diagnostic for SchemaReport {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new("typed_polars::schema_mismatch"))
+    }
+    
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        Some(Box::new(self.table()))
+    }
+}
+
+impl From<SchemaReport> for PolarsError {
+    fn from(report: SchemaReport) -> Self {
+        PolarsError::SchemaMismatch(format!("{}\n{}", report, report.table()).into())
+    }
+}
+
+impl Diagnostic for TypedPolarsError {
+    fn code<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let code = match self {
+            TypedPolarsError::ColumnNotFound { .. } => "typed_polars::column_not_found",
+            TypedPolarsError::TypeMismatch { .. } => "typed_polars::type_mismatch",
+            TypedPolarsError::UnexpectedNulls { .. } => "typed_polars::unexpected_nulls",
+            TypedPolarsError::FingerprintMismatch { .. } => "typed_polars::fingerprint_mismatch",
+            TypedPolarsError::Multiple(_) => "typed_polars::schema_mismatch",
+            TypedPolarsError::Polars(_) => "typed_polars::polars",
+        };
+        Some(Box::new(code))
+    }
+    
+    fn help<'a>(&'a self) -> Option<Box<dyn fmt::Display + 'a>> {
+        let help = match self {
+            TypedPolarsError::ColumnNotFound { .. } | TypedPolarsError::Multiple(_) => {
+                "use SchemaReport::check for a table of mismatching columns with suggestions"
+            }
+            TypedPolarsError::TypeMismatch { .. } => "cast the column, or read with `.coerce(true)` to cast to the schema",
+            TypedPolarsError::UnexpectedNulls { .. } => "declare the column as `Option<T>`, or fill or drop the nulls",
+            TypedPolarsError::FingerprintMismatch { .. } => "the data was written under another schema or `#[version = N]`",
+            TypedPolarsError::Polars(_) => return None,
+        };
+        Some(Box::new(help))
+    }
+}
+
+/// The candidate closest to `name` by edit distance, if it is close enough to be a likely typo.
+fn closest<'a>(name: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let limit = (name.len() / 3).max(2);
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(&name.to_lowercase(), &candidate.to_lowercase()), *candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings, by characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
@@ -0,0 +1,184 @@
+//! Data dictionaries: publishable documentation generated from schemas.
+
+use polars::prelude::*;
+use crate::schema::{schema_name, Schema};
+use crate::anonymize::PiiStrategy;
+
+/// One column of a [`DataDictionary`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnEntry {
+    /// The column name
+    pub name: String,
+    /// The column type
+    pub dtype: DataType,
+    /// The declared `#[format]`, `#[range]` and `#[pii]` attributes, e.g. `range = 0 ..= 130`
+    pub constraints: Vec<String>,
+    /// The column's doc comment, or an empty string
+    pub description: String,
+}
+
+/// The columns of a schema with their types, constraints and doc comments.
+///
+/// Built by [`Schema::data_dictionary`] from the `define_schema!` declaration,
+/// so documentation published from it cannot drift from the code.
+///
+/// # Example
+///
+/// ```ignore
+/// std::fs::write("docs/users.md", UserSchema::data_dictionary().to_markdown())?;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataDictionary {
+    /// The schema name
+    pub name: String,
+    /// The schema's doc comment, or an empty string
+    pub description: String,
+    /// The schema version
+    pub version: u32,
+    /// The schema fingerprint stored in written files
+    pub fingerprint: String,
+    /// One entry per column, in schema order
+    pub columns: Vec<ColumnEntry>,
+}
+
+impl DataDictionary {
+    /// The data dictionary of the schema `S`.
+    pub fn of<S: Schema>() -> Self {
+        let formats = S::column_formats();
+        let ranges = S::column_ranges();
+        let pii = S::column_pii();
+        let docs = S::column_docs();
+        let columns = S::schema()
+            .iter()
+            .map(|(name, dtype)| {
+                let name = name.as_str();
+                let mut constraints = Vec::new();
+                if let Some((_, format)) = formats.iter().find(|(column, _)| *column == name) {
+                    constraints.push(format!("format = {:?}", format));
+                }
+                if let Some((_, min, max)) = ranges.iter().find(|(column, _, _)| *column == name) {
+                    constraints.push(format!("range = {} ..= {}", min, max));
+                }
+                if let Some((_, strategy)) = pii.iter().find(|(column, _)| *column == name) {
+                    constraints.push(match strategy {
+                        PiiStrategy::Hash => "pii(hash)".to_string(),
+                        PiiStrategy::Truncate(length) => format!("pii(truncate = {})", length),
+                        PiiStrategy::Redact => "pii(redact)".to_string(),
+                        PiiStrategy::Bucket(width) => format!("pii(bucket = {})", width),
+                    });
+                }
+                ColumnEntry {
+                    name: name.to_string(),
+                    dtype: dtype.clone(),
+                    constraints,
+                    description: docs
+                        .iter()
+                        .find(|(column, _)| *column == name)
+                        .map(|(_, doc)| doc.clone())
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+        Self {
+            name: schema_name::<S>().to_string(),
+            description: S::description(),
+            version: S::version(),
+            fingerprint: S::fingerprint(),
+            columns,
+        }
+    }
+    
+    /// The dictionary as a Markdown section with a table of the columns.
+    pub fn to_markdown(&self) -> String {
+        let cell = |text: &str| text.replace('|', "\\|").replace('\n', "<br>");
+        let mut markdown = format!("# {}\n\n", self.name);
+        if !self.description.is_empty() {
+            markdown.push_str(&format!("{}\n\n", self.description));
+        }
+        markdown.push_str(&format!("Version {}\n\n", self.version));
+        markdown.push_str("| Column | Type | Constraints | Description |\n|---|---|---|---|\n");
+        for column in &self.columns {
+            markdown.push_str(&format!(
+                "| `{}` | {} | {} | {} |\n",
+                column.name,
+                cell(&column.dtype.to_string()),
+                cell(&column.constraints.join(", ")),
+                cell(&column.description),
+            ));
+        }
+        markdown
+    }
+    
+    /// The dictionary as an HTML fragment: a heading, the description and a table.
+    pub fn to_html(&self) -> String {
+        let mut html = format!("<section>\n<h1>{}</h1>\n", escape_html(&self.name));
+        if !self.description.is_empty() {
+            html.push_str(&format!("<p>{}</p>\n", escape_html(&self.description)));
+        }
+        html.push_str(&format!("<p>Version {}</p>\n", self.version));
+        html.push_str("<table>\n<thead><tr><th>Column</th><th>Type</th><th>Constraints</th><th>Description</th></tr></thead>\n<tbody>\n");
+        for column in &self.columns {
+            html.push_str(&format!(
+                "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(&column.name),
+                escape_html(&column.dtype.to_string()),
+                escape_html(&column.constraints.join(", ")),
+                escape_html(&column.description),
+            ));
+        }
+        html.push_str("</tbody>\n</table>\n</section>\n");
+        html
+    }
+    
+    /// The dictionary as a JSON object, for documentation tooling.
+    pub fn to_json(&self) -> String {
+        let columns: Vec<String> = self
+            .columns
+            .iter()
+            .map(|column| {
+                let constraints: Vec<String> = column.constraints.iter().map(|constraint| escape_json(constraint)).collect();
+                format!(
+                    "{{\"name\":{},\"dtype\":{},\"constraints\":[{}],\"description\":{}}}",
+                    escape_json(&column.name),
+                    escape_json(&column.dtype.to_string()),
+                    constraints.join(","),
+                    escape_json(&column.description),
+                )
+            })
+            .collect();
+        format!(
+            "{{\"name\":{},\"description\":{},\"version\":{},\"fingerprint\":{},\"columns\":[{}]}}",
+            escape_json(&self.name),
+            escape_json(&self.description),
+            self.version,
+            escape_json(&self.fingerprint),
+            columns.join(","),
+        )
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A JSON string literal, quotes included.
+fn escape_json(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
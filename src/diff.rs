@@ -0,0 +1,176 @@
+//! Row-level differences between two versions of a keyed typed frame.
+
+use polars::prelude::*;
+use crate::schema::{ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::keyed::Keyed;
+
+/// The rows inserted, deleted and changed between two versions of a frame.
+///
+/// Rows are matched on their keys. A matched row is changed if any other
+/// column differs, with nulls equal to each other; the before and after
+/// versions of changed rows are row-aligned frames of the same schema.
+///
+/// # Example
+///
+/// ```ignore
+/// let diff = yesterday.diff(&today, AccountSchema::id)?;
+/// println!("{} opened, {} closed", diff.inserted().height(), diff.deleted().height());
+/// audit_log.append(&diff.changes()?)?;
+/// ```
+pub struct FrameDiff<S: Schema> {
+    key_columns: Vec<&'static str>,
+    inserted: TypedDataFrame<S>,
+    deleted: TypedDataFrame<S>,
+    before: TypedDataFrame<S>,
+    after: TypedDataFrame<S>,
+}
+
+impl<S: Schema> FrameDiff<S> {
+    /// Rows whose keys are only in the new frame.
+    pub fn inserted(&self) -> &TypedDataFrame<S> {
+        &self.inserted
+    }
+    
+    /// Rows whose keys are only in the old frame.
+    pub fn deleted(&self) -> &TypedDataFrame<S> {
+        &self.deleted
+    }
+    
+    /// Changed rows as they were in the old frame.
+    pub fn changed_before(&self) -> &TypedDataFrame<S> {
+        &self.before
+    }
+    
+    /// Changed rows as they are in the new frame, aligned with [`changed_before`](Self::changed_before).
+    pub fn changed_after(&self) -> &TypedDataFrame<S> {
+        &self.after
+    }
+    
+    /// Whether the two frames hold the same rows.
+    pub fn is_empty(&self) -> bool {
+        self.inserted.is_empty() && self.deleted.is_empty() && self.before.is_empty()
+    }
+    
+    /// Every changed value, one row per changed cell: the key columns, then
+    /// `column`, `before` and `after`, with the values formatted as strings.
+    pub fn changes(&self) -> PolarsResult<DataFrame> {
+        let mut changes = self.before.inner().select(self.key_columns.iter().copied())?.clear();
+        for column in ["column", "before", "after"] {
+            changes.with_column(Series::new_empty(column.into(), &DataType::String))?;
+        }
+        for name in S::column_names() {
+            if self.key_columns.contains(&name) {
+                continue;
+            }
+            let before = self.before.inner().column(name)?.as_materialized_series();
+            let after = self.after.inner().column(name)?.as_materialized_series();
+            let differs = !before.equal_missing(after)?;
+            
+            let mut columns = self.before.inner().select(self.key_columns.iter().copied())?.filter(&differs)?.take_columns();
+            let height = differs.sum().unwrap_or(0) as usize;
+            columns.push(Series::new("column".into(), vec![name; height]).into_column());
+            columns.push(before.filter(&differs)?.cast(&DataType::String)?.with_name("before".into()).into_column());
+            columns.push(after.filter(&differs)?.cast(&DataType::String)?.with_name("after".into()).into_column());
+            changes.vstack_mut(&DataFrame::new(columns)?)?;
+        }
+        Ok(changes)
+    }
+}
+
+impl<S: Schema> Clone for FrameDiff<S> {
+    fn clone(&self) -> Self {
+        Self {
+            key_columns: self.key_columns.clone(),
+            inserted: self.inserted.clone(),
+            deleted: self.deleted.clone(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for FrameDiff<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrameDiff")
+            .field("inserted", &self.inserted.height())
+            .field("deleted", &self.deleted.height())
+            .field("changed", &self.before.height())
+            .finish()
+    }
+}
+
+impl<S: Schema, K: ColumnSet> Keyed<S, K> {
+    /// The differences from this frame to `new`, matching rows on the keys.
+    ///
+    /// Inserted and deleted rows keep their order in their frame; changed
+    /// rows follow the order of the old frame. A null key matches a null key,
+    /// as it does when the keys are checked for duplicates.
+    pub fn diff(&self, new: &Keyed<S, K>) -> PolarsResult<FrameDiff<S>> {
+        let key_columns = self.keys().names();
+        let on: Vec<Expr> = key_columns.iter().map(|name| col(*name)).collect();
+        let indices = |df: &DataFrame, index: &str| -> PolarsResult<LazyFrame> {
+            Ok(df.select(key_columns.iter().copied())?.with_row_index(index.into(), None)?.lazy())
+        };
+        let matched = indices(self.inner(), "__old")?
+            .join(
+                indices(new.inner(), "__new")?,
+                on.clone(),
+                on,
+                JoinArgs {
+                    join_nulls: true,
+                    ..JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns)
+                },
+            )
+            .collect()?;
+        let old_rows = matched.column("__old")?.idx()?;
+        let new_rows = matched.column("__new")?.idx()?;
+        
+        let mut deleted = Vec::new();
+        let mut inserted = Vec::new();
+        let mut pairs = Vec::new();
+        for (old_row, new_row) in old_rows.into_iter().zip(new_rows) {
+            match (old_row, new_row) {
+                (Some(old_row), Some(new_row)) => pairs.push((old_row, new_row)),
+                (Some(old_row), None) => deleted.push(old_row),
+                (None, Some(new_row)) => inserted.push(new_row),
+                (None, None) => {}
+            }
+        }
+        deleted.sort_unstable();
+        inserted.sort_unstable();
+        pairs.sort_unstable();
+        
+        let (old_matched, new_matched): (Vec<IdxSize>, Vec<IdxSize>) = pairs.into_iter().unzip();
+        let before = self.take(&IdxCa::from_vec("".into(), old_matched))?;
+        let after = new.take(&IdxCa::from_vec("".into(), new_matched))?;
+        let mut changed = BooleanChunked::full("".into(), false, before.height());
+        for name in S::column_names() {
+            if key_columns.contains(&name) {
+                continue;
+            }
+            let before = before.inner().column(name)?.as_materialized_series();
+            let after = after.inner().column(name)?.as_materialized_series();
+            changed = &changed | &!before.equal_missing(after)?;
+        }
+        
+        Ok(FrameDiff {
+            key_columns,
+            inserted: new.take(&IdxCa::from_vec("".into(), inserted))?,
+            deleted: self.take(&IdxCa::from_vec("".into(), deleted))?,
+            before: before.filter(&changed)?,
+            after: after.filter(&changed)?,
+        })
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// The differences from this frame to `new`, matching rows on `keys`.
+    ///
+    /// Fails if a key occurs twice in either frame; see [`Keyed::diff`].
+    pub fn diff<K: ColumnSet + Copy>(&self, new: &Self, keys: K) -> PolarsResult<FrameDiff<S>> {
+        let old = Keyed::new(self.clone(), keys)?;
+        let new = Keyed::new(new.clone(), keys)?;
+        old.diff(&new)
+    }
+}
@@ -0,0 +1,174 @@
+//! Categorical feature encodings producing typed numeric and indicator columns.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::series::TypedSeries;
+use std::collections::{BTreeSet, HashMap};
+
+/// Undeclared categories listed in an error before the rest are elided.
+const MAX_REPORTED_CATEGORIES: usize = 5;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Expand a categorical column into indicator columns of the schema `Out`.
+    ///
+    /// Every column of `Out` that the frame does not have must be named
+    /// `<column>_<category>`; it becomes an indicator of the rows whose
+    /// category (as text) is `<category>`, cast to its declared type, e.g.
+    /// `bool` or `u8`. Columns of `Out` that the frame has are carried over.
+    /// A category without an indicator column is an error, so the output
+    /// schema is the full list of categories; null categories set no indicator.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// define_schema! {
+    ///     EncodedPets {
+    ///         id: i64,
+    ///         species_cat: u8,
+    ///         species_dog: u8,
+    ///     }
+    /// }
+    ///
+    /// let encoded: TypedDataFrame<EncodedPets> = pets.one_hot(PetSchema::species)?;
+    /// ```
+    pub fn one_hot<T: ColumnType, Out: Schema>(&self, column: Column<T>) -> PolarsResult<TypedDataFrame<Out>> {
+        let prefix = format!("{}_", column.name());
+        let categories = self.inner().column(column.name())?.as_materialized_series().cast(&DataType::String)?;
+        let categories = categories.str()?;
+        
+        let frame_schema = self.inner().schema();
+        let out_schema = Out::schema();
+        let declared: BTreeSet<&str> = out_schema
+            .iter_names()
+            .filter(|name| !frame_schema.contains(name))
+            .filter_map(|name| name.strip_prefix(prefix.as_str()))
+            .collect();
+        let undeclared: BTreeSet<&str> = categories
+            .into_iter()
+            .flatten()
+            .filter(|category| !declared.contains(category))
+            .collect();
+        if !undeclared.is_empty() {
+            let mut listed: Vec<&str> = undeclared.iter().copied().take(MAX_REPORTED_CATEGORIES).collect();
+            if undeclared.len() > MAX_REPORTED_CATEGORIES {
+                listed.push("...");
+            }
+            return Err(PolarsError::SchemaMismatch(
+                format!(
+                    "Column '{}' has categories without an indicator column in the output schema: {}",
+                    column.name(),
+                    listed.join(", "),
+                ).into()
+            ));
+        }
+        
+        let columns = out_schema
+            .iter()
+            .map(|(name, dtype)| {
+                if frame_schema.contains(name) {
+                    return Ok(self.inner().column(name)?.clone());
+                }
+                let category = name.strip_prefix(prefix.as_str()).ok_or_else(|| PolarsError::ColumnNotFound(
+                    format!("'{}' is neither a column of the frame nor an indicator of '{}'", name, column.name()).into()
+                ))?;
+                let indicator: Vec<bool> = categories.into_iter().map(|value| value == Some(category)).collect();
+                Ok(Series::new(name.clone(), indicator).cast(dtype)?.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        TypedDataFrame::new(DataFrame::new(columns)?)
+    }
+    
+    /// Replace each category with the mean of a numeric label over its rows,
+    /// shrunk towards the overall mean.
+    ///
+    /// A category seen `n` times with label mean `m` encodes as
+    /// `(n * m + smoothing * overall) / (n + smoothing)`, so rare categories
+    /// stay close to the overall mean. Null categories are encoded as one
+    /// category; rows with a null label are ignored when computing means. The
+    /// result is named `<category>_target`.
+    ///
+    /// The means are computed from this frame, so encoding the rows a model is
+    /// trained on leaks their labels; encode held-out folds for training.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let city_target = houses.target_encode(HouseSchema::city, HouseSchema::price, 10.0)?;
+    /// let features = houses.inner().clone().with_column(city_target.into_inner())?;
+    /// ```
+    pub fn target_encode<C: ColumnType, L: ColumnType>(
+        &self,
+        category: Column<C>,
+        label: Column<L>,
+        smoothing: f64,
+    ) -> PolarsResult<TypedSeries<f64>> {
+        if smoothing.is_nan() || smoothing < 0.0 {
+            return Err(PolarsError::ComputeError(
+                format!("Smoothing must be non-negative, got {}", smoothing).into()
+            ));
+        }
+        let labels = self.inner().column(label.name())?;
+        if !labels.dtype().is_numeric() && !labels.dtype().is_bool() {
+            return Err(PolarsError::SchemaMismatch(
+                format!("Label column '{}' has type {:?}; target encoding needs a numeric label", label.name(), labels.dtype()).into()
+            ));
+        }
+        let labels = labels.as_materialized_series().cast(&DataType::Float64)?;
+        let labels = labels.f64()?;
+        let categories = self.inner().column(category.name())?.as_materialized_series().cast(&DataType::String)?;
+        let categories = categories.str()?;
+        
+        let mut sums: HashMap<Option<&str>, (f64, f64)> = HashMap::new();
+        let (mut total, mut count) = (0.0, 0.0);
+        for (category, label) in categories.into_iter().zip(labels.into_iter()) {
+            if let Some(label) = label {
+                let entry = sums.entry(category).or_insert((0.0, 0.0));
+                entry.0 += label;
+                entry.1 += 1.0;
+                total += label;
+                count += 1.0;
+            }
+        }
+        let overall = if count > 0.0 { total / count } else { f64::NAN };
+        
+        let encoded: Vec<f64> = categories
+            .into_iter()
+            .map(|category| match sums.get(&category) {
+                Some((sum, n)) => (sum + smoothing * overall) / (n + smoothing),
+                None => overall,
+            })
+            .collect();
+        Ok(TypedSeries::<f64>::from_vec(&format!("{}_target", category.name()), encoded))
+    }
+    
+    /// Hash each category into one of `n_buckets` buckets.
+    ///
+    /// Categories hash by their text with FNV-1a, so buckets are stable across
+    /// runs, processes and library versions, and categories unseen in training
+    /// still get a bucket. Null categories stay null. The result is named
+    /// `<category>_hash`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let user_bucket = events.hash_encode(EventSchema::user_agent, 1024)?;
+    /// ```
+    pub fn hash_encode<C: ColumnType>(&self, category: Column<C>, n_buckets: u32) -> PolarsResult<TypedSeries<Option<u32>>> {
+        if n_buckets == 0 {
+            return Err(PolarsError::ComputeError("Feature hashing needs at least one bucket".into()));
+        }
+        let categories = self.inner().column(category.name())?.as_materialized_series().cast(&DataType::String)?;
+        let buckets: UInt32Chunked = categories
+            .str()?
+            .into_iter()
+            .map(|category| category.map(|category| (fnv1a(category.as_bytes()) % n_buckets as u64) as u32))
+            .collect();
+        TypedSeries::new(buckets.with_name(format!("{}_hash", category.name()).into()).into_series())
+    }
+}
+
+/// 64-bit FNV-1a, a hash that is stable everywhere, unlike `std`'s default hasher.
+pub(crate) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
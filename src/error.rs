@@ -0,0 +1,168 @@
+//! Structured errors for schema validation failures.
+
+use polars::prelude::*;
+use std::fmt;
+
+/// Result type for operations that report [`TypedPolarsError`]s.
+pub type TypedResult<T> = Result<T, TypedPolarsError>;
+
+/// A validation failure with the data needed to react to it programmatically.
+///
+/// APIs returning `PolarsResult` convert these into `PolarsError`s (missing
+/// columns become `ColumnNotFound`, the others `SchemaMismatch`) with the
+/// same message; [`Schema::check`](crate::schema::Schema::check) and
+/// [`TypedDataFrame::try_new`](crate::dataframe::TypedDataFrame::try_new)
+/// return them directly. The readers build them with the file, query or
+/// other input as context, so the message names what was being read.
+///
+/// # Example
+///
+/// ```ignore
+/// match TypedDataFrame::<OrderSchema>::try_new(df) {
+///     Err(TypedPolarsError::TypeMismatch { column, actual: DataType::String, .. }) => reparse(&column),
+///     other => other?,
+/// }
+/// ```
+#[derive(Debug)]
+pub enum TypedPolarsError {
+    /// A schema column is missing from the data
+    ColumnNotFound {
+        column: String,
+        /// The file or operation being validated, if known
+        context: Option<String>,
+    },
+    /// A column's type differs from the schema
+    TypeMismatch {
+        column: String,
+        expected: DataType,
+        actual: DataType,
+        /// The file or operation being validated, if known
+        context: Option<String>,
+    },
+    /// A column not declared as `Option<T>` holds nulls
+    UnexpectedNulls {
+        column: String,
+        /// The number of nulls in the column
+        count: usize,
+        /// The file or operation being validated, if known
+        context: Option<String>,
+    },
+    /// Data was written under a different [`Schema::fingerprint`](crate::schema::Schema::fingerprint)
+    FingerprintMismatch {
+        expected: String,
+        /// The stored fingerprint, or `None` if the data carries none
+        actual: Option<String>,
+        /// The file or operation being validated, if known
+        context: Option<String>,
+    },
+    /// Several columns failed validation, in schema order
+    Multiple(Vec<TypedPolarsError>),
+    /// Any other error raised by Polars
+    Polars(PolarsError),
+}
+
+impl TypedPolarsError {
+    /// Attach the file or operation that was being validated.
+    pub fn with_context(self, context: impl Into<String>) -> Self {
+        let context: String = context.into();
+        match self {
+            TypedPolarsError::ColumnNotFound { column, .. } => {
+                TypedPolarsError::ColumnNotFound { column, context: Some(context) }
+            }
+            TypedPolarsError::TypeMismatch { column, expected, actual, .. } => {
+                TypedPolarsError::TypeMismatch { column, expected, actual, context: Some(context) }
+            }
+            TypedPolarsError::UnexpectedNulls { column, count, .. } => {
+                TypedPolarsError::UnexpectedNulls { column, count, context: Some(context) }
+            }
+            TypedPolarsError::FingerprintMismatch { expected, actual, .. } => {
+                TypedPolarsError::FingerprintMismatch { expected, actual, context: Some(context) }
+            }
+            TypedPolarsError::Multiple(errors) => TypedPolarsError::Multiple(
+                errors.into_iter().map(|err| err.with_context(context.clone())).collect()
+            ),
+            TypedPolarsError::Polars(err) => TypedPolarsError::Polars(err.context(context.into())),
+        }
+    }
+    
+    /// The column the error is about, if any.
+    pub fn column(&self) -> Option<&str> {
+        match self {
+            TypedPolarsError::ColumnNotFound { column, .. }
+            | TypedPolarsError::TypeMismatch { column, .. }
+            | TypedPolarsError::UnexpectedNulls { column, .. } => Some(column),
+            _ => None,
+        }
+    }
+    
+    fn context(&self) -> Option<&str> {
+        match self {
+            TypedPolarsError::ColumnNotFound { context, .. }
+            | TypedPolarsError::TypeMismatch { context, .. }
+            | TypedPolarsError::UnexpectedNulls { context, .. }
+            | TypedPolarsError::FingerprintMismatch { context, .. } => context.as_deref(),
+            TypedPolarsError::Multiple(_) | TypedPolarsError::Polars(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for TypedPolarsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(context) = self.context() {
+            write!(f, "{}: ", context)?;
+        }
+        match self {
+            TypedPolarsError::ColumnNotFound { column, .. } => write!(f, "Column '{}' not found in DataFrame", column),
+            TypedPolarsError::TypeMismatch { column, expected, actual, .. } => {
+                write!(f, "Column '{}' has type {:?}, expected {:?}", column, actual, expected)
+            }
+            TypedPolarsError::UnexpectedNulls { column, count, .. } => {
+                write!(f, "Column '{}' has {} nulls but is not declared as Option", column, count)
+            }
+            TypedPolarsError::FingerprintMismatch { expected, actual: Some(actual), .. } => {
+                write!(f, "Data was written with schema '{}', expected '{}'", actual, expected)
+            }
+            TypedPolarsError::FingerprintMismatch { expected, actual: None, .. } => {
+                write!(f, "Data has no schema fingerprint, expected '{}'", expected)
+            }
+            TypedPolarsError::Multiple(errors) => {
+                write!(f, "{} columns do not match the schema", errors.len())?;
+                for err in errors {
+                    write!(f, "\n  {}", err)?;
+                }
+                Ok(())
+            }
+            TypedPolarsError::Polars(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for TypedPolarsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TypedPolarsError::Polars(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<PolarsError> for TypedPolarsError {
+    fn from(err: PolarsError) -> Self {
+        TypedPolarsError::Polars(err)
+    }
+}
+
+impl From<TypedPolarsError> for PolarsError {
+    fn from(err: TypedPolarsError) -> Self {
+        match err {
+            TypedPolarsError::Polars(err) => err,
+            err @ TypedPolarsError::ColumnNotFound { .. } => PolarsError::ColumnNotFound(err.to_string().into()),
+            TypedPolarsError::Multiple(errors)
+                if errors.iter().all(|err| matches!(err, TypedPolarsError::ColumnNotFound { .. })) =>
+            {
+                PolarsError::ColumnNotFound(TypedPolarsError::Multiple(errors).to_string().into())
+            }
+            err => PolarsError::SchemaMismatch(err.to_string().into()),
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Excel (xlsx/xls/ods) reading with schema validation, and xlsx writing.
+//!
+//! Requires the `excel` feature.
+
+use calamine::{open_workbook_auto, Data, DataType as _, Reader};
+use polars::prelude::*;
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::error::TypedPolarsError;
+use std::path::Path;
+
+/// Which worksheet of a workbook to read.
+enum Sheet {
+    Index(usize),
+    Name(String),
+}
+
+/// Reader for Excel workbooks with schema validation.
+///
+/// Columns are matched by the header row and each cell is coerced to the
+/// schema type, so numbers stored as text or integers stored as floats are
+/// accepted. Empty cells become nulls. A float cell with a fractional part
+/// in an integer column is an error rather than being truncated.
+pub struct ExcelReader<Sch: Schema> {
+    path: String,
+    sheet: Sheet,
+    header_row: usize,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> ExcelReader<Sch> {
+    /// Create a new Excel reader for the given path, reading the first sheet.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_string_lossy().to_string(),
+            sheet: Sheet::Index(0),
+            header_row: 0,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    /// Select the sheet to read by name.
+    pub fn sheet_name(mut self, name: &str) -> Self {
+        self.sheet = Sheet::Name(name.to_string());
+        self
+    }
+
+    /// Select the sheet to read by its zero-based position (default: 0).
+    pub fn sheet_index(mut self, index: usize) -> Self {
+        self.sheet = Sheet::Index(index);
+        self
+    }
+
+    /// Set the zero-based row holding the column names (default: 0).
+    ///
+    /// Rows above the header are skipped; every row below it is data.
+    pub fn header_row(mut self, row: usize) -> Self {
+        self.header_row = row;
+        self
+    }
+
+    /// Read the sheet and validate it against the schema.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        let mut workbook = open_workbook_auto(&self.path).map_err(excel_error)?;
+        let range = match &self.sheet {
+            Sheet::Index(index) => workbook
+                .worksheet_range_at(*index)
+                .ok_or_else(|| PolarsError::ComputeError(
+                    format!("Sheet index {} out of range", index).into()
+                ))?
+                .map_err(excel_error)?,
+            Sheet::Name(name) => workbook.worksheet_range(name).map_err(excel_error)?,
+        };
+
+        let mut rows = range.rows().skip(self.header_row);
+        let header: Vec<String> = rows
+            .next()
+            .ok_or_else(|| PolarsError::NoData("Sheet has no header row".into()))?
+            .iter()
+            .map(|cell| cell.to_string())
+            .collect();
+        let rows: Vec<&[Data]> = rows.collect();
+
+        let schema = Sch::schema();
+        let columns = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let index = header
+                    .iter()
+                    .position(|h| h == name.as_str())
+                    .ok_or_else(|| TypedPolarsError::ColumnNotFound {
+                        column: name.to_string(),
+                        context: Some(self.path.clone()),
+                    })?;
+                let cells: Vec<Option<&Data>> = rows.iter().map(|row| row.get(index)).collect();
+                cells_to_series(name.clone(), &cells, dtype).map(|s| s.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        TypedDataFrame::from_source(DataFrame::new(columns)?, &self.path)
+    }
+}
+
+/// Convert a column of cells into a series of the given type.
+fn cells_to_series(name: PlSmallStr, cells: &[Option<&Data>], dtype: &DataType) -> PolarsResult<Series> {
+    let series = if dtype.is_integer() {
+        Series::new(name.clone(), convert_cells(&name, cells, cell_to_i64)?)
+    } else if dtype.is_float() {
+        Series::new(name.clone(), convert_cells(&name, cells, |c| c.as_f64())?)
+    } else if dtype == &DataType::Boolean {
+        Series::new(name.clone(), convert_cells(&name, cells, cell_to_bool)?)
+    } else if dtype == &DataType::String {
+        Series::new(name.clone(), convert_cells(&name, cells, |c| Some(c.to_string()))?)
+    } else {
+        return Err(PolarsError::ComputeError(
+            format!("Column '{}' has type {:?}, which is not supported for Excel input", name, dtype).into()
+        ));
+    };
+
+    series.strict_cast(dtype)
+}
+
+/// Apply `convert` to every non-empty cell, failing on the first cell it rejects.
+fn convert_cells<T>(
+    name: &str,
+    cells: &[Option<&Data>],
+    convert: impl Fn(&Data) -> Option<T>,
+) -> PolarsResult<Vec<Option<T>>> {
+    cells
+        .iter()
+        .enumerate()
+        .map(|(row, cell)| match cell {
+            None | Some(Data::Empty) => Ok(None),
+            Some(cell) => convert(cell).map(Some).ok_or_else(|| PolarsError::ComputeError(
+                format!("Cannot convert cell {:?} in column '{}' (data row {})", cell, name, row).into()
+            )),
+        })
+        .collect()
+}
+
+/// Integers, and floats or strings holding a whole number in the `i64` range.
+fn cell_to_i64(cell: &Data) -> Option<i64> {
+    let float = match cell {
+        Data::Int(i) => return Some(*i),
+        Data::Float(f) => *f,
+        Data::String(s) => return s.trim().parse::<i64>().ok(),
+        _ => return None,
+    };
+    // i64::MAX rounds up to 2^63 as a float, so the upper bound is exclusive
+    let whole = float.fract() == 0.0 && float >= i64::MIN as f64 && float < i64::MAX as f64;
+    whole.then_some(float as i64)
+}
+
+fn cell_to_bool(cell: &Data) -> Option<bool> {
+    match cell {
+        Data::Bool(b) => Some(*b),
+        Data::Int(0) => Some(false),
+        Data::Int(1) => Some(true),
+        Data::String(s) if s.eq_ignore_ascii_case("true") => Some(true),
+        Data::String(s) if s.eq_ignore_ascii_case("false") => Some(false),
+        _ => None,
+    }
+}
+
+fn excel_error(err: calamine::Error) -> PolarsError {
+    PolarsError::ComputeError(format!("Failed to read Excel workbook: {}", err).into())
+}
+
+/// Writer for xlsx workbooks with one typed frame per sheet.
+///
+/// Each sheet can hold a frame of a different schema. The formatting options
+/// apply to every sheet added after they are set.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut writer = ExcelWriter::new().bold_header(true).autofit(true);
+/// writer.add_sheet("Sales", &sales)?;
+/// writer.add_sheet("Returns", &returns)?;
+/// writer.finish("report.xlsx")?;
+/// ```
+pub struct ExcelWriter {
+    workbook: Workbook,
+    bold_header: bool,
+    freeze_header: bool,
+    autofit: bool,
+    date_format: String,
+    datetime_format: String,
+}
+
+impl Default for ExcelWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExcelWriter {
+    /// Create a writer for an empty workbook.
+    pub fn new() -> Self {
+        Self {
+            workbook: Workbook::new(),
+            bold_header: false,
+            freeze_header: false,
+            autofit: false,
+            date_format: "yyyy-mm-dd".to_string(),
+            datetime_format: "yyyy-mm-dd hh:mm:ss".to_string(),
+        }
+    }
+    
+    /// Write the header row in bold (default: false).
+    pub fn bold_header(mut self, bold: bool) -> Self {
+        self.bold_header = bold;
+        self
+    }
+    
+    /// Keep the header row visible when scrolling (default: false).
+    pub fn freeze_header(mut self, freeze: bool) -> Self {
+        self.freeze_header = freeze;
+        self
+    }
+    
+    /// Size each column to fit its contents (default: false).
+    pub fn autofit(mut self, autofit: bool) -> Self {
+        self.autofit = autofit;
+        self
+    }
+    
+    /// Set the Excel number format for date cells (default: `yyyy-mm-dd`).
+    pub fn date_format(mut self, format: &str) -> Self {
+        self.date_format = format.to_string();
+        self
+    }
+    
+    /// Set the Excel number format for datetime cells (default: `yyyy-mm-dd hh:mm:ss`).
+    pub fn datetime_format(mut self, format: &str) -> Self {
+        self.datetime_format = format.to_string();
+        self
+    }
+    
+    /// Add a sheet holding the DataFrame, with the column names as header row.
+    pub fn add_sheet<S: Schema>(&mut self, name: &str, df: &TypedDataFrame<S>) -> PolarsResult<&mut Self> {
+        let header_format = if self.bold_header {
+            Format::new().set_bold()
+        } else {
+            Format::new()
+        };
+        let date_format = Format::new().set_num_format(&self.date_format);
+        let datetime_format = Format::new().set_num_format(&self.datetime_format);
+        
+        let worksheet = self.workbook.add_worksheet();
+        worksheet.set_name(name).map_err(xlsx_error)?;
+        for (index, column) in df.inner().get_columns().iter().enumerate() {
+            let col = u16::try_from(index).map_err(|_| PolarsError::ComputeError(
+                "Too many columns for an Excel sheet".into()
+            ))?;
+            worksheet
+                .write_string_with_format(0, col, column.name().as_str(), &header_format)
+                .map_err(xlsx_error)?;
+            for (row, value) in column.as_materialized_series().iter().enumerate() {
+                let row = u32::try_from(row + 1).map_err(|_| PolarsError::ComputeError(
+                    "Too many rows for an Excel sheet".into()
+                ))?;
+                write_cell(worksheet, row, col, value, &date_format, &datetime_format)?;
+            }
+        }
+        
+        if self.freeze_header {
+            worksheet.set_freeze_panes(1, 0).map_err(xlsx_error)?;
+        }
+        if self.autofit {
+            worksheet.autofit();
+        }
+        Ok(self)
+    }
+    
+    /// Write the workbook to an xlsx file.
+    pub fn finish(mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        self.workbook.save(path.as_ref()).map_err(xlsx_error)
+    }
+    
+    /// Write the workbook into an in-memory buffer.
+    pub fn to_bytes(mut self) -> PolarsResult<Vec<u8>> {
+        self.workbook.save_to_buffer().map_err(xlsx_error)
+    }
+}
+
+/// Write one value; nulls are left as empty cells.
+fn write_cell(
+    worksheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    value: AnyValue,
+    date_format: &Format,
+    datetime_format: &Format,
+) -> PolarsResult<()> {
+    let result = match value {
+        AnyValue::Null => return Ok(()),
+        AnyValue::Boolean(b) => worksheet.write_boolean(row, col, b),
+        AnyValue::String(s) => worksheet.write_string(row, col, s),
+        AnyValue::StringOwned(s) => worksheet.write_string(row, col, s.as_str()),
+        AnyValue::Date(days) => {
+            let date = chrono::NaiveDate::default() + chrono::Duration::days(days as i64);
+            worksheet.write_datetime_with_format(row, col, date, date_format)
+        }
+        AnyValue::Datetime(value, unit, _) => {
+            let micros = match unit {
+                TimeUnit::Nanoseconds => value / 1_000,
+                TimeUnit::Microseconds => value,
+                TimeUnit::Milliseconds => value * 1_000,
+            };
+            let datetime = chrono::DateTime::from_timestamp_micros(micros)
+                .ok_or_else(|| PolarsError::ComputeError(
+                    format!("Datetime {} out of range", value).into()
+                ))?
+                .naive_utc();
+            worksheet.write_datetime_with_format(row, col, datetime, datetime_format)
+        }
+        other if other.is_numeric() => worksheet.write_number(row, col, cell_number(&other)?),
+        other => worksheet.write_string(row, col, other.to_string()),
+    };
+    result.map(|_| ()).map_err(xlsx_error)
+}
+
+/// A numeric value as the float Excel stores, failing if it does not convert
+/// or is an integer too large to be stored exactly.
+fn cell_number(value: &AnyValue) -> PolarsResult<f64> {
+    let number = value.extract::<f64>();
+    let exact = !value.is_integer() || number.zip(value.extract::<i128>()).is_some_and(|(n, i)| n as i128 == i);
+    match number {
+        Some(number) if exact => Ok(number),
+        _ => Err(PolarsError::ComputeError(
+            format!("Cannot write {} as an Excel number without losing precision", value).into()
+        )),
+    }
+}
+
+fn xlsx_error(err: XlsxError) -> PolarsError {
+    PolarsError::ComputeError(format!("Failed to write Excel workbook: {}", err).into())
+}
@@ -0,0 +1,275 @@
+//! Expectation suites: declarative data checks on typed frames.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::expr::TypedExpr;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Start an expectation on a column.
+pub fn expect<T: ColumnType>(column: Column<T>) -> ColumnExpectation<T> {
+    ColumnExpectation { column }
+}
+
+/// Start an expectation on the number of rows.
+pub fn expect_row_count() -> RowCountExpectation {
+    RowCountExpectation
+}
+
+/// Start an expectation that a boolean expression holds on every row; nulls pass.
+pub fn expect_that(description: &str, expr: TypedExpr<bool>) -> Expectation {
+    Expectation {
+        description: description.to_string(),
+        check: Check::Rows(expr.into_inner()),
+    }
+}
+
+/// One check of an [`ExpectationSuite`].
+#[derive(Clone, Debug)]
+pub struct Expectation {
+    description: String,
+    check: Check,
+}
+
+#[derive(Clone, Debug)]
+enum Check {
+    /// The row count is at least `min` and below `below`
+    RowCount { min: usize, below: usize },
+    /// The expression is not false on any row
+    Rows(Expr),
+    /// No value of the column occurs twice
+    Unique(&'static str),
+}
+
+impl Expectation {
+    /// What the expectation checks, e.g. `age between 0 and 130`.
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// Expectations on the values of one column, built with [`expect`].
+pub struct ColumnExpectation<T: ColumnType> {
+    column: Column<T>,
+}
+
+impl<T: ColumnType> ColumnExpectation<T> {
+    /// No value is null.
+    pub fn not_null(self) -> Expectation {
+        Expectation {
+            description: format!("{} not null", self.column.name()),
+            check: Check::Rows(col(self.column.name()).is_not_null()),
+        }
+    }
+    
+    /// No value occurs twice; nulls count as a value.
+    pub fn unique(self) -> Expectation {
+        Expectation {
+            description: format!("{} unique", self.column.name()),
+            check: Check::Unique(self.column.name()),
+        }
+    }
+    
+    /// Every non-null value is between `min` and `max`, inclusive.
+    pub fn between(self, min: T, max: T) -> Expectation
+    where
+        T: Literal + fmt::Debug,
+    {
+        let description = format!("{} between {:?} and {:?}", self.column.name(), min, max);
+        let value = col(self.column.name());
+        Expectation {
+            description,
+            check: Check::Rows(value.clone().gt_eq(lit(min)).and(value.lt_eq(lit(max)))),
+        }
+    }
+}
+
+/// Expectations on the number of rows, built with [`expect_row_count`].
+pub struct RowCountExpectation;
+
+impl RowCountExpectation {
+    /// More than `n` rows.
+    pub fn gt(self, n: usize) -> Expectation {
+        self.bounds(format!("row count > {}", n), n.saturating_add(1), usize::MAX)
+    }
+    
+    /// Fewer than `n` rows.
+    pub fn lt(self, n: usize) -> Expectation {
+        self.bounds(format!("row count < {}", n), 0, n)
+    }
+    
+    /// Exactly `n` rows.
+    pub fn eq(self, n: usize) -> Expectation {
+        self.bounds(format!("row count = {}", n), n, n.saturating_add(1))
+    }
+    
+    /// Between `min` and `max` rows, inclusive.
+    pub fn between(self, min: usize, max: usize) -> Expectation {
+        self.bounds(format!("row count between {} and {}", min, max), min, max.saturating_add(1))
+    }
+    
+    fn bounds(self, description: String, min: usize, below: usize) -> Expectation {
+        Expectation {
+            description,
+            check: Check::RowCount { min, below },
+        }
+    }
+}
+
+/// The outcome of one expectation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectationResult {
+    /// What the expectation checks
+    pub description: String,
+    /// Whether it held
+    pub success: bool,
+    /// The number of rows that broke it; for row counts, the actual row count
+    pub observed: usize,
+}
+
+/// The outcome of running an [`ExpectationSuite`], one result per expectation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExpectationReport {
+    /// The results, in the order the expectations were added
+    pub results: Vec<ExpectationResult>,
+}
+
+impl ExpectationReport {
+    /// Whether every expectation held.
+    pub fn success(&self) -> bool {
+        self.results.iter().all(|result| result.success)
+    }
+    
+    /// The expectations that did not hold.
+    pub fn failures(&self) -> impl Iterator<Item = &ExpectationResult> {
+        self.results.iter().filter(|result| !result.success)
+    }
+    
+    /// The report as one line per expectation.
+    pub fn table(&self) -> String {
+        self.results
+            .iter()
+            .map(|result| match result.success {
+                true => format!("PASS  {}", result.description),
+                false => format!("FAIL  {} (observed {})", result.description, result.observed),
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for ExpectationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self.failures().count();
+        writeln!(f, "{} of {} expectations failed", failed, self.results.len())?;
+        write!(f, "{}", self.table())
+    }
+}
+
+/// A set of expectations run together against frames of the schema `S`.
+///
+/// # Example
+///
+/// ```ignore
+/// let suite = ExpectationSuite::<UserSchema>::new()
+///     .expect(expect(UserSchema::age).between(0, 130))
+///     .expect(expect(UserSchema::id).unique())
+///     .expect(expect_row_count().gt(1000));
+///
+/// let report = suite.run(&users)?;
+/// if !report.success() {
+///     eprintln!("{}", report);
+/// }
+/// ```
+pub struct ExpectationSuite<S: Schema> {
+    expectations: Vec<Expectation>,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> ExpectationSuite<S> {
+    /// An empty suite.
+    pub fn new() -> Self {
+        Self {
+            expectations: Vec::new(),
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// Add an expectation.
+    pub fn expect(mut self, expectation: Expectation) -> Self {
+        self.expectations.push(expectation);
+        self
+    }
+    
+    /// The expectations, in the order they were added.
+    pub fn expectations(&self) -> &[Expectation] {
+        &self.expectations
+    }
+    
+    /// Check every expectation against a frame.
+    pub fn run(&self, df: &TypedDataFrame<S>) -> PolarsResult<ExpectationReport> {
+        let results = self
+            .expectations
+            .iter()
+            .map(|expectation| {
+                let (success, observed) = match &expectation.check {
+                    Check::RowCount { min, below } => {
+                        let rows = df.height();
+                        ((*min..*below).contains(&rows), rows)
+                    }
+                    Check::Rows(expr) => {
+                        let passed = df.inner().clone().lazy().select([expr.clone()]).collect()?;
+                        let passed = passed.get_columns()[0].as_materialized_series().cast(&DataType::Boolean)?;
+                        let failing = passed.bool()?.into_iter().filter(|passed| *passed == Some(false)).count();
+                        (failing == 0, failing)
+                    }
+                    Check::Unique(name) => {
+                        let duplicated = df.inner().select([*name])?.is_duplicated()?;
+                        let failing = duplicated.sum().unwrap_or(0) as usize;
+                        (failing == 0, failing)
+                    }
+                };
+                Ok(ExpectationResult {
+                    description: expectation.description.clone(),
+                    success,
+                    observed,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(ExpectationReport { results })
+    }
+    
+    /// Check every expectation, failing with the report if any does not hold.
+    pub fn validate(&self, df: &TypedDataFrame<S>) -> PolarsResult<()> {
+        let report = self.run(df)?;
+        if report.success() {
+            Ok(())
+        } else {
+            Err(PolarsError::ComputeError(report.to_string().into()))
+        }
+    }
+}
+
+impl<S: Schema> Default for ExpectationSuite<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S: Schema> Clone for ExpectationSuite<S> {
+    fn clone(&self) -> Self {
+        Self {
+            expectations: self.expectations.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> fmt::Debug for ExpectationSuite<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpectationSuite")
+            .field("expectations", &self.expectations)
+            .finish()
+    }
+}
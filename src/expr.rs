@@ -52,6 +52,38 @@ pub fn col<T: ColumnType>(column: Column<T>) -> TypedExpr<T> {
     TypedExpr::new(polars::prelude::col(column.name()))
 }
 
+/// Create a typed literal expression.
+pub fn lit<T: ColumnType + Literal>(value: T) -> TypedExpr<T> {
+    TypedExpr::new(polars::prelude::lit(value))
+}
+
+// Comparison operations
+impl<T: ColumnType> TypedExpr<T> {
+    pub fn eq(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.eq(other.inner))
+    }
+    
+    pub fn neq(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.neq(other.inner))
+    }
+    
+    pub fn gt(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.gt(other.inner))
+    }
+    
+    pub fn gt_eq(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.gt_eq(other.inner))
+    }
+    
+    pub fn lt(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.lt(other.inner))
+    }
+    
+    pub fn lt_eq(self, other: TypedExpr<T>) -> TypedExpr<bool> {
+        TypedExpr::new(self.inner.lt_eq(other.inner))
+    }
+}
+
 // Numeric operations
 impl TypedExpr<i32> {
     pub fn add(self, other: TypedExpr<i32>) -> Self {
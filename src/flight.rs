@@ -0,0 +1,120 @@
+//! Arrow Flight client reader with schema validation.
+//!
+//! Requires the `flight` feature.
+
+use arrow_flight::decode::FlightRecordBatchStream;
+use arrow_flight::{FlightClient, Ticket};
+use futures::StreamExt;
+use polars::io::pl_async::get_runtime;
+use polars::prelude::*;
+use tonic::transport::Endpoint;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::arrow::from_arrow_rs_batch;
+
+/// Reader for a Flight `DoGet` stream with schema validation.
+///
+/// Every record batch in the stream is validated against the schema, either
+/// while it is assembled into one frame ([`finish`](Self::finish)) or as it is
+/// yielded ([`batches`](Self::batches)). The stream is read on Polars'
+/// shared async runtime, so the reader may be used from inside another Tokio
+/// runtime.
+///
+/// # Example
+///
+/// ```ignore
+/// let trades = FlightReader::<TradeSchema>::new("http://localhost:50051", "trades/2024-06-01")
+///     .header("authorization", "Bearer ...")
+///     .finish()?;
+/// ```
+pub struct FlightReader<Sch: Schema> {
+    endpoint: String,
+    ticket: Vec<u8>,
+    headers: Vec<(String, String)>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> FlightReader<Sch> {
+    /// Create a reader that fetches `ticket` from the Flight service at `endpoint`.
+    pub fn new(endpoint: &str, ticket: impl Into<Vec<u8>>) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            ticket: ticket.into(),
+            headers: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Send an additional request header, e.g. for authentication.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_string(), value.to_string()));
+        self
+    }
+    
+    /// Fetch the whole stream and assemble it into a single frame.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        let mut df: Option<DataFrame> = None;
+        for batch in self.batches()? {
+            let batch = batch?.into_inner();
+            match df.as_mut() {
+                Some(df) => {
+                    df.vstack_mut(&batch)?;
+                }
+                None => df = Some(batch),
+            }
+        }
+        
+        TypedDataFrame::new(df.unwrap_or_else(|| DataFrame::empty_with_schema(&Sch::schema())))
+    }
+    
+    /// Start the stream and yield each record batch as a validated frame.
+    pub fn batches(self) -> PolarsResult<FlightBatches<Sch>> {
+        let Self { endpoint, ticket, headers, .. } = self;
+        let stream = get_runtime()
+            .block_on_potential_spawn(async move {
+                let channel = Endpoint::from_shared(endpoint)
+                    .map_err(flight_error)?
+                    .connect()
+                    .await
+                    .map_err(flight_error)?;
+                let mut client = FlightClient::new(channel);
+                for (key, value) in &headers {
+                    client.add_header(key, value).map_err(flight_error)?;
+                }
+                client.do_get(Ticket::new(ticket)).await.map_err(flight_error)
+            })?;
+        
+        Ok(FlightBatches {
+            stream: Some(stream),
+            _phantom: std::marker::PhantomData,
+        })
+    }
+}
+
+/// Iterator over the record batches of a Flight stream, validated against the schema.
+///
+/// Created by [`FlightReader::batches`].
+pub struct FlightBatches<Sch: Schema> {
+    // Moved into the runtime while a batch is fetched
+    stream: Option<FlightRecordBatchStream>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> Iterator for FlightBatches<Sch> {
+    type Item = PolarsResult<TypedDataFrame<Sch>>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut stream = self.stream.take()?;
+        let (batch, stream) = get_runtime().block_on_potential_spawn(async move {
+            let batch = stream.next().await;
+            (batch, stream)
+        });
+        self.stream = Some(stream);
+        let batch = batch?;
+        Some(batch.map_err(flight_error).and_then(from_arrow_rs_batch))
+    }
+}
+
+fn flight_error(err: impl std::fmt::Display) -> PolarsError {
+    PolarsError::ComputeError(format!("Flight error: {}", err).into())
+}
@@ -1,120 +1,2462 @@
 //! I/O operations for reading and writing typed DataFrames.
 
 use polars::prelude::*;
-use crate::schema::Schema;
+use crate::schema::{Column, ColumnType, Schema};
+#[cfg(all(feature = "parquet", feature = "partition_by"))]
+use crate::schema::ColumnSet;
 use crate::dataframe::TypedDataFrame;
+#[cfg(feature = "parquet")]
+use crate::expr::TypedExpr;
+use crate::lazy::TypedLazyFrame;
+use crate::manifest::Manifest;
+#[cfg(feature = "cloud")]
+use crate::cloud::{self, CloudConfig, RetryPolicy};
+use polars::io::csv::read::OwnedBatchedCsvReader;
+use polars::io::mmap::MmapBytesReader;
+use polars::io::RowIndex;
+#[cfg(feature = "ipc")]
+use polars::export::arrow::io::ipc::write::{StreamWriter, WriteOptions};
+use std::borrow::Cow;
+use std::io::{Cursor, Read, Write};
 use std::path::Path;
 
+/// Where a reader takes its input from.
+enum Source {
+    Path(String),
+    Bytes(Vec<u8>),
+    Reader(Box<dyn Read>),
+}
+
+/// An opened [`Source`] in a form Polars can read from.
+enum OpenSource {
+    File(std::fs::File),
+    Bytes(Cursor<Vec<u8>>),
+}
+
+impl Source {
+    fn from_path(path: impl AsRef<Path>) -> Self {
+        Source::Path(path.as_ref().to_string_lossy().to_string())
+    }
+    
+    /// Names the input in validation errors.
+    fn describe(&self) -> String {
+        match self {
+            Source::Path(path) => path.clone(),
+            Source::Bytes(_) => "in-memory bytes".to_string(),
+            Source::Reader(_) => "reader".to_string(),
+        }
+    }
+    
+    /// Open the file, or buffer an arbitrary reader into memory.
+    fn open(self) -> PolarsResult<OpenSource> {
+        match self {
+            Source::Path(path) => Ok(OpenSource::File(std::fs::File::open(path)?)),
+            Source::Bytes(bytes) => Ok(OpenSource::Bytes(Cursor::new(bytes))),
+            Source::Reader(mut reader) => {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes)?;
+                Ok(OpenSource::Bytes(Cursor::new(bytes)))
+            }
+        }
+    }
+}
+
+impl OpenSource {
+    fn into_handle(self) -> Box<dyn MmapBytesReader> {
+        match self {
+            OpenSource::File(file) => Box::new(file),
+            OpenSource::Bytes(bytes) => Box::new(bytes),
+        }
+    }
+}
+
+/// Rows per Parquet row group when none is set, as Polars' own writer uses.
+#[cfg(feature = "parquet")]
+const DEFAULT_ROW_GROUP_SIZE: usize = 512 * 512;
+
+/// Rows read per chunk when a reader reports progress.
+const PROGRESS_CHUNK_ROWS: usize = 64 * 1024;
+
+/// How far a read has got, passed to `on_progress` callbacks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReadProgress {
+    /// Rows read so far
+    pub rows_read: usize,
+    /// Rows the read will produce in total, when known up front
+    pub total_rows: Option<usize>,
+}
+
+type ProgressCallback = Box<dyn FnMut(ReadProgress)>;
+
+/// A row index numbering rows by their position in the file, given how many leading rows were skipped.
+fn file_row_index(row_index: &Option<RowIndex>, skipped: usize) -> Option<RowIndex> {
+    row_index.as_ref().map(|row_index| RowIndex {
+        name: row_index.name.clone(),
+        offset: row_index.offset + skipped as IdxSize,
+    })
+}
+
+/// The schema's columns, plus the row index column if one is added.
+fn projection_with_index<Sch: Schema>(row_index: &Option<RowIndex>) -> Vec<Expr> {
+    row_index
+        .iter()
+        .map(|row_index| col(row_index.name.clone()))
+        .chain(Sch::column_names().into_iter().map(col))
+        .collect()
+}
+
+/// The frame with chunk boundaries lined up across columns, as the batched
+/// Polars writers expect.
+///
+/// Writers hand frames to Polars by reference, so the data is only copied
+/// when its columns are chunked differently and must be realigned.
+fn aligned_chunks(df: &DataFrame) -> Cow<'_, DataFrame> {
+    if df.should_rechunk() {
+        let mut aligned = df.clone();
+        aligned.align_chunks_par();
+        Cow::Owned(aligned)
+    } else {
+        Cow::Borrowed(df)
+    }
+}
+
+/// Append `chunk` to the frame being assembled.
+fn append_chunk(df: &mut Option<DataFrame>, chunk: DataFrame) -> PolarsResult<()> {
+    match df.as_mut() {
+        Some(df) => {
+            df.vstack_mut(&chunk)?;
+        }
+        None => *df = Some(chunk),
+    }
+    Ok(())
+}
+
+impl Source {
+    /// Decompress the whole input into memory; uncompressed input is left as is.
+    fn decompress(self, compression: CsvCompression) -> PolarsResult<Source> {
+        let reader: Box<dyn Read> = match (compression, self) {
+            (CsvCompression::Uncompressed, source) => return Ok(source),
+            (_, Source::Path(path)) => Box::new(std::fs::File::open(path)?),
+            (_, Source::Bytes(bytes)) => Box::new(Cursor::new(bytes)),
+            (_, Source::Reader(reader)) => reader,
+        };
+        let mut decoder: Box<dyn Read> = match compression {
+            CsvCompression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(reader)),
+            CsvCompression::Zstd => Box::new(zstd::stream::read::Decoder::new(reader)?),
+            CsvCompression::Uncompressed => reader,
+        };
+        let mut bytes = Vec::new();
+        decoder.read_to_end(&mut bytes)?;
+        Ok(Source::Bytes(bytes))
+    }
+}
+
+/// Memory-map a local file for reading.
+#[cfg(all(feature = "fs", feature = "parquet"))]
+fn map_file(path: &str) -> PolarsResult<Cursor<memmap2::Mmap>> {
+    let file = std::fs::File::open(path)?;
+    // Safety: the map is only read; as with any mapping, the file must not be
+    // truncated by another process while it is being decoded
+    let map = unsafe { memmap2::Mmap::map(&file)? };
+    Ok(Cursor::new(map))
+}
+
+/// Without the `fs` feature nothing is memory-mapped; the file is read into memory instead.
+#[cfg(all(not(feature = "fs"), feature = "parquet"))]
+fn map_file(path: &str) -> PolarsResult<Cursor<Vec<u8>>> {
+    Ok(Cursor::new(std::fs::read(path)?))
+}
+
+/// Whether `path` points into a cloud object store rather than the local filesystem.
+fn is_cloud_path(_path: &str) -> bool {
+    #[cfg(feature = "cloud")]
+    {
+        cloud::is_cloud_url(_path)
+    }
+    #[cfg(not(feature = "cloud"))]
+    {
+        false
+    }
+}
+
+/// Load the manifest of a reader's source when verification is requested.
+fn source_manifest(source: &Source, verify: bool) -> PolarsResult<Option<Manifest>> {
+    match source {
+        _ if !verify => Ok(None),
+        Source::Path(path) if !is_cloud_path(path) => Manifest::read(path).map(Some),
+        _ => Err(PolarsError::InvalidOperation(
+            "Manifest verification requires a local file path".into()
+        )),
+    }
+}
+
+/// Compute the manifest a writer should store for `path`, if requested.
+fn writer_manifest<Sch: Schema>(
+    df: &TypedDataFrame<Sch>,
+    path: &Path,
+    write: bool,
+) -> PolarsResult<Option<Manifest>> {
+    if !write {
+        return Ok(None);
+    }
+    if path.to_str().is_some_and(is_cloud_path) {
+        return Err(PolarsError::InvalidOperation(
+            "Manifests can only be written next to local files".into()
+        ));
+    }
+    Manifest::compute(df).map(Some)
+}
+
+/// Compression of a CSV file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CsvCompression {
+    /// Plain text
+    Uncompressed,
+    /// gzip, including files of several concatenated gzip members
+    Gzip,
+    /// Zstandard
+    Zstd,
+}
+
+impl CsvCompression {
+    /// Detect the compression from a file extension: `.gz` or `.zst`/`.zstd`.
+    fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("gz") => CsvCompression::Gzip,
+            Some("zst") | Some("zstd") => CsvCompression::Zstd,
+            _ => CsvCompression::Uncompressed,
+        }
+    }
+}
+
 /// Reader for CSV files with schema validation.
 pub struct CsvReader<Sch: Schema> {
-    path: String,
+    source: Source,
     has_header: bool,
+    separator: u8,
+    quote_char: Option<u8>,
+    comment_prefix: Option<String>,
+    skip_rows: usize,
+    skip_rows_after_header: usize,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    null_values: Option<Vec<String>>,
+    encoding: CsvEncoding,
+    ignore_errors: bool,
+    coerce: bool,
+    project: bool,
+    compression: Option<CsvCompression>,
+    verify_manifest: bool,
+    formats: Vec<(String, String)>,
+    progress: Option<ProgressCallback>,
+    #[cfg(feature = "cloud")]
+    cloud: Option<CloudConfig>,
+    #[cfg(feature = "cloud")]
+    retry: Option<RetryPolicy>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> CsvReader<Sch> {
+    /// Create a new CSV reader for the given path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new CSV reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new CSV reader over an arbitrary reader, e.g. an HTTP response body.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    /// Create a new CSV reader over standard input, for use in Unix pipelines.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_stdin() -> Self {
+        Self::from_reader(std::io::stdin())
+    }
+    
+    fn from_source(source: Source) -> Self {
+        Self {
+            source,
+            has_header: true,
+            separator: b',',
+            quote_char: Some(b'"'),
+            comment_prefix: None,
+            skip_rows: 0,
+            skip_rows_after_header: 0,
+            n_rows: None,
+            row_index: None,
+            null_values: None,
+            encoding: CsvEncoding::Utf8,
+            ignore_errors: false,
+            coerce: false,
+            project: false,
+            compression: None,
+            verify_manifest: false,
+            formats: Sch::column_formats()
+                .into_iter()
+                .map(|(name, format)| (name.to_string(), format.to_string()))
+                .collect(),
+            progress: None,
+            #[cfg(feature = "cloud")]
+            cloud: None,
+            #[cfg(feature = "cloud")]
+            retry: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Set whether the CSV has a header row (default: true).
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+    
+    /// Set the field delimiter (default: `,`).
+    pub fn separator(mut self, separator: u8) -> Self {
+        self.separator = separator;
+        self
+    }
+    
+    /// Set the quote character, or `None` to disable quoting (default: `"`).
+    pub fn quote_char(mut self, quote_char: Option<u8>) -> Self {
+        self.quote_char = quote_char;
+        self
+    }
+    
+    /// Skip lines starting with the given prefix, e.g. `#`.
+    pub fn comment_prefix(mut self, prefix: &str) -> Self {
+        self.comment_prefix = Some(prefix.to_string());
+        self
+    }
+    
+    /// Skip this many lines at the start of the file, before the header (default: 0).
+    pub fn skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+    
+    /// Skip this many data rows after the header, e.g. to read a later page (default: 0).
+    ///
+    /// Skipped rows are only scanned for line breaks, not parsed.
+    pub fn skip_rows_after_header(mut self, skip_rows: usize) -> Self {
+        self.skip_rows_after_header = skip_rows;
+        self
+    }
+    
+    /// Stop after reading this many rows.
+    pub fn n_rows(mut self, n_rows: usize) -> Self {
+        self.n_rows = Some(n_rows);
+        self
+    }
+    
+    /// Add a row index column numbering data rows from `offset`.
+    ///
+    /// Rows skipped with [`skip_rows_after_header`](Self::skip_rows_after_header)
+    /// are counted, so the index is the row's position in the file.
+    pub fn with_row_index(mut self, name: &str, offset: IdxSize) -> Self {
+        self.row_index = Some(RowIndex { name: name.into(), offset });
+        self
+    }
+    
+    /// Treat these values as null in every column, e.g. `&["NA", "-"]`.
+    pub fn null_values(mut self, values: &[&str]) -> Self {
+        self.null_values = Some(values.iter().map(|v| v.to_string()).collect());
+        self
+    }
+    
+    /// Set the text encoding (default: UTF-8).
+    ///
+    /// `CsvEncoding::LossyUtf8` replaces invalid sequences instead of failing.
+    pub fn encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+    
+    /// Set whether to turn unparseable values into nulls instead of failing (default: false).
+    pub fn ignore_errors(mut self, ignore_errors: bool) -> Self {
+        self.ignore_errors = ignore_errors;
+        self
+    }
+    
+    /// Infer column types and cast them to the schema types (default: false).
+    ///
+    /// Values such as `1.0` in an integer column are accepted instead of failing
+    /// the parse. Use [`finish_with_report`](Self::finish_with_report) to see
+    /// which columns were cast. Lazy scans always parse with the schema.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+    
+    /// Only read the columns declared in the schema (default: false).
+    ///
+    /// Other columns in the file are skipped while parsing, so wide files
+    /// produce exactly-schema frames. When disabled the file must contain only
+    /// the schema's columns.
+    pub fn project_to_schema(mut self, project: bool) -> Self {
+        self.project = project;
+        self
+    }
+    
+    /// Set the compression of the input (default: detected from the file extension).
+    ///
+    /// Compressed input is decompressed into memory before parsing. Paths
+    /// ending in `.gz` are read as gzip and `.zst`/`.zstd` as Zstandard.
+    pub fn compression(mut self, compression: CsvCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+    
+    /// Verify the read against the manifest stored next to the file (default: false).
+    ///
+    /// The manifest is written by [`CsvWriter::write_manifest`]; see
+    /// [`Manifest`]. Reads that skip or limit rows will not match it.
+    pub fn verify_manifest(mut self, verify: bool) -> Self {
+        self.verify_manifest = verify;
+        self
+    }
+    
+    /// Parse a temporal column with the given format, e.g. `%d/%m/%Y`.
+    ///
+    /// Overrides a `#[format = "..."]` declared on the schema.
+    pub fn format<T: ColumnType>(mut self, column: Column<T>, format: &str) -> Self {
+        self.formats.retain(|(name, _)| name != column.name());
+        self.formats.push((column.name().to_string(), format.to_string()));
+        self
+    }
+    
+    /// Set the credentials used when the path is a cloud URL.
+    #[cfg(feature = "cloud")]
+    pub fn cloud_config(mut self, config: CloudConfig) -> Self {
+        self.cloud = Some(config);
+        self
+    }
+    
+    /// Retry reads from a cloud URL that fail transiently.
+    #[cfg(feature = "cloud")]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+    
+    /// Run a read of `path`, retrying it under the reader's policy if the path is remote.
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+    fn remote<T>(&self, path: &str, mut read: impl FnMut() -> PolarsResult<T>) -> PolarsResult<T> {
+        #[cfg(feature = "cloud")]
+        if let Some(retry) = self.retry.as_ref().filter(|_| is_cloud_path(path)) {
+            return retry.run(read);
+        }
+        read()
+    }
+    
+    /// Call `callback` with the running row count as the file is read.
+    ///
+    /// The file is then parsed in chunks, reporting after each one; the total
+    /// is not known up front. Not reported for cloud URLs.
+    pub fn on_progress(mut self, callback: impl FnMut(ReadProgress) + 'static) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+    
+    /// Read the CSV file and validate it against the schema.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        self.finish_with_report().map(|(df, _)| df)
+    }
+    
+    /// Read the CSV file and also report the columns cast in coercion mode.
+    pub fn finish_with_report(self) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        let manifest = source_manifest(&self.source, self.verify_manifest)?;
+        let (df, coercions) = self.read_with_report()?;
+        if let Some(manifest) = manifest {
+            manifest.verify(&df)?;
+        }
+        Ok((df, coercions))
+    }
+    
+    fn read_with_report(mut self) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        let source = self.source.describe();
+        if let Source::Path(path) = &self.source {
+            if is_cloud_path(path) {
+                let df = self.remote(path, || self.lazy_frame(path)?.collect())?;
+                return Ok((TypedDataFrame::from_source(df, &source)?, Vec::new()));
+            }
+        }
+        
+        let options = self.read_options();
+        let format_exprs = self.format_exprs()?;
+        let mut df = match self.progress.take() {
+            Some(progress) => {
+                let empty = DataFrame::empty_with_schema(&self.parse_schema());
+                read_csv_chunked(options, self.open_source()?.into_handle(), empty, progress)?
+            }
+            None => match self.open_source()? {
+                OpenSource::File(file) => options.into_reader_with_file_handle(file).finish()?,
+                OpenSource::Bytes(bytes) => options.into_reader_with_file_handle(bytes).finish()?,
+            },
+        };
+        if !format_exprs.is_empty() {
+            df = df.lazy().with_columns(format_exprs).collect()?;
+        }
+        
+        finish_coercion(df, self.coerce, &source)
+    }
+    
+    /// Options for the eager Polars CSV reader.
+    fn read_options(&self) -> CsvReadOptions {
+        let parse_options = CsvParseOptions::default()
+            .with_separator(self.separator)
+            .with_quote_char(self.quote_char)
+            .with_comment_prefix(self.comment_prefix.as_deref())
+            .with_null_values(self.polars_null_values())
+            .with_encoding(self.encoding);
+        
+        let mut options = CsvReadOptions::default()
+            .with_has_header(self.has_header)
+            .with_skip_rows(self.skip_rows)
+            .with_skip_rows_after_header(self.skip_rows_after_header)
+            .with_n_rows(self.n_rows)
+            .with_row_index(file_row_index(&self.row_index, self.skip_rows_after_header))
+            .with_ignore_errors(self.ignore_errors)
+            .with_parse_options(parse_options);
+        if self.project {
+            let columns = Sch::column_names().into_iter().map(PlSmallStr::from).collect();
+            options = options.with_columns(Some(columns));
+        }
+        
+        if self.coerce {
+            // Infer everything except the formatted columns, which must stay strings
+            let overrides = self
+                .formats
+                .iter()
+                .map(|(name, _)| Field::new(name.as_str().into(), DataType::String));
+            options.with_schema_overwrite(Some(std::sync::Arc::new(
+                polars::prelude::Schema::from_iter(overrides)
+            )))
+        } else if self.project {
+            options.with_schema_overwrite(Some(std::sync::Arc::new(self.parse_schema())))
+        } else {
+            options.with_schema(Some(std::sync::Arc::new(self.parse_schema())))
+        }
+    }
+    
+    /// Read the CSV in batches of roughly `batch_size` rows.
+    ///
+    /// Each batch is validated against the schema as it is produced, so huge
+    /// files can be processed without loading them whole.
+    pub fn batched(mut self, batch_size: usize) -> PolarsResult<CsvBatchedReader<Sch>> {
+        let options = self.read_options().with_chunk_size(batch_size);
+        let format_exprs = self.format_exprs()?;
+        let source = self.source.describe();
+        let handle = self.open_source()?.into_handle();
+        
+        Ok(CsvBatchedReader {
+            reader: options.into_reader_with_file_handle(handle).batched(None)?,
+            format_exprs,
+            coerce: self.coerce,
+            source,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Scan the CSV file lazily with these options.
+    ///
+    /// The schema is validated when the resulting frame is collected.
+    pub fn scan(self) -> PolarsResult<TypedLazyFrame<Sch>> {
+        if self.resolved_compression() != CsvCompression::Uncompressed {
+            return Err(PolarsError::InvalidOperation(
+                "Lazy scans of compressed CSV are not supported".into()
+            ));
+        }
+        match &self.source {
+            Source::Path(path) => Ok(TypedLazyFrame::new(self.lazy_frame(path)?)),
+            _ => Err(PolarsError::InvalidOperation(
+                "Lazy scans require a file path".into()
+            )),
+        }
+    }
+    
+    /// The explicit compression, or the one implied by the path's extension.
+    fn resolved_compression(&self) -> CsvCompression {
+        match (&self.source, self.compression) {
+            (_, Some(compression)) => compression,
+            (Source::Path(path), None) => CsvCompression::from_path(Path::new(path)),
+            _ => CsvCompression::Uncompressed,
+        }
+    }
+    
+    /// Open the input, decompressing it first if needed.
+    fn open_source(&mut self) -> PolarsResult<OpenSource> {
+        let compression = self.resolved_compression();
+        let source = std::mem::replace(&mut self.source, Source::Bytes(Vec::new()));
+        source.decompress(compression)?.open()
+    }
+    
+    /// A lazy Polars CSV scan over `path` with the same options.
+    fn lazy_frame(&self, path: &str) -> PolarsResult<LazyFrame> {
+        #[allow(unused_mut)]
+        let schema = Some(std::sync::Arc::new(self.parse_schema()));
+        let mut reader = LazyCsvReader::new(path)
+            .with_has_header(self.has_header)
+            .with_separator(self.separator)
+            .with_quote_char(self.quote_char)
+            .with_comment_prefix(self.comment_prefix.as_deref().map(Into::into))
+            .with_skip_rows(self.skip_rows)
+            .with_skip_rows_after_header(self.skip_rows_after_header)
+            .with_n_rows(self.n_rows)
+            .with_row_index(file_row_index(&self.row_index, self.skip_rows_after_header))
+            .with_null_values(self.polars_null_values())
+            .with_encoding(self.encoding)
+            .with_ignore_errors(self.ignore_errors);
+        reader = if self.project {
+            reader.with_dtype_overwrite(schema)
+        } else {
+            reader.with_schema(schema)
+        };
+        #[cfg(feature = "cloud")]
+        if cloud::is_cloud_url(path) {
+            reader = reader.with_cloud_options(cloud::read_options(path, self.cloud.as_ref(), self.retry.as_ref())?);
+        }
+        
+        let format_exprs = self.format_exprs()?;
+        let mut lf = reader.finish()?;
+        if self.project {
+            lf = lf.select(projection_with_index::<Sch>(&self.row_index));
+        }
+        if format_exprs.is_empty() {
+            Ok(lf)
+        } else {
+            Ok(lf.with_columns(format_exprs))
+        }
+    }
+    
+    /// The schema to parse with: formatted columns are read as strings first.
+    fn parse_schema(&self) -> polars::prelude::Schema {
+        let mut schema = Sch::schema();
+        for (name, _) in &self.formats {
+            schema.with_column(name.as_str().into(), DataType::String);
+        }
+        schema
+    }
+    
+    /// Expressions converting the formatted string columns to their schema types.
+    fn format_exprs(&self) -> PolarsResult<Vec<Expr>> {
+        let schema = Sch::schema();
+        self.formats
+            .iter()
+            .filter_map(|(name, format)| {
+                let dtype = schema.get(name)?.clone();
+                Some(parse_temporal(name, dtype, Some(format)))
+            })
+            .collect()
+    }
+    
+    fn polars_null_values(&self) -> Option<NullValues> {
+        self.null_values.as_ref().map(|values| {
+            NullValues::AllColumns(values.iter().map(|v| v.as_str().into()).collect())
+        })
+    }
+}
+
+/// An expression parsing the string column `name` as the temporal `dtype`,
+/// with `format` or an inferred format.
+#[cfg(feature = "temporal")]
+fn parse_temporal(name: &str, dtype: DataType, format: Option<&str>) -> PolarsResult<Expr> {
+    let options = StrptimeOptions {
+        format: format.map(Into::into),
+        ..Default::default()
+    };
+    Ok(col(name).str().strptime(dtype, options, lit("raise")))
+}
+
+/// Without the `temporal` feature temporal strings are cast from their ISO
+/// forms; columns with a declared format cannot be parsed.
+#[cfg(not(feature = "temporal"))]
+fn parse_temporal(name: &str, dtype: DataType, format: Option<&str>) -> PolarsResult<Expr> {
+    match format {
+        None => Ok(col(name).strict_cast(dtype)),
+        Some(format) => Err(PolarsError::InvalidOperation(
+            format!("Parsing column '{}' with format '{}' requires the `temporal` feature", name, format).into()
+        )),
+    }
+}
+
+/// Read a CSV in chunks, reporting the running row count after each one.
+///
+/// `empty` is returned when the input has no rows.
+fn read_csv_chunked(
+    options: CsvReadOptions,
+    handle: Box<dyn MmapBytesReader>,
+    empty: DataFrame,
+    mut progress: ProgressCallback,
+) -> PolarsResult<DataFrame> {
+    let mut reader = options
+        .with_chunk_size(PROGRESS_CHUNK_ROWS)
+        .into_reader_with_file_handle(handle)
+        .batched(None)?;
+    let mut df = None;
+    let mut rows_read = 0;
+    while let Some(batches) = reader.next_batches(1)? {
+        for batch in batches {
+            rows_read += batch.height();
+            append_chunk(&mut df, batch)?;
+            progress(ReadProgress { rows_read, total_rows: None });
+        }
+    }
+    Ok(df.unwrap_or(empty))
+}
+
+/// Iterator over typed batches of a CSV file, created by [`CsvReader::batched`].
+pub struct CsvBatchedReader<Sch: Schema> {
+    reader: OwnedBatchedCsvReader,
+    format_exprs: Vec<Expr>,
+    coerce: bool,
+    source: String,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> Iterator for CsvBatchedReader<Sch> {
+    type Item = PolarsResult<TypedDataFrame<Sch>>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch = match self.reader.next_batches(1) {
+            Ok(Some(mut batches)) => batches.pop()?,
+            Ok(None) => return None,
+            Err(err) => return Some(Err(err)),
+        };
+        
+        let batch = if self.format_exprs.is_empty() {
+            Ok(batch)
+        } else {
+            batch.lazy().with_columns(self.format_exprs.clone()).collect()
+        };
+        Some(batch.and_then(|batch| finish_coercion(batch, self.coerce, &self.source)).map(|(df, _)| df))
+    }
+}
+
+/// Writer for CSV files.
+pub struct CsvWriter<'a, Sch: Schema> {
+    df: &'a TypedDataFrame<Sch>,
+    has_header: bool,
+    append: bool,
+    compression: Option<CsvCompression>,
+    write_manifest: bool,
+    #[cfg(feature = "cloud")]
+    cloud: Option<CloudConfig>,
+}
+
+impl<'a, Sch: Schema> CsvWriter<'a, Sch> {
+    /// Create a new CSV writer for the given DataFrame.
+    pub fn new(df: &'a TypedDataFrame<Sch>) -> Self {
+        Self {
+            df,
+            has_header: true,
+            append: false,
+            compression: None,
+            write_manifest: false,
+            #[cfg(feature = "cloud")]
+            cloud: None,
+        }
+    }
+    
+    /// Set whether to write a header row (default: true).
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+    
+    /// Append rows to the file instead of overwriting it (default: false).
+    ///
+    /// The header is only written when the file is new or empty, so repeated
+    /// appends produce a single valid CSV.
+    pub fn append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+    
+    /// Compress the output (default: detected from the file extension in
+    /// [`finish`](Self::finish), otherwise uncompressed).
+    ///
+    /// Appending to a compressed file adds a new gzip member or zstd frame,
+    /// which readers decode as one continuous file.
+    pub fn compression(mut self, compression: CsvCompression) -> Self {
+        self.compression = Some(compression);
+        self
+    }
+    
+    /// Store a [`Manifest`] next to the file in [`finish`](Self::finish) (default: false).
+    ///
+    /// Only supported for local, non-appending writes.
+    pub fn write_manifest(mut self, write: bool) -> Self {
+        self.write_manifest = write;
+        self
+    }
+    
+    /// Set the credentials used when the path is a cloud URL.
+    #[cfg(feature = "cloud")]
+    pub fn cloud_config(mut self, config: CloudConfig) -> Self {
+        self.cloud = Some(config);
+        self
+    }
+    
+    /// Write the DataFrame to a CSV file.
+    pub fn finish(mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        if self.compression.is_none() {
+            self.compression = Some(CsvCompression::from_path(path.as_ref()));
+        }
+        if self.write_manifest && self.append {
+            return Err(PolarsError::InvalidOperation(
+                "Manifests cannot be written for appended CSV files".into()
+            ));
+        }
+        let manifest = writer_manifest(self.df, path.as_ref(), self.write_manifest)?;
+        
+        #[cfg(feature = "cloud")]
+        if let Some(url) = path.as_ref().to_str().filter(|p| cloud::is_cloud_url(p)) {
+            let writer = cloud::open_writer(url, self.cloud.as_ref())?;
+            return self.finish_to_writer(writer);
+        }
+        
+        if self.append {
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            let is_empty = file.metadata()?.len() == 0;
+            let has_header = self.has_header && is_empty;
+            return self.has_header(has_header).finish_to_writer(file);
+        }
+        
+        let file = std::fs::File::create(path.as_ref())?;
+        self.finish_to_writer(file)?;
+        if let Some(manifest) = manifest {
+            manifest.write(path)?;
+        }
+        Ok(())
+    }
+    
+    /// Write the DataFrame to an arbitrary writer, e.g. a socket or HTTP response.
+    pub fn finish_to_writer(self, writer: impl Write) -> PolarsResult<()> {
+        match self.compression.unwrap_or(CsvCompression::Uncompressed) {
+            CsvCompression::Uncompressed => self.write_csv(writer),
+            CsvCompression::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+                self.write_csv(&mut encoder)?;
+                encoder.finish()?;
+                Ok(())
+            }
+            CsvCompression::Zstd => {
+                let mut encoder = zstd::stream::write::Encoder::new(writer, 0)?;
+                self.write_csv(&mut encoder)?;
+                encoder.finish()?;
+                Ok(())
+            }
+        }
+    }
+    
+    fn write_csv(&self, writer: impl Write) -> PolarsResult<()> {
+        let df = aligned_chunks(self.df.inner());
+        polars::prelude::CsvWriter::new(writer)
+            .include_header(self.has_header)
+            .batched(&df.schema())?
+            .write_batch(&df)
+    }
+    
+    /// Write the DataFrame to standard output, for use in Unix pipelines.
+    pub fn finish_to_stdout(self) -> PolarsResult<()> {
+        let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+        self.finish_to_writer(&mut stdout)?;
+        stdout.flush()?;
+        Ok(())
+    }
+    
+    /// Write the DataFrame into an in-memory buffer.
+    pub fn to_bytes(self) -> PolarsResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.finish_to_writer(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Reader for Parquet files with schema validation.
+#[cfg(feature = "parquet")]
+pub struct ParquetReader<Sch: Schema> {
+    source: Source,
+    filter: Option<Expr>,
+    coerce: bool,
+    memory_map: bool,
+    skip_rows: usize,
+    n_rows: Option<usize>,
+    row_index: Option<RowIndex>,
+    progress: Option<ProgressCallback>,
+    verify_manifest: bool,
+    #[cfg(feature = "cloud")]
+    cloud: Option<CloudConfig>,
+    #[cfg(feature = "cloud")]
+    retry: Option<RetryPolicy>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(feature = "parquet")]
+impl<Sch: Schema> ParquetReader<Sch> {
+    /// Create a new Parquet reader for the given path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new Parquet reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new Parquet reader over an arbitrary reader, e.g. an HTTP response body.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    fn from_source(source: Source) -> Self {
+        Self {
+            source,
+            filter: None,
+            coerce: false,
+            memory_map: false,
+            skip_rows: 0,
+            n_rows: None,
+            row_index: None,
+            progress: None,
+            verify_manifest: false,
+            #[cfg(feature = "cloud")]
+            cloud: None,
+            #[cfg(feature = "cloud")]
+            retry: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Only keep rows matching the predicate.
+    ///
+    /// For file paths the predicate is pushed into the scan, so row groups whose
+    /// statistics rule out any match are never decoded.
+    pub fn with_filter(mut self, predicate: TypedExpr<bool>) -> Self {
+        self.filter = Some(predicate.into_inner());
+        self
+    }
+    
+    /// Cast columns stored with a different type to the schema types (default: false).
+    ///
+    /// For example an `Int64` column is accepted for an `i32` field. Use
+    /// [`finish_with_report`](Self::finish_with_report) to see which columns were cast.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+    
+    /// Stop reading after `limit` rows, e.g. to preview a large file.
+    ///
+    /// The limit applies to the rows read from the file, before any filter.
+    pub fn with_n_rows(mut self, limit: usize) -> Self {
+        self.n_rows = Some(limit);
+        self
+    }
+    
+    /// Skip this many rows at the start of the file, e.g. to read a later page (default: 0).
+    ///
+    /// Row groups that lie entirely within the skipped rows are not decoded.
+    pub fn skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+    
+    /// Add a row index column numbering rows from `offset`.
+    ///
+    /// Skipped rows are counted, so the index is the row's position in the
+    /// file; it is assigned before any filter is applied.
+    pub fn with_row_index(mut self, name: &str, offset: IdxSize) -> Self {
+        self.row_index = Some(RowIndex { name: name.into(), offset });
+        self
+    }
+    
+    /// Call `callback` with the running row count as the file is read.
+    ///
+    /// The file is then decoded in chunks, reporting after each one. The total
+    /// is taken from the file footer. Not supported for cloud URLs.
+    pub fn on_progress(mut self, callback: impl FnMut(ReadProgress) + 'static) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+    
+    /// Verify the read against the manifest stored next to the file (default: false).
+    ///
+    /// The manifest is written by [`ParquetWriter::write_manifest`]; see
+    /// [`Manifest`]. Filtered or row-limited reads will not match it.
+    pub fn verify_manifest(mut self, verify: bool) -> Self {
+        self.verify_manifest = verify;
+        self
+    }
+    
+    /// Decode from a memory map of the file instead of reading it (default: false).
+    ///
+    /// Only applies to local file paths. Repeatedly loading a large file is
+    /// then served straight from the page cache. Without the `fs` feature the
+    /// file is read into memory instead.
+    pub fn memory_map(mut self, memory_map: bool) -> Self {
+        self.memory_map = memory_map;
+        self
+    }
+    
+    /// Set the credentials used when the path is a cloud URL.
+    #[cfg(feature = "cloud")]
+    pub fn cloud_config(mut self, config: CloudConfig) -> Self {
+        self.cloud = Some(config);
+        self
+    }
+    
+    /// Retry reads from a cloud URL that fail transiently.
+    #[cfg(feature = "cloud")]
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = Some(policy);
+        self
+    }
+    
+    /// Run a read of `path`, retrying it under the reader's policy if the path is remote.
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+    fn remote<T>(&self, path: &str, mut read: impl FnMut() -> PolarsResult<T>) -> PolarsResult<T> {
+        #[cfg(feature = "cloud")]
+        if let Some(retry) = self.retry.as_ref().filter(|_| is_cloud_path(path)) {
+            return retry.run(read);
+        }
+        read()
+    }
+    
+    /// Read the Parquet file and validate it against the schema.
+    ///
+    /// Only the schema's columns are decoded; any other columns in the file are skipped.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        self.finish_with_report().map(|(df, _)| df)
+    }
+    
+    /// Read the Parquet file and also report the columns cast in coercion mode.
+    pub fn finish_with_report(self) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        let manifest = source_manifest(&self.source, self.verify_manifest)?;
+        let (df, coercions) = self.read_with_report()?;
+        if let Some(manifest) = manifest {
+            manifest.verify(&df)?;
+        }
+        Ok((df, coercions))
+    }
+    
+    fn read_with_report(mut self) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        if let Some(progress) = self.progress.take() {
+            return self.finish_chunked(progress);
+        }
+        let source = self.source.describe();
+        if let Some(path) = self.scan_path() {
+            let df = self.remote(path, || self.lazy_frame(path)?.collect())?;
+            return finish_coercion(df, self.coerce, &source);
+        }
+        
+        let columns = Some(Self::projection());
+        let slice = (self.skip_rows > 0 || self.n_rows.is_some())
+            .then(|| (self.skip_rows, self.n_rows.unwrap_or(usize::MAX)));
+        let row_index = file_row_index(&self.row_index, self.skip_rows);
+        let mut df = match self.source {
+            Source::Path(path) if self.memory_map => polars::prelude::ParquetReader::new(map_file(&path)?)
+                .with_columns(columns)
+                .with_slice(slice)
+                .finish()?,
+            source => match source.open()? {
+                OpenSource::File(file) => polars::prelude::ParquetReader::new(file)
+                    .with_columns(columns)
+                    .with_slice(slice)
+                    .finish()?,
+                OpenSource::Bytes(bytes) => polars::prelude::ParquetReader::new(bytes)
+                    .with_columns(columns)
+                    .with_slice(slice)
+                    .finish()?,
+            },
+        };
+        if let Some(row_index) = row_index {
+            df = df.with_row_index(row_index.name, Some(row_index.offset))?;
+        }
+        if let Some(predicate) = self.filter {
+            df = df.lazy().filter(predicate).collect()?;
+        }
+        
+        finish_coercion(df, self.coerce, &source)
+    }
+    
+    /// Decode the file in slices of [`PROGRESS_CHUNK_ROWS`], reporting after each one.
+    fn finish_chunked(self, mut progress: ProgressCallback) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        let source = self.source.describe();
+        let map;
+        let buffer;
+        let bytes: &[u8] = match self.source {
+            Source::Path(path) if is_cloud_path(&path) => {
+                return Err(PolarsError::InvalidOperation(
+                    "Progress reporting is not supported for cloud URLs".into()
+                ));
+            }
+            Source::Path(path) => {
+                map = map_file(&path)?.into_inner();
+                &map
+            }
+            Source::Bytes(bytes) => {
+                buffer = bytes;
+                &buffer
+            }
+            Source::Reader(mut reader) => {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes)?;
+                buffer = bytes;
+                &buffer
+            }
+        };
+        
+        let file_rows = polars::prelude::ParquetReader::new(Cursor::new(bytes)).num_rows()?;
+        let available = file_rows.saturating_sub(self.skip_rows);
+        let total_rows = self.n_rows.map_or(available, |n_rows| n_rows.min(available));
+        let columns = Some(Self::projection());
+        let mut df = None;
+        let mut offset = 0;
+        loop {
+            let length = PROGRESS_CHUNK_ROWS.min(total_rows - offset);
+            let mut chunk = polars::prelude::ParquetReader::new(Cursor::new(bytes))
+                .with_columns(columns.clone())
+                .with_slice(Some((self.skip_rows + offset, length)))
+                .finish()?;
+            if let Some(row_index) = file_row_index(&self.row_index, self.skip_rows + offset) {
+                chunk = chunk.with_row_index(row_index.name, Some(row_index.offset))?;
+            }
+            if let Some(predicate) = &self.filter {
+                chunk = chunk.lazy().filter(predicate.clone()).collect()?;
+            }
+            append_chunk(&mut df, chunk)?;
+            offset += length;
+            progress(ReadProgress { rows_read: offset, total_rows: Some(total_rows) });
+            if offset >= total_rows {
+                break;
+            }
+        }
+        
+        let df = df.expect("at least one chunk is always read");
+        finish_coercion(df, self.coerce, &source)
+    }
+    
+    /// The path to read through a lazy scan, if pushdown or a cloud store is involved.
+    fn scan_path(&self) -> Option<&str> {
+        match &self.source {
+            Source::Path(path) if self.filter.is_some() || is_cloud_path(path) => Some(path.as_str()),
+            _ => None,
+        }
+    }
+    
+    /// Scan the Parquet file lazily with the schema projection and filter applied.
+    ///
+    /// The schema is validated when the resulting frame is collected.
+    pub fn scan(self) -> PolarsResult<TypedLazyFrame<Sch>> {
+        match &self.source {
+            Source::Path(path) => Ok(TypedLazyFrame::new(self.lazy_frame(path)?)),
+            _ => Err(PolarsError::InvalidOperation(
+                "Lazy scans require a file path".into()
+            )),
+        }
+    }
+    
+    /// Build a lazy scan with the schema projection and filter applied.
+    fn lazy_frame(&self, path: &str) -> PolarsResult<LazyFrame> {
+        let mut lf = LazyFrame::scan_parquet(path, self.scan_args(path)?)?;
+        if self.skip_rows > 0 {
+            // Pushed down into the scan by the optimizer
+            let length = self.n_rows.map_or(IdxSize::MAX, |n_rows| n_rows as IdxSize);
+            lf = lf.slice(self.skip_rows as i64, length);
+        }
+        lf = lf.select(projection_with_index::<Sch>(&self.row_index));
+        if let Some(predicate) = &self.filter {
+            lf = lf.filter(predicate.clone());
+        }
+        Ok(lf)
+    }
+    
+    #[cfg_attr(not(feature = "cloud"), allow(unused_variables))]
+    fn scan_args(&self, path: &str) -> PolarsResult<ScanArgsParquet> {
+        #[allow(unused_mut)]
+        let mut args = ScanArgsParquet {
+            // With skipped rows the limit is applied by a slice after the scan
+            n_rows: self.n_rows.filter(|_| self.skip_rows == 0),
+            row_index: self.row_index.clone(),
+            ..Default::default()
+        };
+        #[cfg(feature = "cloud")]
+        if cloud::is_cloud_url(path) {
+            args.cloud_options = cloud::read_options(path, self.cloud.as_ref(), self.retry.as_ref())?;
+        }
+        Ok(args)
+    }
+    
+    /// Check the schema stored in the file footer against the schema without reading any data.
+    ///
+    /// Returns the reader so the data can be read afterwards, e.g.
+    /// `ParquetReader::<S>::new(path).validate_metadata()?.finish()?`.
+    pub fn validate_metadata(mut self) -> PolarsResult<Self> {
+        self.buffer_reader()?;
+        
+        let file_schema = match &self.source {
+            Source::Path(path) if is_cloud_path(path) => self.remote(path, || {
+                let mut lf = LazyFrame::scan_parquet(path.as_str(), self.scan_args(path)?)?;
+                Ok(lf.collect_schema()?.as_ref().clone())
+            })?,
+            Source::Path(path) => {
+                let file = std::fs::File::open(path)?;
+                let arrow_schema = polars::prelude::ParquetReader::new(file).schema()?;
+                polars::prelude::Schema::from_arrow_schema(&arrow_schema)
+            }
+            Source::Bytes(bytes) => {
+                let cursor = Cursor::new(bytes.as_slice());
+                let arrow_schema = polars::prelude::ParquetReader::new(cursor).schema()?;
+                polars::prelude::Schema::from_arrow_schema(&arrow_schema)
+            }
+            Source::Reader(_) => unreachable!("readers are buffered above"),
+        };
+        
+        Sch::validate(&DataFrame::empty_with_schema(&file_schema))?;
+        Ok(self)
+    }
+    
+    /// Buffer an arbitrary reader so the data is still available after inspecting the footer.
+    fn buffer_reader(&mut self) -> PolarsResult<()> {
+        if let Source::Reader(_) = self.source {
+            let source = std::mem::replace(&mut self.source, Source::Bytes(Vec::new()));
+            if let OpenSource::Bytes(bytes) = source.open()? {
+                self.source = Source::Bytes(bytes.into_inner());
+            }
+        }
+        Ok(())
+    }
+    
+    /// The columns to decode: exactly those declared in the schema.
+    fn projection() -> Vec<String> {
+        Sch::column_names().into_iter().map(String::from).collect()
+    }
+}
+
+/// Writer for Parquet files.
+#[cfg(feature = "parquet")]
+pub struct ParquetWriter<'a, Sch: Schema> {
+    df: &'a TypedDataFrame<Sch>,
+    compression: ParquetCompression,
+    statistics: StatisticsOptions,
+    row_group_size: Option<usize>,
+    data_page_size: Option<usize>,
+    write_manifest: bool,
+    #[cfg(feature = "cloud")]
+    cloud: Option<CloudConfig>,
+}
+
+#[cfg(feature = "parquet")]
+impl<'a, Sch: Schema> ParquetWriter<'a, Sch> {
+    /// Create a new Parquet writer for the given DataFrame.
+    pub fn new(df: &'a TypedDataFrame<Sch>) -> Self {
+        Self {
+            df,
+            compression: ParquetCompression::default(),
+            statistics: StatisticsOptions::default(),
+            row_group_size: None,
+            data_page_size: None,
+            write_manifest: false,
+            #[cfg(feature = "cloud")]
+            cloud: None,
+        }
+    }
+    
+    /// Set the compression codec and level (default: zstd).
+    ///
+    /// For example `ParquetCompression::Zstd(Some(ZstdLevel::try_new(10)?))`,
+    /// `ParquetCompression::Snappy` or `ParquetCompression::Lz4Raw`.
+    pub fn compression(mut self, compression: ParquetCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+    
+    /// Set whether to write column statistics (default: true).
+    ///
+    /// Statistics let readers skip row groups when filtering.
+    pub fn statistics(mut self, statistics: bool) -> Self {
+        self.statistics = if statistics {
+            StatisticsOptions::default()
+        } else {
+            StatisticsOptions::empty()
+        };
+        self
+    }
+    
+    /// Set the maximum number of rows per row group (default: chosen by Polars).
+    pub fn row_group_size(mut self, size: usize) -> Self {
+        self.row_group_size = Some(size);
+        self
+    }
+    
+    /// Set the target data page size in bytes (default: chosen by Polars).
+    pub fn data_page_size(mut self, size: usize) -> Self {
+        self.data_page_size = Some(size);
+        self
+    }
+    
+    /// Store a [`Manifest`] next to the file in [`finish`](Self::finish) (default: false).
+    ///
+    /// Only supported for local files.
+    pub fn write_manifest(mut self, write: bool) -> Self {
+        self.write_manifest = write;
+        self
+    }
+    
+    /// Set the credentials used when the path is a cloud URL.
+    #[cfg(feature = "cloud")]
+    pub fn cloud_config(mut self, config: CloudConfig) -> Self {
+        self.cloud = Some(config);
+        self
+    }
+    
+    /// Write the DataFrame to a Parquet file.
+    pub fn finish(self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let manifest = writer_manifest(self.df, path.as_ref(), self.write_manifest)?;
+        
+        #[cfg(feature = "cloud")]
+        if let Some(url) = path.as_ref().to_str().filter(|p| cloud::is_cloud_url(p)) {
+            let writer = cloud::open_writer(url, self.cloud.as_ref())?;
+            return self.finish_to_writer(writer);
+        }
+        
+        let file = std::fs::File::create(path.as_ref())?;
+        self.finish_to_writer(file)?;
+        if let Some(manifest) = manifest {
+            manifest.write(path)?;
+        }
+        Ok(())
+    }
+    
+    /// Write the DataFrame to an arbitrary writer, e.g. a socket or HTTP response.
+    pub fn finish_to_writer(self, writer: impl Write) -> PolarsResult<()> {
+        let df = aligned_chunks(self.df.inner());
+        let mut batched = polars::prelude::ParquetWriter::new(writer)
+            .with_compression(self.compression)
+            .with_statistics(self.statistics)
+            .with_row_group_size(self.row_group_size)
+            .with_data_page_size(self.data_page_size)
+            .batched(&df.schema())?;
+        // Zero-copy slices of the borrowed frame; each becomes (at least) one row group
+        let row_group_size = self.row_group_size.unwrap_or(DEFAULT_ROW_GROUP_SIZE).max(1);
+        let mut offset = 0;
+        while offset < df.height() {
+            batched.write_batch(&df.slice(offset as i64, row_group_size))?;
+            offset += row_group_size;
+        }
+        batched.finish()?;
+        Ok(())
+    }
+    
+    /// Write the DataFrame into an in-memory buffer.
+    pub fn to_bytes(self) -> PolarsResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.finish_to_writer(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Appends typed frames to a directory as a multi-part Parquet dataset.
+///
+/// Each call to [`append`](Self::append) writes a new `part-NNNNN.parquet`
+/// file, numbered after any parts already present, so a restarted job keeps
+/// adding to the same dataset. Read it back with [`read_parquet_glob`].
+#[cfg(all(feature = "fs", feature = "parquet"))]
+pub struct ParquetDatasetAppender<Sch: Schema> {
+    dir: std::path::PathBuf,
+    next_part: usize,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(all(feature = "fs", feature = "parquet"))]
+impl<Sch: Schema> ParquetDatasetAppender<Sch> {
+    /// Open (or create) the dataset directory.
+    pub fn new(dir: impl AsRef<Path>) -> PolarsResult<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        let next_part = next_part_number(&dir, "parquet")?;
+        
+        Ok(Self {
+            dir,
+            next_part,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Write the DataFrame as the next part and return its path.
+    pub fn append(&mut self, df: &TypedDataFrame<Sch>) -> PolarsResult<std::path::PathBuf> {
+        let path = self.dir.join(format!("part-{:05}.parquet", self.next_part));
+        ParquetWriter::new(df).finish(&path)?;
+        self.next_part += 1;
+        Ok(path)
+    }
+}
+
+/// Create `dir` if needed and return the number after its highest `part-NNNNN.<extension>` file.
+#[cfg(feature = "fs")]
+fn next_part_number(dir: &Path, extension: &str) -> PolarsResult<usize> {
+    std::fs::create_dir_all(dir)?;
+    
+    let mut next_part = 0;
+    for entry in std::fs::read_dir(dir)? {
+        let name = entry?.file_name();
+        let part = name
+            .to_str()
+            .and_then(|name| name.strip_prefix("part-"))
+            .and_then(|name| name.strip_suffix(extension)?.strip_suffix('.'))
+            .and_then(|number| number.parse::<usize>().ok());
+        if let Some(part) = part {
+            next_part = next_part.max(part + 1);
+        }
+    }
+    Ok(next_part)
+}
+
+/// File format of the parts written by a [`DatasetWriter`].
+#[cfg(feature = "fs")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DatasetFormat {
+    /// `.parquet` files
+    #[cfg(feature = "parquet")]
+    Parquet,
+    /// `.csv` files, each with a header row
+    Csv,
+    /// `.ndjson` files
+    #[cfg(feature = "json")]
+    NdJson,
+}
+
+#[cfg(feature = "fs")]
+impl DatasetFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            #[cfg(feature = "parquet")]
+            DatasetFormat::Parquet => "parquet",
+            DatasetFormat::Csv => "csv",
+            #[cfg(feature = "json")]
+            DatasetFormat::NdJson => "ndjson",
+        }
+    }
+}
+
+/// Splits typed frames across numbered files in a directory, with limits on rows and bytes per file.
+///
+/// Parts are named `part-00000.<ext>`, `part-00001.<ext>`, ..., numbered after
+/// any parts already in the directory. Rows are buffered until a file's worth
+/// has arrived, so a stream of small batches still produces full-size files;
+/// only the last part written by [`finish`](Self::finish) may be smaller.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut writer = DatasetWriter::<EventSchema>::new("export/events", DatasetFormat::Csv)?
+///     .max_rows(1_000_000)
+///     .max_bytes(256 * 1024 * 1024);
+/// for batch in CsvReader::<EventSchema>::new("events.csv").batched(100_000)? {
+///     writer.write(&batch?)?;
+/// }
+/// let parts = writer.finish()?;
+/// ```
+#[cfg(feature = "fs")]
+pub struct DatasetWriter<Sch: Schema> {
+    dir: std::path::PathBuf,
+    format: DatasetFormat,
+    max_rows: Option<usize>,
+    max_bytes: Option<usize>,
+    next_part: usize,
+    buffer: Option<DataFrame>,
+    written: Vec<std::path::PathBuf>,
     _phantom: std::marker::PhantomData<Sch>,
 }
 
-impl<Sch: Schema> CsvReader<Sch> {
-    /// Create a new CSV reader for the given path.
+#[cfg(feature = "fs")]
+impl<Sch: Schema> DatasetWriter<Sch> {
+    /// Open (or create) the dataset directory.
+    pub fn new(dir: impl AsRef<Path>, format: DatasetFormat) -> PolarsResult<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        let next_part = next_part_number(&dir, format.extension())?;
+        
+        Ok(Self {
+            dir,
+            format,
+            max_rows: None,
+            max_bytes: None,
+            next_part,
+            buffer: None,
+            written: Vec::new(),
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Set the maximum number of rows per file (default: unlimited).
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows.max(1));
+        self
+    }
+    
+    /// Set the maximum size of each file in bytes (default: unlimited).
+    ///
+    /// A file is cut once the buffered rows take up this much memory, then
+    /// shrunk until its encoded size fits, so compressed formats produce
+    /// files below the limit rather than exactly at it.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+    
+    /// Buffer a frame, writing every file it completes.
+    pub fn write(&mut self, df: &TypedDataFrame<Sch>) -> PolarsResult<()> {
+        append_chunk(&mut self.buffer, df.inner().clone())?;
+        self.flush(false)
+    }
+    
+    /// Write every frame of a stream of typed batches, e.g. a [`CsvBatchedReader`].
+    pub fn write_batches(
+        &mut self,
+        batches: impl IntoIterator<Item = PolarsResult<TypedDataFrame<Sch>>>,
+    ) -> PolarsResult<()> {
+        for batch in batches {
+            self.write(&batch?)?;
+        }
+        Ok(())
+    }
+    
+    /// Write the remaining buffered rows and return the paths of all files written.
+    pub fn finish(mut self) -> PolarsResult<Vec<std::path::PathBuf>> {
+        self.flush(true)?;
+        Ok(self.written)
+    }
+    
+    /// Write files from the front of the buffer while a full file is available, or until empty if `all`.
+    fn flush(&mut self, all: bool) -> PolarsResult<()> {
+        while let Some(buffer) = self.buffer.take() {
+            let full = self.max_rows.is_some_and(|max| buffer.height() >= max)
+                || self.max_bytes.is_some_and(|max| buffer.estimated_size() >= max);
+            if !full && !(all && buffer.height() > 0) {
+                self.buffer = Some(buffer);
+                break;
+            }
+            
+            let mut rows = self.max_rows.unwrap_or(usize::MAX).min(buffer.height());
+            let mut bytes = self.encode(buffer.slice(0, rows))?;
+            while let Some(max) = self.max_bytes.filter(|max| bytes.len() > *max) {
+                if rows == 1 {
+                    return Err(PolarsError::ComputeError(
+                        format!("A single row encodes to {} bytes, over the {} byte limit", bytes.len(), max).into()
+                    ));
+                }
+                rows = (rows * max / bytes.len()).clamp(1, rows - 1);
+                bytes = self.encode(buffer.slice(0, rows))?;
+            }
+            
+            let path = self.dir.join(format!("part-{:05}.{}", self.next_part, self.format.extension()));
+            std::fs::write(&path, bytes)?;
+            self.next_part += 1;
+            self.written.push(path);
+            
+            if rows < buffer.height() {
+                self.buffer = Some(buffer.slice(rows as i64, buffer.height() - rows));
+            }
+        }
+        Ok(())
+    }
+    
+    fn encode(&self, df: DataFrame) -> PolarsResult<Vec<u8>> {
+        let df = TypedDataFrame::<Sch>::new(df)?;
+        match self.format {
+            #[cfg(feature = "parquet")]
+            DatasetFormat::Parquet => ParquetWriter::new(&df).to_bytes(),
+            DatasetFormat::Csv => CsvWriter::new(&df).to_bytes(),
+            #[cfg(feature = "json")]
+            DatasetFormat::NdJson => NdJsonWriter::new(&df).to_bytes(),
+        }
+    }
+}
+
+/// Reader for Arrow IPC (Feather v2) files with schema validation.
+#[cfg(feature = "ipc")]
+pub struct IpcReader<Sch: Schema> {
+    source: Source,
+    memory_map: bool,
+    n_rows: Option<usize>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(feature = "ipc")]
+impl<Sch: Schema> IpcReader<Sch> {
+    /// Create a new IPC reader for the given path.
     pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new IPC reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new IPC reader over an arbitrary reader.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    fn from_source(source: Source) -> Self {
         Self {
-            path: path.as_ref().to_string_lossy().to_string(),
-            has_header: true,
+            source,
+            memory_map: false,
+            n_rows: None,
             _phantom: std::marker::PhantomData,
         }
     }
     
-    /// Set whether the CSV has a header row (default: true).
-    pub fn has_header(mut self, has_header: bool) -> Self {
-        self.has_header = has_header;
+    /// Memory-map the file instead of reading it (default: false).
+    ///
+    /// Only applies to local file paths. Uncompressed columns then reference
+    /// the mapped pages directly instead of being copied into memory.
+    pub fn memory_map(mut self, memory_map: bool) -> Self {
+        self.memory_map = memory_map;
         self
     }
     
-    /// Read the CSV file and validate it against the schema.
+    /// Stop reading after `limit` rows, e.g. to preview a large file.
+    pub fn with_n_rows(mut self, limit: usize) -> Self {
+        self.n_rows = Some(limit);
+        self
+    }
+    
+    /// Read the IPC file and validate it against the schema.
+    ///
+    /// Only the schema's columns are read; any other columns in the file are skipped.
     pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
-        let df = CsvReadOptions::default()
-            .with_has_header(self.has_header)
-            .with_schema(Some(std::sync::Arc::new(Sch::schema())))
-            .try_into_reader_with_file_path(Some(self.path.into()))?
-            .finish()?;
+        let columns = Some(Sch::column_names().into_iter().map(String::from).collect());
+        let source = self.source.describe();
+        let mapped = match (&self.source, self.memory_map) {
+            (Source::Path(path), true) => Some(std::path::PathBuf::from(path)),
+            _ => None,
+        };
+        let df = match self.source.open()? {
+            OpenSource::File(file) => polars::prelude::IpcReader::new(file)
+                .with_columns(columns)
+                .with_n_rows(self.n_rows)
+                .memory_mapped(mapped)
+                .finish()?,
+            OpenSource::Bytes(bytes) => polars::prelude::IpcReader::new(bytes)
+                .with_columns(columns)
+                .with_n_rows(self.n_rows)
+                .finish()?,
+        };
         
-        TypedDataFrame::new(df)
+        TypedDataFrame::from_source(df, &source)
     }
 }
 
-/// Writer for CSV files.
-pub struct CsvWriter<'a, Sch: Schema> {
+/// Writer for Arrow IPC (Feather v2) files.
+#[cfg(feature = "ipc")]
+pub struct IpcWriter<'a, Sch: Schema> {
     df: &'a TypedDataFrame<Sch>,
-    has_header: bool,
 }
 
-impl<'a, Sch: Schema> CsvWriter<'a, Sch> {
-    /// Create a new CSV writer for the given DataFrame.
+#[cfg(feature = "ipc")]
+impl<'a, Sch: Schema> IpcWriter<'a, Sch> {
+    /// Create a new IPC writer for the given DataFrame.
     pub fn new(df: &'a TypedDataFrame<Sch>) -> Self {
+        Self { df }
+    }
+    
+    /// Write the DataFrame to an IPC file.
+    pub fn finish(self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let file = std::fs::File::create(path)?;
+        self.finish_to_writer(file)
+    }
+    
+    /// Write the DataFrame to an arbitrary writer, e.g. a socket or HTTP response.
+    pub fn finish_to_writer(self, writer: impl Write) -> PolarsResult<()> {
+        let df = aligned_chunks(self.df.inner());
+        let mut batched = polars::prelude::IpcWriter::new(writer).batched(&df.schema())?;
+        batched.write_batch(&df)?;
+        batched.finish()
+    }
+    
+    /// Write the DataFrame into an in-memory buffer.
+    pub fn to_bytes(self) -> PolarsResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.finish_to_writer(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Reader for fixed-width flat files, where each column occupies a declared byte range.
+///
+/// Every schema column needs a range. Fields are trimmed of surrounding
+/// whitespace and empty fields read as null; values are then cast to the
+/// schema types, with temporal columns parsed using their declared format.
+///
+/// # Example
+///
+/// ```ignore
+/// let payments = FixedWidthReader::<PaymentSchema>::new("payments.dat")
+///     .field(PaymentSchema::account, 0..10)
+///     .field(PaymentSchema::amount, 10..22)
+///     .field(PaymentSchema::booked, 22..30)
+///     .skip_rows(1)
+///     .finish()?;
+/// ```
+pub struct FixedWidthReader<Sch: Schema> {
+    source: Source,
+    fields: Vec<(&'static str, std::ops::Range<usize>)>,
+    skip_rows: usize,
+    trim: bool,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> FixedWidthReader<Sch> {
+    /// Create a new fixed-width reader for the given path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new fixed-width reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new fixed-width reader over an arbitrary reader.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    fn from_source(source: Source) -> Self {
         Self {
-            df,
-            has_header: true,
+            source,
+            fields: Vec::new(),
+            skip_rows: 0,
+            trim: true,
+            _phantom: std::marker::PhantomData,
         }
     }
     
-    /// Set whether to write a header row (default: true).
-    pub fn has_header(mut self, has_header: bool) -> Self {
-        self.has_header = has_header;
+    /// Declare the byte range a column occupies on each line, e.g. `0..10`.
+    ///
+    /// Ranges past the end of a short line yield the bytes that are present.
+    /// An empty or reversed range, such as `10..5`, fails the read.
+    pub fn field<T: ColumnType>(mut self, column: Column<T>, bytes: std::ops::Range<usize>) -> Self {
+        self.fields.retain(|(name, _)| *name != column.name());
+        self.fields.push((column.name(), bytes));
         self
     }
     
-    /// Write the DataFrame to a CSV file.
+    /// Skip lines at the start of the file, e.g. a header record (default: 0).
+    pub fn skip_rows(mut self, skip_rows: usize) -> Self {
+        self.skip_rows = skip_rows;
+        self
+    }
+    
+    /// Trim whitespace padding around each field (default: true).
+    pub fn trim(mut self, trim: bool) -> Self {
+        self.trim = trim;
+        self
+    }
+    
+    /// Read the file and validate it against the schema.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        let fields = Sch::column_names()
+            .into_iter()
+            .map(|name| {
+                self.fields
+                    .iter()
+                    .find(|(field, _)| *field == name)
+                    .cloned()
+                    .ok_or_else(|| PolarsError::ColumnNotFound(
+                        format!("No byte range declared for column '{}'", name).into()
+                    ))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if let Some((name, range)) = fields.iter().find(|(_, range)| range.is_empty()) {
+            return Err(PolarsError::InvalidOperation(
+                format!("Column '{}' has an empty byte range {}..{}", name, range.start, range.end).into()
+            ));
+        }
+        let source = self.source.describe();
+        let bytes = match self.source.open()? {
+            OpenSource::File(mut file) => {
+                let mut bytes = Vec::new();
+                file.read_to_end(&mut bytes)?;
+                bytes
+            }
+            OpenSource::Bytes(bytes) => bytes.into_inner(),
+        };
+        
+        let mut values: Vec<Vec<Option<&str>>> = vec![Vec::new(); fields.len()];
+        for (index, line) in bytes.split(|byte| *byte == b'\n').enumerate().skip(self.skip_rows) {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            if line.is_empty() {
+                continue;
+            }
+            for ((name, range), column) in fields.iter().zip(values.iter_mut()) {
+                let field = line.get(range.start.min(line.len())..range.end.min(line.len())).ok_or_else(|| {
+                    PolarsError::ComputeError(format!("Line {}: column '{}' is out of range", index + 1, name).into())
+                })?;
+                let text = std::str::from_utf8(field).map_err(|_| PolarsError::ComputeError(
+                    format!("Line {}: column '{}' is not valid UTF-8", index + 1, name).into()
+                ))?;
+                let text = if self.trim { text.trim() } else { text };
+                column.push((!text.is_empty()).then_some(text));
+            }
+        }
+        
+        let columns = fields
+            .iter()
+            .zip(values)
+            .map(|((name, _), values)| Series::new((*name).into(), values).into_column())
+            .collect();
+        let formats = Sch::column_formats();
+        let casts = Sch::schema()
+            .iter()
+            .map(|(name, dtype)| {
+                if dtype.is_temporal() {
+                    let format = formats
+                        .iter()
+                        .find(|(column, _)| *column == name.as_str())
+                        .map(|(_, format)| *format);
+                    parse_temporal(name, dtype.clone(), format)
+                } else {
+                    Ok(col(name.clone()).strict_cast(dtype.clone()))
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        
+        TypedDataFrame::from_source(DataFrame::new(columns)?.lazy().with_columns(casts).collect()?, &source)
+    }
+}
+
+/// Writes typed frames incrementally as an Arrow IPC stream to any writer, e.g. a TCP socket.
+///
+/// The schema message is sent when the writer is created and every call to
+/// [`write`](Self::write) sends the frame's record batches, so the receiving
+/// service can process them as they arrive with any Arrow IPC stream reader.
+///
+/// # Example
+///
+/// ```ignore
+/// let socket = std::net::TcpStream::connect("ingest:9000")?;
+/// let mut writer = IpcStreamWriter::<TradeSchema, _>::new(socket)?;
+/// for batch in CsvReader::<TradeSchema>::new("trades.csv").batched(50_000)? {
+///     writer.write(&batch?)?;
+/// }
+/// writer.finish()?;
+/// ```
+#[cfg(feature = "ipc")]
+pub struct IpcStreamWriter<Sch: Schema, W: Write> {
+    writer: StreamWriter<W>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(feature = "ipc")]
+impl<Sch: Schema, W: Write> IpcStreamWriter<Sch, W> {
+    /// Start a stream on `writer`, sending the schema message.
+    pub fn new(writer: W) -> PolarsResult<Self> {
+        let mut writer = StreamWriter::new(writer, WriteOptions { compression: None });
+        writer.start(&TypedDataFrame::<Sch>::arrow_schema(), None)?;
+        Ok(Self {
+            writer,
+            _phantom: std::marker::PhantomData,
+        })
+    }
+    
+    /// Send the schema columns of a frame, one record batch per chunk.
+    pub fn write(&mut self, df: &TypedDataFrame<Sch>) -> PolarsResult<()> {
+        let columns = df.inner().select(Sch::column_names())?;
+        let columns = aligned_chunks(&columns);
+        for batch in columns.iter_chunks(CompatLevel::newest(), false) {
+            self.writer.write(&batch, None)?;
+        }
+        Ok(())
+    }
+    
+    /// Send the end-of-stream marker.
+    pub fn finish(mut self) -> PolarsResult<()> {
+        self.writer.finish()
+    }
+}
+
+/// Reader for JSON files (an array of objects) with schema validation.
+#[cfg(feature = "json")]
+pub struct JsonReader<Sch: Schema> {
+    source: Source,
+    coerce: bool,
+    project: bool,
+    n_rows: Option<usize>,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(feature = "json")]
+impl<Sch: Schema> JsonReader<Sch> {
+    /// Create a new JSON reader for the given path.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new JSON reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new JSON reader over an arbitrary reader, e.g. an HTTP response body.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    fn from_source(source: Source) -> Self {
+        Self {
+            source,
+            coerce: false,
+            project: false,
+            n_rows: None,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Cast columns to the schema types after reading (default: false).
+    ///
+    /// JSON numbers are inferred as `Int64`/`Float64`, so narrower schema
+    /// types such as `i32` only validate with coercion enabled. Use
+    /// [`finish_with_report`](Self::finish_with_report) to see which columns were cast.
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+    
+    /// Drop keys not declared in the schema instead of keeping them untyped (default: false).
+    pub fn project_to_schema(mut self, project: bool) -> Self {
+        self.project = project;
+        self
+    }
+    
+    /// Keep only the first `limit` records.
+    ///
+    /// A JSON array is still parsed whole; prefer NDJSON for previewing large inputs.
+    pub fn with_n_rows(mut self, limit: usize) -> Self {
+        self.n_rows = Some(limit);
+        self
+    }
+    
+    /// Read the JSON file and validate it against the schema.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        self.finish_with_report().map(|(df, _)| df)
+    }
+    
+    /// Read the JSON file and also report the columns cast in coercion mode.
+    pub fn finish_with_report(self) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+        let source = self.source.describe();
+        let mut df = match self.source.open()? {
+            OpenSource::File(file) => polars::prelude::JsonReader::new(file)
+                .with_json_format(JsonFormat::Json)
+                .finish()?,
+            OpenSource::Bytes(bytes) => polars::prelude::JsonReader::new(bytes)
+                .with_json_format(JsonFormat::Json)
+                .finish()?,
+        };
+        if let Some(n_rows) = self.n_rows {
+            df = df.head(Some(n_rows));
+        }
+        if self.project {
+            df = df.select(Sch::column_names())?;
+        }
+        
+        finish_coercion(df, self.coerce, &source)
+    }
+}
+
+/// Writer for JSON files (an array of objects).
+#[cfg(feature = "json")]
+pub struct JsonWriter<'a, Sch: Schema> {
+    df: &'a TypedDataFrame<Sch>,
+}
+
+#[cfg(feature = "json")]
+impl<'a, Sch: Schema> JsonWriter<'a, Sch> {
+    /// Create a new JSON writer for the given DataFrame.
+    pub fn new(df: &'a TypedDataFrame<Sch>) -> Self {
+        Self { df }
+    }
+    
+    /// Write the DataFrame to a JSON file.
     pub fn finish(self, path: impl AsRef<Path>) -> PolarsResult<()> {
-        let mut file = std::fs::File::create(path)?;
+        let file = std::fs::File::create(path)?;
+        self.finish_to_writer(file)
+    }
+    
+    /// Write the DataFrame to an arbitrary writer, e.g. a socket or HTTP response.
+    pub fn finish_to_writer(self, mut writer: impl Write) -> PolarsResult<()> {
         let mut df_clone = self.df.inner().clone();
-        polars::prelude::CsvWriter::new(&mut file)
-            .include_header(self.has_header)
+        polars::prelude::JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::Json)
             .finish(&mut df_clone)?;
         Ok(())
     }
+    
+    /// Write the DataFrame into an in-memory buffer.
+    pub fn to_bytes(self) -> PolarsResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.finish_to_writer(&mut buffer)?;
+        Ok(buffer)
+    }
 }
 
-/// Reader for Parquet files with schema validation.
-pub struct ParquetReader<Sch: Schema> {
-    path: String,
+/// Reader for newline-delimited JSON (JSON Lines) files with schema validation.
+///
+/// Values are parsed directly into the schema types rather than inferred.
+#[cfg(feature = "json")]
+pub struct NdJsonReader<Sch: Schema> {
+    source: Source,
+    ignore_unknown_keys: bool,
+    n_rows: Option<usize>,
     _phantom: std::marker::PhantomData<Sch>,
 }
 
-impl<Sch: Schema> ParquetReader<Sch> {
-    /// Create a new Parquet reader for the given path.
+#[cfg(feature = "json")]
+impl<Sch: Schema> NdJsonReader<Sch> {
+    /// Create a new NDJSON reader for the given path.
     pub fn new(path: impl AsRef<Path>) -> Self {
+        Self::from_source(Source::from_path(path))
+    }
+    
+    /// Create a new NDJSON reader over in-memory bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self::from_source(Source::Bytes(bytes.to_vec()))
+    }
+    
+    /// Create a new NDJSON reader over an arbitrary reader, e.g. an HTTP response body.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_reader(reader: impl Read + 'static) -> Self {
+        Self::from_source(Source::Reader(Box::new(reader)))
+    }
+    
+    /// Create a new NDJSON reader over standard input, for use in Unix pipelines.
+    ///
+    /// The input is buffered into memory when the reader is finished.
+    pub fn from_stdin() -> Self {
+        Self::from_reader(std::io::stdin())
+    }
+    
+    fn from_source(source: Source) -> Self {
         Self {
-            path: path.as_ref().to_string_lossy().to_string(),
+            source,
+            ignore_unknown_keys: false,
+            n_rows: None,
             _phantom: std::marker::PhantomData,
         }
     }
     
-    /// Read the Parquet file and validate it against the schema.
+    /// Silently drop keys that are not part of the schema (default: false).
+    ///
+    /// When disabled, a record containing an undeclared key is an error.
+    pub fn ignore_unknown_keys(mut self, ignore: bool) -> Self {
+        self.ignore_unknown_keys = ignore;
+        self
+    }
+    
+    /// Stop reading after `limit` records, e.g. to preview a large file.
+    pub fn with_n_rows(mut self, limit: usize) -> Self {
+        self.n_rows = Some(limit);
+        self
+    }
+    
+    /// Read the NDJSON file and validate it against the schema.
     pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
-        let file = std::fs::File::open(&self.path)?;
-        let df = polars::prelude::ParquetReader::new(file).finish()?;
+        let ignore_unknown_keys = self.ignore_unknown_keys;
+        let n_rows = self.n_rows;
+        let source = self.source.describe();
+        let df = match self.source.open()? {
+            OpenSource::File(file) => Self::read(file, ignore_unknown_keys, n_rows)?,
+            OpenSource::Bytes(bytes) => Self::read(bytes, ignore_unknown_keys, n_rows)?,
+        };
+        
+        TypedDataFrame::from_source(df, &source)
+    }
+    
+    fn read<R: MmapBytesReader>(reader: R, ignore_unknown_keys: bool, n_rows: Option<usize>) -> PolarsResult<DataFrame> {
+        let schema = Sch::schema();
+        let reader = polars::prelude::JsonLineReader::new(reader)
+            .with_n_rows(n_rows);
         
-        TypedDataFrame::new(df)
+        if ignore_unknown_keys {
+            reader.with_schema(std::sync::Arc::new(schema)).finish()
+        } else {
+            let df = reader.with_schema_overwrite(&schema).finish()?;
+            let column_names = Sch::column_names();
+            if let Some(unknown) = df
+                .get_column_names()
+                .into_iter()
+                .find(|name| !column_names.contains(&name.as_str()))
+            {
+                return Err(PolarsError::SchemaMismatch(
+                    format!("Unknown key '{}' not declared in schema", unknown).into()
+                ));
+            }
+            Ok(df)
+        }
     }
 }
 
-/// Writer for Parquet files.
-pub struct ParquetWriter<'a, Sch: Schema> {
+/// Writer for newline-delimited JSON (JSON Lines) files.
+#[cfg(feature = "json")]
+pub struct NdJsonWriter<'a, Sch: Schema> {
     df: &'a TypedDataFrame<Sch>,
 }
 
-impl<'a, Sch: Schema> ParquetWriter<'a, Sch> {
-    /// Create a new Parquet writer for the given DataFrame.
+#[cfg(feature = "json")]
+impl<'a, Sch: Schema> NdJsonWriter<'a, Sch> {
+    /// Create a new NDJSON writer for the given DataFrame.
     pub fn new(df: &'a TypedDataFrame<Sch>) -> Self {
         Self { df }
     }
     
-    /// Write the DataFrame to a Parquet file.
+    /// Write the DataFrame to an NDJSON file, one object per line.
     pub fn finish(self, path: impl AsRef<Path>) -> PolarsResult<()> {
-        let mut file = std::fs::File::create(path)?;
+        let file = std::fs::File::create(path)?;
+        self.finish_to_writer(file)
+    }
+    
+    /// Write the DataFrame to an arbitrary writer, e.g. a socket or HTTP response.
+    pub fn finish_to_writer(self, mut writer: impl Write) -> PolarsResult<()> {
         let mut df_clone = self.df.inner().clone();
-        polars::prelude::ParquetWriter::new(&mut file)
+        polars::prelude::JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::JsonLines)
             .finish(&mut df_clone)?;
         Ok(())
     }
+    
+    /// Write the DataFrame to standard output, for use in Unix pipelines.
+    pub fn finish_to_stdout(self) -> PolarsResult<()> {
+        let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+        self.finish_to_writer(&mut stdout)?;
+        stdout.flush()?;
+        Ok(())
+    }
+    
+    /// Write the DataFrame into an in-memory buffer.
+    pub fn to_bytes(self) -> PolarsResult<Vec<u8>> {
+        let mut buffer = Vec::new();
+        self.finish_to_writer(&mut buffer)?;
+        Ok(buffer)
+    }
+}
+
+/// Lazily scan a CSV file as a typed frame.
+///
+/// The schema drives parsing, so projections and filters are pushed into the
+/// scan; validation happens on collect. Use [`CsvReader::scan`] for options.
+pub fn scan_csv<Sch: Schema>(path: impl AsRef<Path>) -> PolarsResult<TypedLazyFrame<Sch>> {
+    CsvReader::new(path).scan()
+}
+
+/// Lazily scan a Parquet file as a typed frame.
+///
+/// Only the schema's columns are decoded and filters are pushed into the scan;
+/// validation happens on collect. Use [`ParquetReader::scan`] for options.
+#[cfg(feature = "parquet")]
+pub fn scan_parquet<Sch: Schema>(path: impl AsRef<Path>) -> PolarsResult<TypedLazyFrame<Sch>> {
+    ParquetReader::new(path).scan()
+}
+
+/// Read every Parquet file matching a glob pattern, e.g. `"data/*.parquet"`.
+///
+/// Each file is validated against the schema on its own, so an error names the
+/// offending file. The frames are concatenated in path order, keeping only the
+/// schema columns. Files are read concurrently; see [`ParallelParquetReader`].
+#[cfg(all(feature = "fs", feature = "parquet"))]
+pub fn read_parquet_glob<Sch: Schema>(pattern: &str) -> PolarsResult<TypedDataFrame<Sch>> {
+    ParallelParquetReader::<Sch>::from_glob(pattern)?.finish()
+}
+
+/// Reader that loads many Parquet files concurrently, e.g. the parts of a partitioned dataset.
+///
+/// Every file is read and validated against the schema on a worker thread;
+/// the frames are concatenated in the order the paths were given, keeping
+/// only the schema columns. The first failure stops the remaining reads and
+/// names the offending file.
+///
+/// # Example
+///
+/// ```ignore
+/// let events = ParallelParquetReader::<EventSchema>::from_glob("lake/events/*/*.parquet")?
+///     .concurrency(16)
+///     .finish()?;
+/// ```
+#[cfg(all(feature = "fs", feature = "parquet"))]
+pub struct ParallelParquetReader<Sch: Schema> {
+    paths: Vec<std::path::PathBuf>,
+    concurrency: usize,
+    coerce: bool,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+#[cfg(all(feature = "fs", feature = "parquet"))]
+impl<Sch: Schema> ParallelParquetReader<Sch> {
+    /// Create a reader for the given files.
+    pub fn new(paths: impl IntoIterator<Item = impl AsRef<Path>>) -> Self {
+        Self {
+            paths: paths.into_iter().map(|path| path.as_ref().to_path_buf()).collect(),
+            concurrency: std::thread::available_parallelism().map_or(4, |n| n.get()),
+            coerce: false,
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Create a reader for every file matching a glob pattern, in path order.
+    pub fn from_glob(pattern: &str) -> PolarsResult<Self> {
+        let paths = glob::glob(pattern)
+            .map_err(|err| PolarsError::ComputeError(
+                format!("Invalid glob pattern '{}': {}", pattern, err).into()
+            ))?
+            .map(|path| path.map_err(|err| PolarsError::from(std::io::Error::from(err))))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        if paths.is_empty() {
+            return Err(PolarsError::NoData(
+                format!("No files match pattern '{}'", pattern).into()
+            ));
+        }
+        Ok(Self::new(paths))
+    }
+    
+    /// Set how many files are read at the same time (default: the number of CPUs).
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+    
+    /// Cast columns stored with a different type to the schema types (default: false).
+    pub fn coerce(mut self, coerce: bool) -> Self {
+        self.coerce = coerce;
+        self
+    }
+    
+    /// Read all files and concatenate them into one validated frame.
+    pub fn finish(self) -> PolarsResult<TypedDataFrame<Sch>> {
+        if self.paths.is_empty() {
+            return Err(PolarsError::NoData("No Parquet files to read".into()));
+        }
+        
+        let paths = &self.paths;
+        let coerce = self.coerce;
+        let next = std::sync::atomic::AtomicUsize::new(0);
+        let failed = std::sync::atomic::AtomicBool::new(false);
+        let results: Vec<std::sync::Mutex<Option<PolarsResult<DataFrame>>>> =
+            paths.iter().map(|_| std::sync::Mutex::new(None)).collect();
+        std::thread::scope(|scope| {
+            for _ in 0..self.concurrency.min(paths.len()) {
+                scope.spawn(|| {
+                    use std::sync::atomic::Ordering;
+                    while !failed.load(Ordering::Relaxed) {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(path) = paths.get(index) else {
+                            break;
+                        };
+                        let result = ParquetReader::<Sch>::new(path)
+                            .coerce(coerce)
+                            .finish()
+                            .and_then(|df| df.into_inner().select(Sch::column_names()))
+                            .map_err(|err| err.context(format!("Failed to read '{}'", path.display()).into()));
+                        if result.is_err() {
+                            failed.store(true, Ordering::Relaxed);
+                        }
+                        *results[index].lock().unwrap() = Some(result);
+                    }
+                });
+            }
+        });
+        
+        let mut combined: Option<DataFrame> = None;
+        // Report the first failure in path order; reads after it may not have run
+        for result in results {
+            let Some(df) = result.into_inner().unwrap() else {
+                continue;
+            };
+            append_chunk(&mut combined, df?)?;
+        }
+        TypedDataFrame::from_source(combined.expect("the first file is always read"), "Parquet dataset")
+    }
+}
+
+/// Read every CSV file matching a glob pattern, e.g. `"data/*.csv"`.
+///
+/// See [`read_parquet_glob`] for how files are validated and combined.
+#[cfg(feature = "fs")]
+pub fn read_csv_glob<Sch: Schema>(pattern: &str) -> PolarsResult<TypedDataFrame<Sch>> {
+    read_glob(pattern, |path| CsvReader::<Sch>::new(path).finish())
+}
+
+#[cfg(feature = "fs")]
+fn read_glob<Sch: Schema>(
+    pattern: &str,
+    read: impl Fn(&Path) -> PolarsResult<TypedDataFrame<Sch>>,
+) -> PolarsResult<TypedDataFrame<Sch>> {
+    let paths = glob::glob(pattern).map_err(|err| PolarsError::ComputeError(
+        format!("Invalid glob pattern '{}': {}", pattern, err).into()
+    ))?;
+    
+    let mut combined: Option<DataFrame> = None;
+    for path in paths {
+        let path = path.map_err(|err| PolarsError::from(std::io::Error::from(err)))?;
+        let df = read(&path)
+            .map_err(|err| err.context(format!("Failed to read '{}'", path.display()).into()))?
+            .into_inner()
+            .select(Sch::column_names())?;
+        match combined.as_mut() {
+            Some(combined) => {
+                combined.vstack_mut(&df)?;
+            }
+            None => combined = Some(df),
+        }
+    }
+    
+    let combined = combined.ok_or_else(|| PolarsError::NoData(
+        format!("No files match pattern '{}'", pattern).into()
+    ))?;
+    TypedDataFrame::from_source(combined, pattern)
+}
+
+/// Write a DataFrame as a hive-partitioned Parquet dataset.
+///
+/// Rows are split by the values of the `by` columns into directories such as
+/// `root/date=2024-01-01/region=EU/part-0.parquet`. Partition columns are
+/// encoded in the directory names only, as downstream query engines expect,
+/// with characters such as `/` and `=` percent-encoded as Hive does.
+///
+/// Writing into an existing dataset adds files: each partition gets the next
+/// free `part-N.parquet`, and existing files are never overwritten.
+#[cfg(all(feature = "parquet", feature = "partition_by"))]
+pub fn write_parquet_partitioned<Sch: Schema>(
+    df: &TypedDataFrame<Sch>,
+    root: impl AsRef<Path>,
+    by: impl ColumnSet,
+) -> PolarsResult<()> {
+    let by = by.names();
+    for partition in df.inner().partition_by_stable(by.iter().copied(), true)? {
+        let mut dir = root.as_ref().to_path_buf();
+        for name in &by {
+            let value = partition.column(name)?.get(0)?;
+            dir.push(format!("{}={}", name, partition_value(value)));
+        }
+        std::fs::create_dir_all(&dir)?;
+        
+        let mut data = partition.drop_many(by.iter().copied());
+        let file = create_next_part(&dir)?;
+        polars::prelude::ParquetWriter::new(file).finish(&mut data)?;
+    }
+    Ok(())
+}
+
+/// Create the first `part-N.parquet` in `dir` that does not exist yet.
+#[cfg(all(feature = "parquet", feature = "partition_by"))]
+fn create_next_part(dir: &Path) -> PolarsResult<std::fs::File> {
+    for part in 0.. {
+        match std::fs::OpenOptions::new().write(true).create_new(true).open(dir.join(format!("part-{}.parquet", part))) {
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            result => return Ok(result?),
+        }
+    }
+    unreachable!("some part number is free")
+}
+
+/// Format a partition key the way hive-style readers expect.
+#[cfg(all(feature = "parquet", feature = "partition_by"))]
+fn partition_value(value: AnyValue) -> String {
+    let value = match value {
+        AnyValue::Null => return "__HIVE_DEFAULT_PARTITION__".to_string(),
+        AnyValue::String(s) => s.to_string(),
+        AnyValue::StringOwned(s) => s.to_string(),
+        other => other.to_string(),
+    };
+    // Hive leaves dots alone, but a value of only dots is encoded too
+    let only_dots = value.chars().all(|c| c == '.');
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_ascii_control() || "\"#%'*/:=?\\{[]^".contains(c) || (only_dots && c == '.') {
+            escaped.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            escaped.push(c);
+        }
+    }
+    escaped
+}
+
+/// A column that a reader in coercion mode cast to its schema type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Coercion {
+    /// The column name
+    pub column: String,
+    /// The type the column was read as
+    pub from: DataType,
+    /// The schema type it was cast to
+    pub to: DataType,
+}
+
+/// Optionally coerce `df` to the schema, then validate it.
+fn finish_coercion<Sch: Schema>(
+    df: DataFrame,
+    coerce: bool,
+    source: &str,
+) -> PolarsResult<(TypedDataFrame<Sch>, Vec<Coercion>)> {
+    let (df, coercions) = if coerce {
+        coerce_to_schema::<Sch>(df)?
+    } else {
+        (df, Vec::new())
+    };
+    Ok((TypedDataFrame::from_source(df, source)?, coercions))
+}
+
+/// Cast every schema column of `df` to its declared type.
+///
+/// Columns that already have the expected type are left untouched; a value
+/// that cannot be represented in the target type is an error. Floats are only
+/// cast to integers when every value is integral, so `2.5` is rejected rather
+/// than truncated.
+fn coerce_to_schema<Sch: Schema>(mut df: DataFrame) -> PolarsResult<(DataFrame, Vec<Coercion>)> {
+    let schema = Sch::schema();
+    let mut coercions = Vec::new();
+    for (name, dtype) in schema.iter() {
+        // Missing columns are reported by the validation that follows
+        let Ok(column) = df.column(name) else {
+            continue;
+        };
+        if column.dtype() != dtype {
+            let from = column.dtype().clone();
+            if from.is_float() && dtype.is_integer() {
+                let values = column.as_materialized_series().cast(&DataType::Float64)?;
+                if let Some(value) = values.f64()?.into_iter().flatten().find(|value| value.fract() != 0.0) {
+                    return Err(PolarsError::ComputeError(
+                        format!("Column '{}' holds the non-integral value {}, which cannot be cast to {}", name, value, dtype).into()
+                    ));
+                }
+            }
+            let cast = column.as_materialized_series().strict_cast(dtype)?;
+            df.with_column(cast)?;
+            coercions.push(Coercion {
+                column: name.to_string(),
+                from,
+                to: dtype.clone(),
+            });
+        }
+    }
+    Ok((df, coercions))
 }
 
 /// Extension trait for TypedDataFrame to add I/O convenience methods.
@@ -123,7 +2465,20 @@ pub trait TypedDataFrameIo<Sch: Schema> {
     fn write_csv(&self, path: impl AsRef<Path>) -> PolarsResult<()>;
     
     /// Write this DataFrame to a Parquet file.
+    #[cfg(feature = "parquet")]
     fn write_parquet(&self, path: impl AsRef<Path>) -> PolarsResult<()>;
+    
+    /// Write this DataFrame to a JSON file.
+    #[cfg(feature = "json")]
+    fn write_json(&self, path: impl AsRef<Path>) -> PolarsResult<()>;
+    
+    /// Write this DataFrame to a newline-delimited JSON file.
+    #[cfg(feature = "json")]
+    fn write_ndjson(&self, path: impl AsRef<Path>) -> PolarsResult<()>;
+    
+    /// Write this DataFrame as a hive-partitioned Parquet dataset under `root`.
+    #[cfg(all(feature = "parquet", feature = "partition_by"))]
+    fn write_parquet_partitioned(&self, root: impl AsRef<Path>, by: impl ColumnSet) -> PolarsResult<()>;
 }
 
 impl<Sch: Schema> TypedDataFrameIo<Sch> for TypedDataFrame<Sch> {
@@ -131,7 +2486,23 @@ impl<Sch: Schema> TypedDataFrameIo<Sch> for TypedDataFrame<Sch> {
         CsvWriter::new(self).finish(path)
     }
     
+    #[cfg(feature = "parquet")]
     fn write_parquet(&self, path: impl AsRef<Path>) -> PolarsResult<()> {
         ParquetWriter::new(self).finish(path)
     }
+    
+    #[cfg(feature = "json")]
+    fn write_json(&self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        JsonWriter::new(self).finish(path)
+    }
+    
+    #[cfg(feature = "json")]
+    fn write_ndjson(&self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        NdJsonWriter::new(self).finish(path)
+    }
+    
+    #[cfg(all(feature = "parquet", feature = "partition_by"))]
+    fn write_parquet_partitioned(&self, root: impl AsRef<Path>, by: impl ColumnSet) -> PolarsResult<()> {
+        write_parquet_partitioned(self, root, by)
+    }
 }
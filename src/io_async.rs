@@ -0,0 +1,62 @@
+//! Async I/O for typed DataFrames on the tokio runtime.
+//!
+//! Requires the `async` feature. Files are accessed through `tokio::fs` and
+//! parsing/serialization runs on tokio's blocking thread pool, so neither
+//! stalls the executor.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::io::{CsvReader, CsvWriter};
+#[cfg(feature = "parquet")]
+use crate::io::{ParquetReader, ParquetWriter};
+use std::path::Path;
+
+/// Read a CSV file and validate it against the schema without blocking the executor.
+pub async fn read_csv_async<Sch: Schema + Send + 'static>(
+    path: impl AsRef<Path>,
+) -> PolarsResult<TypedDataFrame<Sch>> {
+    let bytes = tokio::fs::read(path).await?;
+    run_blocking(move || CsvReader::<Sch>::from_bytes(&bytes).finish()).await
+}
+
+/// Read a Parquet file and validate it against the schema without blocking the executor.
+#[cfg(feature = "parquet")]
+pub async fn read_parquet_async<Sch: Schema + Send + 'static>(
+    path: impl AsRef<Path>,
+) -> PolarsResult<TypedDataFrame<Sch>> {
+    let bytes = tokio::fs::read(path).await?;
+    run_blocking(move || ParquetReader::<Sch>::from_bytes(&bytes).finish()).await
+}
+
+/// Write a DataFrame to a CSV file without blocking the executor.
+pub async fn write_csv_async<Sch: Schema + Send + 'static>(
+    df: &TypedDataFrame<Sch>,
+    path: impl AsRef<Path>,
+) -> PolarsResult<()> {
+    let df = df.clone();
+    let bytes = run_blocking(move || CsvWriter::new(&df).to_bytes()).await?;
+    tokio::fs::write(path, bytes).await?;
+    Ok(())
+}
+
+/// Write a DataFrame to a Parquet file without blocking the executor.
+#[cfg(feature = "parquet")]
+pub async fn write_parquet_async<Sch: Schema + Send + 'static>(
+    df: &TypedDataFrame<Sch>,
+    path: impl AsRef<Path>,
+) -> PolarsResult<()> {
+    let df = df.clone();
+    let bytes = run_blocking(move || ParquetWriter::new(&df).to_bytes()).await?;
+    tokio::fs::write(path, bytes).await?;
+    Ok(())
+}
+
+/// Run CPU-bound work on the blocking pool and surface panics as errors.
+async fn run_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> PolarsResult<T> + Send + 'static,
+) -> PolarsResult<T> {
+    tokio::task::spawn_blocking(f).await.map_err(|err| {
+        PolarsError::ComputeError(format!("Blocking I/O task failed: {}", err).into())
+    })?
+}
@@ -0,0 +1,180 @@
+//! Typed equi-joins of two frames on key columns of matching types.
+
+use polars::prelude::*;
+use crate::schema::{is_disjoint, ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::marker::PhantomData;
+
+/// Which rows a [`TypedDataFrame::join`] keeps; see
+/// [`outer_join`](TypedDataFrame::outer_join) for every row of both frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum JoinKind {
+    /// Only rows whose keys occur in both frames
+    Inner,
+    /// Every row of the left frame, with nulls where the right frame has no match
+    Left,
+}
+
+/// The schema of an inner or left join of frames of the schemas `A` and
+/// `B`: the columns of `A` followed by the columns of `B`.
+///
+/// The two schemas may not share a column name; joins of schemas from
+/// `define_schema!` or `#[derive(Schema)]` that do fail to compile, as with
+/// [`assert_schema_disjoint!`](crate::assert_schema_disjoint). The columns of
+/// `B` are nullable, since left joins fill them with nulls where a row has no
+/// match. Select all columns with a [`Concat`](crate::schema::Concat) of a
+/// column set of each schema.
+pub struct Joined<A: Schema, B: Schema>(PhantomData<(A, B)>);
+
+impl<A: Schema, B: Schema> Schema for Joined<A, B> {
+    type Columns = (A::Columns, B::Columns);
+    
+    fn schema() -> polars::prelude::Schema {
+        polars::prelude::Schema::from_iter(
+            A::schema().iter()
+                .chain(B::schema().iter())
+                .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+        )
+    }
+    
+    fn column_names() -> Vec<&'static str> {
+        A::column_names().into_iter().chain(B::column_names()).collect()
+    }
+    
+    fn column_formats() -> Vec<(&'static str, &'static str)> {
+        A::column_formats().into_iter().chain(B::column_formats()).collect()
+    }
+    
+    fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+        A::column_ranges().into_iter().chain(B::column_ranges()).collect()
+    }
+    
+    fn column_pii() -> Vec<(&'static str, crate::anonymize::PiiStrategy)> {
+        A::column_pii().into_iter().chain(B::column_pii()).collect()
+    }
+    
+    fn nullable_columns() -> Vec<&'static str> {
+        A::nullable_columns().into_iter().chain(B::column_names()).collect()
+    }
+    
+    fn column_docs() -> Vec<(&'static str, String)> {
+        A::column_docs().into_iter().chain(B::column_docs()).collect()
+    }
+}
+
+/// The schema of an outer join of frames of the schemas `A` and `B`: the
+/// columns of [`Joined<A, B>`], all nullable, since rows found in only one
+/// frame have nulls in every column of the other.
+pub struct OuterJoined<A: Schema, B: Schema>(PhantomData<(A, B)>);
+
+impl<A: Schema, B: Schema> Schema for OuterJoined<A, B> {
+    type Columns = (A::Columns, B::Columns);
+    
+    fn schema() -> polars::prelude::Schema {
+        Joined::<A, B>::schema()
+    }
+    
+    fn column_names() -> Vec<&'static str> {
+        Joined::<A, B>::column_names()
+    }
+    
+    fn column_formats() -> Vec<(&'static str, &'static str)> {
+        Joined::<A, B>::column_formats()
+    }
+    
+    fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+        Joined::<A, B>::column_ranges()
+    }
+    
+    fn column_pii() -> Vec<(&'static str, crate::anonymize::PiiStrategy)> {
+        Joined::<A, B>::column_pii()
+    }
+    
+    fn nullable_columns() -> Vec<&'static str> {
+        Joined::<A, B>::column_names()
+    }
+    
+    fn column_docs() -> Vec<(&'static str, String)> {
+        Joined::<A, B>::column_docs()
+    }
+}
+
+/// Fails the build of a join of `A` and `B` when their declared columns overlap.
+struct Disjoint<A: Schema, B: Schema>(PhantomData<(A, B)>);
+
+impl<A: Schema, B: Schema> Disjoint<A, B> {
+    const CHECK: () = assert!(is_disjoint(A::COLUMN_TYPES, B::COLUMN_TYPES), "joined schemas share a column");
+}
+
+impl<A: Schema> TypedDataFrame<A> {
+    /// Join with `other` where the `left_on` columns equal the `right_on` columns.
+    ///
+    /// Both key sets are the same [`ColumnSet`] type, so keys of different
+    /// types, or a different number of keys, do not compile, and neither do
+    /// schemas sharing a column name; see [`Joined`]. Both key columns are
+    /// kept, and columns outside the two schemas are dropped. Null keys never
+    /// match.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let enriched: TypedDataFrame<Joined<OrderSchema, CustomerSchema>> =
+    ///     orders.join(&customers, OrderSchema::customer_id, CustomerSchema::id, JoinKind::Left)?;
+    /// let regions = enriched.column(CustomerSchema::region)?;
+    /// ```
+    pub fn join<B: Schema, K: ColumnSet>(
+        &self,
+        other: &TypedDataFrame<B>,
+        left_on: K,
+        right_on: K,
+        kind: JoinKind,
+    ) -> PolarsResult<TypedDataFrame<Joined<A, B>>> {
+        let join_type = match kind {
+            JoinKind::Inner => JoinType::Inner,
+            JoinKind::Left => JoinType::Left,
+        };
+        TypedDataFrame::new(self.join_frames(other, left_on, right_on, join_type)?)
+    }
+    
+    /// Join with `other` where the `left_on` columns equal the `right_on`
+    /// columns, keeping every row of both frames.
+    ///
+    /// As [`join`](Self::join), but rows without a match in the other frame
+    /// are kept too, with nulls in all of its columns, keys included.
+    pub fn outer_join<B: Schema, K: ColumnSet>(
+        &self,
+        other: &TypedDataFrame<B>,
+        left_on: K,
+        right_on: K,
+    ) -> PolarsResult<TypedDataFrame<OuterJoined<A, B>>> {
+        TypedDataFrame::new(self.join_frames(other, left_on, right_on, JoinType::Full)?)
+    }
+    
+    fn join_frames<B: Schema, K: ColumnSet>(
+        &self,
+        other: &TypedDataFrame<B>,
+        left_on: K,
+        right_on: K,
+        join_type: JoinType,
+    ) -> PolarsResult<DataFrame> {
+        let () = Disjoint::<A, B>::CHECK;
+        // Schemas without declared column types are only checked here
+        let left_columns = A::column_names();
+        if let Some(shared) = B::column_names().into_iter().find(|name| left_columns.contains(name)) {
+            return Err(PolarsError::Duplicate(
+                format!("Both joined schemas have a column '{}'", shared).into()
+            ));
+        }
+        let keys = |columns: K| -> Vec<Expr> { columns.names().into_iter().map(col).collect() };
+        self.inner()
+            .select(left_columns)?
+            .lazy()
+            .join(
+                other.inner().select(B::column_names())?.lazy(),
+                keys(left_on),
+                keys(right_on),
+                JoinArgs::new(join_type).with_coalesce(JoinCoalesce::KeepColumns),
+            )
+            .collect()
+    }
+}
@@ -0,0 +1,126 @@
+//! Unique-key witnesses: typed frames certified to have no duplicate keys.
+
+use polars::prelude::*;
+use crate::schema::{ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::ops::Deref;
+
+/// A typed frame in which every combination of the key columns `K` occurs at most once.
+///
+/// Produced by [`TypedDataFrame::unique_by`] and [`TypedDataFrame::validate_keys`];
+/// key-based operations such as [`upsert`](Self::upsert) and
+/// [`enrich`](Self::enrich) require it, so "deduplicated on `id`" is checked
+/// once instead of assumed everywhere.
+///
+/// # Example
+///
+/// ```ignore
+/// let customers = customers.validate_keys(CustomerSchema::id)?;
+/// let orders: TypedDataFrame<OrderWithCustomer> = customers.enrich(&orders)?;
+/// ```
+pub struct Keyed<S: Schema, K: ColumnSet> {
+    df: TypedDataFrame<S>,
+    keys: K,
+}
+
+impl<S: Schema, K: ColumnSet> Keyed<S, K> {
+    /// Certify a frame as unique on `keys`, failing if any key occurs twice.
+    pub fn new(df: TypedDataFrame<S>, keys: K) -> PolarsResult<Self> {
+        let duplicated = df.inner().select(keys.names())?.is_duplicated()?;
+        let duplicates = duplicated.sum().unwrap_or(0);
+        if duplicates > 0 {
+            return Err(PolarsError::Duplicate(
+                format!("{} rows share their key ({}) with another row", duplicates, keys.names().join(", ")).into()
+            ));
+        }
+        Ok(Self { df, keys })
+    }
+    
+    /// Certify a frame as unique on `keys` without checking.
+    ///
+    /// # Safety
+    ///
+    /// Key-based operations on the result give wrong answers if a key occurs twice.
+    pub unsafe fn new_unchecked(df: TypedDataFrame<S>, keys: K) -> Self {
+        Self { df, keys }
+    }
+    
+    /// The key columns.
+    pub fn keys(&self) -> &K {
+        &self.keys
+    }
+    
+    /// Drop the witness and return the frame.
+    pub fn into_inner(self) -> TypedDataFrame<S> {
+        self.df
+    }
+    
+    /// Insert the rows of `updates`, replacing the rows whose keys they share.
+    ///
+    /// Replaced rows move to the position of their update, after the rows
+    /// that were not updated.
+    pub fn upsert(&self, updates: &Keyed<S, K>) -> PolarsResult<Self>
+    where
+        K: Copy,
+    {
+        let subset: Vec<String> = self.keys.names().into_iter().map(String::from).collect();
+        let updates = updates.df.inner().select(self.df.inner().get_column_names_str())?;
+        let combined = self.df.inner().vstack(&updates)?;
+        let upserted = combined.unique_stable(Some(&subset), UniqueKeepStrategy::Last, None)?;
+        Ok(Self {
+            df: unsafe { TypedDataFrame::new_unchecked(upserted) },
+            keys: self.keys,
+        })
+    }
+    
+    /// Left-join `facts` to this frame on the key columns, as to a dimension table.
+    ///
+    /// Since the keys are unique, every row of `facts` appears exactly once
+    /// in the result, in its original order; rows without a match get nulls,
+    /// so the columns taken from this frame are `Option<T>` in `Out`.
+    pub fn enrich<L: Schema, Out: Schema>(&self, facts: &TypedDataFrame<L>) -> PolarsResult<TypedDataFrame<Out>> {
+        let on: Vec<Expr> = self.keys.names().into_iter().map(col).collect();
+        let df = facts
+            .inner()
+            .clone()
+            .lazy()
+            .join(self.df.inner().clone().lazy(), on.clone(), on, JoinArgs::new(JoinType::Left))
+            .collect()?;
+        TypedDataFrame::new(df)
+    }
+}
+
+impl<S: Schema, K: ColumnSet> Deref for Keyed<S, K> {
+    type Target = TypedDataFrame<S>;
+    
+    fn deref(&self) -> &Self::Target {
+        &self.df
+    }
+}
+
+impl<S: Schema, K: ColumnSet + Clone> Clone for Keyed<S, K> {
+    fn clone(&self) -> Self {
+        Self {
+            df: self.df.clone(),
+            keys: self.keys.clone(),
+        }
+    }
+}
+
+impl<S: Schema, K: ColumnSet> From<Keyed<S, K>> for TypedDataFrame<S> {
+    fn from(keyed: Keyed<S, K>) -> Self {
+        keyed.df
+    }
+}
+
+impl<S: Schema, K: ColumnSet> std::fmt::Debug for Keyed<S, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.df.fmt(f)
+    }
+}
+
+impl<S: Schema, K: ColumnSet> std::fmt::Display for Keyed<S, K> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.df.fmt(f)
+    }
+}
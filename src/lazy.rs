@@ -0,0 +1,182 @@
+//! Typed wrapper around Polars LazyFrame.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnSet, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::expr::TypedExpr;
+use crate::config::Config;
+use std::marker::PhantomData;
+#[cfg(feature = "streaming")]
+use std::path::Path;
+
+/// A typed wrapper around a Polars LazyFrame whose result is expected to match a schema.
+///
+/// The query is only checked against the schema when it is collected, so scans
+/// can be built without reading any data.
+pub struct TypedLazyFrame<S: Schema> {
+    inner: LazyFrame,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> TypedLazyFrame<S> {
+    /// Wrap a LazyFrame whose result should match the schema.
+    ///
+    /// The schema is validated by [`collect`](Self::collect).
+    pub fn new(lf: LazyFrame) -> Self {
+        Self {
+            inner: lf,
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// Get a reference to the underlying Polars LazyFrame.
+    pub fn inner(&self) -> &LazyFrame {
+        &self.inner
+    }
+    
+    /// Consume self and return the underlying Polars LazyFrame.
+    pub fn into_inner(self) -> LazyFrame {
+        self.inner
+    }
+    
+    /// Filter rows with a boolean expression.
+    pub fn filter(self, predicate: TypedExpr<bool>) -> Self {
+        Self::new(self.inner.filter(predicate.into_inner()))
+    }
+    
+    /// Select columns into the schema `Out`, whose columns must have the
+    /// same types in the same order; see [`TypedDataFrame::select_as`].
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let contacts: TypedLazyFrame<ContactSchema> = scan_parquet::<UserSchema>("users.parquet")?
+    ///     .filter(col(UserSchema::active))
+    ///     .select((UserSchema::id, UserSchema::email));
+    /// ```
+    pub fn select<Out, C>(self, columns: C) -> TypedLazyFrame<Out>
+    where
+        Out: Schema<Columns = C::Columns>,
+        C: ColumnSet,
+    {
+        let exprs: Vec<Expr> = columns.names().into_iter().map(col).collect();
+        TypedLazyFrame::new(self.inner.select(exprs))
+    }
+    
+    /// Add or replace `column` with the values of `expr`, into the schema `Out`.
+    ///
+    /// The expression must have the column's type; pass `S` as `Out` to
+    /// replace a column of the schema in place.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let priced: TypedLazyFrame<PricedOrders> = orders
+    ///     .lazy()
+    ///     .with_column(PricedOrders::total, col(OrderSchema::price).mul(col(OrderSchema::quantity)));
+    /// ```
+    pub fn with_column<T: ColumnType, Out: Schema>(self, column: Column<T>, expr: TypedExpr<T>) -> TypedLazyFrame<Out> {
+        TypedLazyFrame::new(self.inner.with_column(expr.into_inner().alias(column.name())))
+    }
+    
+    /// Sort the rows by a column.
+    ///
+    /// Unlike [`TypedDataFrame::sort`], the result is no [`Sorted`](crate::sorted::Sorted)
+    /// witness, since the order is only established when the query runs.
+    pub fn sort<T: ColumnType>(self, column: Column<T>, descending: bool) -> Self {
+        Self::new(self.inner.sort(
+            [column.name()],
+            SortMultipleOptions::default().with_order_descending(descending),
+        ))
+    }
+    
+    /// Keep `length` rows starting at `offset`.
+    ///
+    /// On a scan the slice is pushed down, so only the requested rows are read.
+    pub fn slice(self, offset: i64, length: IdxSize) -> Self {
+        Self::new(self.inner.slice(offset, length))
+    }
+    
+    /// Add a row index column numbering rows from `offset`.
+    ///
+    /// The column is not part of `S`, whose validation only constrains its own columns.
+    pub fn with_row_index(self, name: &str, offset: IdxSize) -> Self {
+        Self::new(self.inner.with_row_index(name, Some(offset)))
+    }
+    
+    /// Execute the query and validate the result against the schema.
+    pub fn collect(self) -> PolarsResult<TypedDataFrame<S>> {
+        TypedDataFrame::new(self.inner.collect()?)
+    }
+    
+    /// Execute the query with the streaming engine, in batches rather than all at once.
+    ///
+    /// Operators that cannot stream fall back to the in-memory engine. Sorts,
+    /// group-bys and joins spill to disk under memory pressure, to the
+    /// `POLARS_TEMP_DIR` directory; set it, and `POLARS_FORCE_OOC` to always
+    /// spill, before the process starts.
+    #[cfg(feature = "streaming")]
+    pub fn collect_streaming(self) -> PolarsResult<TypedDataFrame<S>> {
+        TypedDataFrame::new(self.inner.with_streaming(true).collect()?)
+    }
+    
+    /// Execute the query with at most the threads `config` allows; see [`Config::run`].
+    ///
+    /// With a chunk size set, the query runs on the streaming engine in
+    /// batches of about that many rows; without the `streaming` feature the
+    /// chunk size only selects the in-memory engine. Fails unless the process
+    /// was started with that chunk size in `POLARS_STREAMING_CHUNK_SIZE`.
+    pub fn collect_with(self, config: &Config) -> PolarsResult<TypedDataFrame<S>> {
+        config.check_chunk_size()?;
+        let inner = self.inner;
+        let df = config.run(move || match config.chunk_size_hint() {
+            #[cfg(feature = "streaming")]
+            Some(_) => inner.with_streaming(true).collect(),
+            _ => inner.collect(),
+        })?;
+        TypedDataFrame::new(df)
+    }
+    
+    /// Stream the query result into a Parquet file without materializing it.
+    ///
+    /// The query's output schema is validated before any data is read.
+    #[cfg(all(feature = "streaming", feature = "parquet"))]
+    pub fn sink_parquet(mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        self.validate_plan()?;
+        self.inner.sink_parquet(path, ParquetWriteOptions::default())
+    }
+    
+    /// Stream the query result into a CSV file without materializing it.
+    ///
+    /// The query's output schema is validated before any data is read.
+    #[cfg(feature = "streaming")]
+    pub fn sink_csv(mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        self.validate_plan()?;
+        self.inner.sink_csv(path, CsvWriterOptions::default())
+    }
+    
+    /// Stream the query result into an Arrow IPC file without materializing it.
+    ///
+    /// The query's output schema is validated before any data is read.
+    #[cfg(all(feature = "streaming", feature = "ipc"))]
+    pub fn sink_ipc(mut self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        self.validate_plan()?;
+        self.inner.sink_ipc(path, IpcWriterOptions::default())
+    }
+    
+    /// Check the query's resolved output schema against `S` without executing it.
+    #[cfg(feature = "streaming")]
+    fn validate_plan(&mut self) -> PolarsResult<()> {
+        let schema = self.inner.collect_schema()?;
+        S::validate(&DataFrame::empty_with_schema(&schema))
+    }
+}
+
+impl<S: Schema> Clone for TypedLazyFrame<S> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -15,7 +15,7 @@
 //! ```ignore
 //! use typed_polars::prelude::*;
 //!
-//! // Define a schema using a struct
+//! // Define a schema using a struct (requires the `derive` feature)
 //! #[derive(Schema)]
 //! struct UserData {
 //!     id: i64,
@@ -28,16 +28,102 @@
 //! let df = TypedDataFrame::<UserData>::new(df_polars)?;
 //!
 //! // Type-safe column access - compile error if column doesn't exist
-//! let name_series = df.column::<schema::name>()?;
+//! let name_series = df.column(UserData::name)?;
 //! ```
 
 pub mod prelude;
+pub mod error;
 pub mod schema;
 pub mod series;
 pub mod dataframe;
+pub mod lazy;
 pub mod expr;
 pub mod io;
+pub mod arrow;
+pub mod codegen;
+pub mod stream;
+pub mod manifest;
+pub mod mock;
+pub mod snapshot;
+pub mod shared;
+pub mod sorted;
+pub mod non_empty;
+pub mod keyed;
+pub mod stats;
+pub mod encoding;
+pub mod scaling;
+pub mod quantile;
+pub mod aggregate;
+pub mod sessions;
+pub mod diff;
+pub mod profile;
+pub mod expectations;
+pub mod anonymize;
+pub mod sampling;
+pub(crate) mod rng;
+pub mod range_join;
+pub mod join;
+pub mod config;
+pub mod plan;
+pub mod timing;
+#[cfg(feature = "parquet")]
+pub mod cache;
+#[cfg(feature = "parquet")]
+pub mod checkpoint;
+pub mod dictionary;
+pub mod cdc;
+pub mod crosstab;
+pub mod binning;
+pub mod row;
+pub mod validation;
+#[cfg(feature = "sql")]
+pub mod sql;
+#[cfg(feature = "excel")]
+pub mod excel;
+#[cfg(feature = "async")]
+pub mod io_async;
+#[cfg(feature = "cloud")]
+pub mod cloud;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+#[cfg(feature = "database")]
+pub mod database;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "avro")]
+pub mod avro;
+#[cfg(feature = "orc")]
+pub mod orc;
+#[cfg(feature = "serde")]
+pub mod serde_support;
+#[cfg(feature = "validator")]
+pub mod validator_support;
+#[cfg(feature = "ndarray")]
+pub mod ndarray_support;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_support;
+#[cfg(feature = "linfa")]
+pub mod linfa_support;
+#[cfg(any(feature = "candle", feature = "burn"))]
+pub mod tensor;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "plot")]
+pub mod plot;
+#[cfg(feature = "diagnostics")]
+pub mod diagnostics;
+#[cfg(feature = "trybuild")]
+pub mod compile_tests;
 
 pub use dataframe::TypedDataFrame;
+pub use lazy::TypedLazyFrame;
 pub use series::TypedSeries;
 pub use schema::Schema;
+#[cfg(feature = "derive")]
+pub use typed_polars_derive::Schema;
+pub use error::{TypedPolarsError, TypedResult};
+
+// Re-exported so schemas can name temporal column types without a direct dependency
+pub use chrono;
@@ -0,0 +1,43 @@
+//! Conversion of typed frames into linfa datasets.
+//!
+//! Requires the `linfa` feature.
+
+use linfa::Dataset;
+use linfa_ndarray::{Array1, Array2, ShapeBuilder};
+use polars::prelude::*;
+use crate::schema::{Column, ColumnSet, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::series::no_null_values;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Build a linfa dataset from feature columns and a target column.
+    ///
+    /// Feature columns must be numeric and are cast to `f64`; the target keeps
+    /// its column type, so classification labels should be integer class ids.
+    /// Nulls in any selected column are an error.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let dataset = flowers.to_linfa_dataset(
+    ///     (Iris::sepal_length, Iris::sepal_width, Iris::petal_length),
+    ///     Iris::species,
+    /// )?;
+    /// let model = DecisionTree::params().fit(&dataset)?;
+    /// ```
+    pub fn to_linfa_dataset<T>(&self, features: impl ColumnSet, target: Column<T>) -> PolarsResult<Dataset<f64, T>>
+    where
+        T: NumericNative + ColumnType,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        let names = features.names();
+        let values = self.numeric_values(&names)?;
+        let records = Array2::from_shape_vec((self.height(), names.len()).f(), values)
+            .map_err(|err| PolarsError::ComputeError(format!("Cannot build feature array: {}", err).into()))?;
+        
+        let targets = self.inner().column(target.name())?.as_materialized_series().cast(&T::data_type())?;
+        let targets = Array1::from_vec(no_null_values::<T>(&targets)?);
+        
+        Ok(Dataset::new(records, targets).with_feature_names(names))
+    }
+}
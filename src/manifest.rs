@@ -0,0 +1,197 @@
+//! Sidecar manifests for end-to-end integrity checks across storage.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use std::hash::Hasher;
+use std::path::{Path, PathBuf};
+
+/// Row count, per-column null counts and a content hash of a typed frame.
+///
+/// Writers store it next to the data file as `<file>.manifest` and readers
+/// verify the frame they read against it. Only the schema's columns are
+/// covered, so a manifest written from a frame with extra columns still
+/// verifies against a projected read.
+///
+/// # Example
+///
+/// ```ignore
+/// ParquetWriter::new(&orders).write_manifest(true).finish("orders.parquet")?;
+///
+/// // Fails if the file was truncated or rewritten without a new manifest
+/// let orders = ParquetReader::<OrderSchema>::new("orders.parquet")
+///     .verify_manifest(true)
+///     .finish()?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Manifest {
+    /// Number of rows
+    pub row_count: usize,
+    /// Null count of every schema column, in schema order
+    pub null_counts: Vec<(String, usize)>,
+    /// Hex-encoded 64-bit FNV-1a hash of the schema columns' values
+    pub content_hash: String,
+}
+
+impl Manifest {
+    /// Compute the manifest of a frame.
+    pub fn compute<S: Schema>(df: &TypedDataFrame<S>) -> PolarsResult<Self> {
+        let mut hasher = Fnv1a::default();
+        let mut null_counts = Vec::new();
+        for name in S::column_names() {
+            let series = df.inner().column(name)?.as_materialized_series().to_physical_repr();
+            null_counts.push((name.to_string(), series.null_count()));
+            hasher.write(name.as_bytes());
+            for value in series.iter() {
+                hash_value(&mut hasher, &value);
+            }
+        }
+        
+        Ok(Self {
+            row_count: df.height(),
+            null_counts,
+            content_hash: format!("{:016x}", hasher.finish()),
+        })
+    }
+    
+    /// The manifest path belonging to a data file: `<file>.manifest`.
+    pub fn sidecar_path(path: impl AsRef<Path>) -> PathBuf {
+        let mut path = path.as_ref().as_os_str().to_owned();
+        path.push(".manifest");
+        PathBuf::from(path)
+    }
+    
+    /// Write the manifest next to the data file at `path`.
+    pub fn write(&self, path: impl AsRef<Path>) -> PolarsResult<()> {
+        let mut contents = format!("rows {}\nhash {}\n", self.row_count, self.content_hash);
+        for (name, nulls) in &self.null_counts {
+            contents.push_str(&format!("nulls {} {}\n", nulls, name));
+        }
+        std::fs::write(Self::sidecar_path(path), contents)?;
+        Ok(())
+    }
+    
+    /// Read the manifest stored next to the data file at `path`.
+    pub fn read(path: impl AsRef<Path>) -> PolarsResult<Self> {
+        let sidecar = Self::sidecar_path(path);
+        let contents = std::fs::read_to_string(&sidecar)?;
+        let invalid = |line: &str| PolarsError::ComputeError(
+            format!("Invalid manifest line in {}: '{}'", sidecar.display(), line).into()
+        );
+        
+        let mut row_count = None;
+        let mut content_hash = None;
+        let mut null_counts = Vec::new();
+        for line in contents.lines().filter(|line| !line.is_empty()) {
+            match line.split_once(' ') {
+                Some(("rows", rows)) => row_count = Some(rows.parse().map_err(|_| invalid(line))?),
+                Some(("hash", hash)) => content_hash = Some(hash.to_string()),
+                Some(("nulls", rest)) => {
+                    let (nulls, name) = rest.split_once(' ').ok_or_else(|| invalid(line))?;
+                    null_counts.push((name.to_string(), nulls.parse().map_err(|_| invalid(line))?));
+                }
+                _ => return Err(invalid(line)),
+            }
+        }
+        
+        Ok(Self {
+            row_count: row_count.ok_or_else(|| invalid("missing rows"))?,
+            null_counts,
+            content_hash: content_hash.ok_or_else(|| invalid("missing hash"))?,
+        })
+    }
+    
+    /// Check a frame against the manifest, reporting the first difference.
+    pub fn verify<S: Schema>(&self, df: &TypedDataFrame<S>) -> PolarsResult<()> {
+        let actual = Self::compute(df)?;
+        if actual.row_count != self.row_count {
+            return Err(mismatch(format!(
+                "expected {} rows, found {}", self.row_count, actual.row_count
+            )));
+        }
+        for (name, nulls) in &self.null_counts {
+            match actual.null_counts.iter().find(|(actual_name, _)| actual_name == name) {
+                Some((_, actual_nulls)) if actual_nulls != nulls => {
+                    return Err(mismatch(format!(
+                        "column '{}' has {} nulls, expected {}", name, actual_nulls, nulls
+                    )));
+                }
+                Some(_) => {}
+                None => return Err(mismatch(format!("column '{}' is not in the schema", name))),
+            }
+        }
+        if actual.content_hash != self.content_hash {
+            return Err(mismatch(format!(
+                "content hash is {}, expected {}", actual.content_hash, self.content_hash
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn mismatch(message: String) -> PolarsError {
+    PolarsError::ComputeError(format!("Manifest mismatch: {}", message).into())
+}
+
+/// Feed one physical value into the hash, tagged so different types never collide.
+fn hash_value(hasher: &mut Fnv1a, value: &AnyValue) {
+    match value {
+        AnyValue::Null => hasher.write_u8(0),
+        AnyValue::Boolean(b) => hasher.write(&[1, *b as u8]),
+        AnyValue::Float32(f) => {
+            hasher.write_u8(2);
+            hasher.write_u64(f64::from(*f).to_bits());
+        }
+        AnyValue::Float64(f) => {
+            hasher.write_u8(2);
+            hasher.write_u64(f.to_bits());
+        }
+        AnyValue::String(s) => hash_str(hasher, s),
+        AnyValue::StringOwned(s) => hash_str(hasher, s),
+        other => match other.extract::<i64>() {
+            Some(i) => {
+                hasher.write_u8(3);
+                hasher.write_i64(i);
+            }
+            // Large unsigned values and nested types
+            None => hash_str(hasher, &other.to_string()),
+        },
+    }
+}
+
+fn hash_str(hasher: &mut Fnv1a, s: &str) {
+    hasher.write_u8(4);
+    hasher.write_usize(s.len());
+    hasher.write(s.as_bytes());
+}
+
+/// 64-bit FNV-1a, chosen because its output is stable across Rust releases and platforms.
+struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Fnv1a(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+    
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+    
+    // Fixed-width little-endian, so hashes match across platforms
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+    
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
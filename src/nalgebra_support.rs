@@ -0,0 +1,48 @@
+//! Export of numeric typed frames and columns to nalgebra matrices and vectors.
+//!
+//! Requires the `nalgebra` feature.
+
+use nalgebra::{DMatrix, DVector, Scalar};
+use polars::prelude::*;
+use crate::schema::{ensure_numeric, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::series::{no_null_values, TypedSeries};
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Copy the schema columns into a `rows x columns` matrix.
+    ///
+    /// Every schema column must be numeric and free of nulls; values are
+    /// cast to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let design: DMatrix<f64> = samples.to_dmatrix::<f64>()?;
+    /// let fit = design.svd(true, true);
+    /// ```
+    pub fn to_dmatrix<T>(&self) -> PolarsResult<DMatrix<T>>
+    where
+        T: NumericNative + ColumnType + Scalar,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        ensure_numeric::<S>()?;
+        // nalgebra stores matrices column-major, so the columns can be appended one after another
+        let mut values = Vec::with_capacity(self.height() * S::column_names().len());
+        for name in S::column_names() {
+            let series = self.inner().column(name)?.as_materialized_series().cast(&T::data_type())?;
+            values.extend(no_null_values::<T>(&series)?);
+        }
+        Ok(DMatrix::from_vec(self.height(), S::column_names().len(), values))
+    }
+}
+
+impl<T> TypedSeries<T>
+where
+    T: NumericNative + ColumnType + Scalar,
+    T::PolarsType: PolarsNumericType<Native = T>,
+{
+    /// Copy the column into a vector; fails if it contains nulls.
+    pub fn to_dvector(&self) -> PolarsResult<DVector<T>> {
+        Ok(DVector::from_vec(no_null_values::<T>(self.inner())?))
+    }
+}
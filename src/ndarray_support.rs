@@ -0,0 +1,65 @@
+//! Conversion between numeric typed frames and 2-D ndarrays.
+//!
+//! Requires the `ndarray` feature.
+
+use ndarray::{Array2, ArrayView2};
+use polars::prelude::*;
+use crate::schema::{ensure_numeric, Schema};
+use crate::dataframe::TypedDataFrame;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Copy the schema columns into a row-major `rows x columns` array.
+    ///
+    /// Every schema column must be numeric and free of nulls; values are
+    /// cast to `T`, so e.g. integer columns read fine into an `f64` array.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let features: Array2<f64> = measurements.to_ndarray::<f64>()?;
+    /// ```
+    pub fn to_ndarray<T>(&self) -> PolarsResult<Array2<T>>
+    where
+        T: NumericNative,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        ensure_numeric::<S>()?;
+        let df = self.select(S::column_names())?;
+        if let Some(column) = df.get_columns().iter().find(|column| column.null_count() > 0) {
+            return Err(PolarsError::ComputeError(
+                format!("Column '{}' contains nulls and cannot be converted to an array", column.name()).into()
+            ));
+        }
+        df.to_ndarray::<T::PolarsType>(IndexOrder::C)
+    }
+    
+    /// Build a frame from a 2-D array whose columns are the schema columns, in schema order.
+    ///
+    /// Each array column is named after its schema column and cast to the
+    /// schema type; casts that would lose values fail.
+    pub fn from_ndarray<T>(array: ArrayView2<T>) -> PolarsResult<Self>
+    where
+        T: NumericNative,
+        T::PolarsType: PolarsNumericType<Native = T>,
+    {
+        ensure_numeric::<S>()?;
+        let schema = S::schema();
+        if array.ncols() != schema.len() {
+            return Err(PolarsError::ShapeMismatch(
+                format!("Array has {} columns, schema has {}", array.ncols(), schema.len()).into()
+            ));
+        }
+        
+        let columns = schema
+            .iter()
+            .zip(array.columns())
+            .map(|((name, dtype), values)| {
+                ChunkedArray::<T::PolarsType>::from_vec(name.clone(), values.to_vec())
+                    .into_series()
+                    .strict_cast(dtype)
+                    .map(|s| s.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Self::new(DataFrame::new(columns)?)
+    }
+}
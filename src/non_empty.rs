@@ -0,0 +1,108 @@
+//! Non-empty witnesses: typed frames certified to hold at least one row.
+
+use polars::prelude::*;
+use num_traits::NumCast;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::num::NonZeroUsize;
+use std::ops::Deref;
+
+/// A value known to hold at least one row, e.g. `NonEmpty<TypedDataFrame<S>>`.
+///
+/// Built with the checked [`NonEmpty::new`] or [`TypedDataFrame::non_empty`],
+/// so accessors that need a row, like [`first`](NonEmpty::first) or
+/// [`min`](NonEmpty::min), return values instead of `Option`s.
+///
+/// # Example
+///
+/// ```ignore
+/// let readings = readings.non_empty()?;
+/// let peak: f64 = readings.max(ReadingSchema::value)?;
+/// ```
+pub struct NonEmpty<F> {
+    inner: F,
+}
+
+impl<F> NonEmpty<F> {
+    /// Drop the witness and return the value.
+    pub fn into_inner(self) -> F {
+        self.inner
+    }
+}
+
+impl<S: Schema> NonEmpty<TypedDataFrame<S>> {
+    /// Certify a frame as non-empty, failing with `NoData` if it has no rows.
+    pub fn new(df: TypedDataFrame<S>) -> PolarsResult<Self> {
+        if df.is_empty() {
+            return Err(PolarsError::NoData("Expected a frame with at least one row".into()));
+        }
+        Ok(Self { inner: df })
+    }
+    
+    /// Get the number of rows, which is never zero.
+    pub fn height(&self) -> NonZeroUsize {
+        NonZeroUsize::new(self.inner.height()).expect("non-empty frames have rows")
+    }
+    
+    /// The first `n` rows, which are still non-empty.
+    pub fn head(&self, n: NonZeroUsize) -> Self {
+        Self { inner: self.inner.head(Some(n.get())) }
+    }
+    
+    /// The value of `column` in the first row.
+    pub fn first<T: ColumnType>(&self, column: Column<T>) -> PolarsResult<AnyValue<'static>> {
+        Ok(self.inner.inner().column(column.name())?.get(0)?.into_static())
+    }
+    
+    /// The value of `column` in the last row.
+    pub fn last<T: ColumnType>(&self, column: Column<T>) -> PolarsResult<AnyValue<'static>> {
+        let last = self.inner.height() - 1;
+        Ok(self.inner.inner().column(column.name())?.get(last)?.into_static())
+    }
+    
+    /// The smallest value of a numeric column, ignoring nulls.
+    ///
+    /// A column that holds only nulls is still an error.
+    pub fn min<T: ColumnType + NumCast>(&self, column: Column<T>) -> PolarsResult<T> {
+        let series = self.inner.inner().column(column.name())?.as_materialized_series();
+        series.min::<T>()?.ok_or_else(|| only_nulls(column.name()))
+    }
+    
+    /// The largest value of a numeric column, ignoring nulls.
+    ///
+    /// A column that holds only nulls is still an error.
+    pub fn max<T: ColumnType + NumCast>(&self, column: Column<T>) -> PolarsResult<T> {
+        let series = self.inner.inner().column(column.name())?.as_materialized_series();
+        series.max::<T>()?.ok_or_else(|| only_nulls(column.name()))
+    }
+}
+
+fn only_nulls(column: &str) -> PolarsError {
+    PolarsError::ComputeError(format!("Column '{}' only holds nulls", column).into())
+}
+
+impl<F> Deref for NonEmpty<F> {
+    type Target = F;
+    
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl<F: Clone> Clone for NonEmpty<F> {
+    fn clone(&self) -> Self {
+        Self { inner: self.inner.clone() }
+    }
+}
+
+impl<F: std::fmt::Debug> std::fmt::Debug for NonEmpty<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<F: std::fmt::Display> std::fmt::Display for NonEmpty<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
@@ -0,0 +1,88 @@
+//! Query plan descriptions of typed lazy frames, annotated with their schemas.
+
+use polars::prelude::*;
+use crate::schema::{schema_name, Schema};
+use crate::lazy::TypedLazyFrame;
+
+impl<S: Schema> TypedLazyFrame<S> {
+    /// The query plan as Polars will run it, after optimization, headed by the
+    /// typed schema of its output.
+    ///
+    /// Fails if the plan does not produce the schema `S`, so a description is
+    /// only ever shown for a pipeline that would collect.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// println!("{}", adults.describe_optimized_plan()?);
+    /// // OUTPUT UserSchema {name: str, age: i32}
+    /// //   FILTER [(col("age")) >= (18)] FROM
+    /// //     DF ["name", "age"]; PROJECT */2 COLUMNS
+    /// ```
+    pub fn describe_optimized_plan(&self) -> PolarsResult<String> {
+        let plan = self.inner().describe_optimized_plan()?;
+        self.describe(&plan)
+    }
+    
+    /// The query plan as written, before optimization, headed by the typed
+    /// schema of its output.
+    pub fn describe_plan(&self) -> PolarsResult<String> {
+        let plan = self.inner().describe_plan()?;
+        self.describe(&plan)
+    }
+    
+    /// The optimized query plan as a Graphviz DOT graph.
+    ///
+    /// Each plan node is a box, with edges in the direction the data flows;
+    /// the final edge leads to an output node labelled with the typed schema.
+    /// Render it with e.g. `dot -Tsvg plan.dot > plan.svg`.
+    pub fn to_dot(&self) -> PolarsResult<String> {
+        let output = self.validated_output()?;
+        let plan = self.inner().describe_optimized_plan()?;
+        
+        let mut dot = String::from("digraph plan {\n    rankdir=BT;\n    node [shape=box];\n");
+        dot.push_str(&format!("    output [label=\"{}\", style=bold];\n", escape(&output)));
+        // Each line is a node; its parent is the closest line above it that is indented less
+        let mut parents: Vec<(usize, usize)> = Vec::new();
+        for (id, line) in plan.lines().filter(|line| !line.trim().is_empty()).enumerate() {
+            let indent = line.len() - line.trim_start().len();
+            while parents.last().is_some_and(|(parent_indent, _)| *parent_indent >= indent) {
+                parents.pop();
+            }
+            dot.push_str(&format!("    n{} [label=\"{}\"];\n", id, escape(line.trim())));
+            match parents.last() {
+                Some((_, parent)) => dot.push_str(&format!("    n{} -> n{};\n", id, parent)),
+                None => dot.push_str(&format!("    n{} -> output;\n", id)),
+            }
+            parents.push((indent, id));
+        }
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+    
+    fn describe(&self, plan: &str) -> PolarsResult<String> {
+        let mut description = format!("OUTPUT {}\n", self.validated_output()?);
+        for line in plan.lines().filter(|line| !line.trim().is_empty()) {
+            description.push_str("  ");
+            description.push_str(line);
+            description.push('\n');
+        }
+        Ok(description)
+    }
+    
+    /// The schema name and columns, after checking the plan produces them.
+    fn validated_output(&self) -> PolarsResult<String> {
+        let schema = self.inner().clone().collect_schema()?;
+        S::validate(&DataFrame::empty_with_schema(&schema))?;
+        let columns: Vec<String> = S::schema()
+            .iter()
+            .map(|(column, dtype)| format!("{}: {}", column, dtype))
+            .collect();
+        Ok(format!("{} {{{}}}", schema_name::<S>(), columns.join(", ")))
+    }
+}
+
+/// Escape a label for a double-quoted DOT string.
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
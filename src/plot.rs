@@ -0,0 +1,126 @@
+//! Quick exploratory plots of typed columns, rendered to SVG with plotters.
+//!
+//! Requires the `plot` feature.
+
+use plotters::prelude::*;
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::ops::Range;
+
+/// Width and height of every rendered plot, in pixels.
+const PLOT_SIZE: (u32, u32) = (800, 600);
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Plot one column against another as points, returning the SVG document.
+    ///
+    /// Both columns must be numeric or temporal (temporal values are plotted
+    /// by their physical value); rows where either is null are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// std::fs::write("height_weight.svg", people.plot_scatter(People::height, People::weight)?)?;
+    /// ```
+    pub fn plot_scatter<X: ColumnType, Y: ColumnType>(&self, x: Column<X>, y: Column<Y>) -> PolarsResult<String> {
+        let points = self.points(x.name(), y.name())?;
+        render(x.name(), y.name(), value_range(points.iter().map(|p| p.0)), value_range(points.iter().map(|p| p.1)), |chart| {
+            chart.draw_series(points.iter().map(|&point| Circle::new(point, 2, BLUE.filled())))?;
+            Ok(())
+        })
+    }
+    
+    /// Plot one column against another as a line, ordered by `x`, returning the SVG document.
+    pub fn plot_line<X: ColumnType, Y: ColumnType>(&self, x: Column<X>, y: Column<Y>) -> PolarsResult<String> {
+        let mut points = self.points(x.name(), y.name())?;
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        render(x.name(), y.name(), value_range(points.iter().map(|p| p.0)), value_range(points.iter().map(|p| p.1)), |chart| {
+            chart.draw_series(LineSeries::new(points.iter().copied(), &BLUE))?;
+            Ok(())
+        })
+    }
+    
+    /// Plot the distribution of a column as a histogram with `bins` equal-width bins, returning the SVG document.
+    pub fn plot_hist<T: ColumnType>(&self, column: Column<T>, bins: usize) -> PolarsResult<String> {
+        if bins == 0 {
+            return Err(PolarsError::InvalidOperation("A histogram needs at least one bin".into()));
+        }
+        let values: Vec<f64> = plot_values(self.inner(), column.name())?.into_iter().flatten().collect();
+        let range = value_range(values.iter().copied());
+        let width = (range.end - range.start) / bins as f64;
+        let mut counts = vec![0usize; bins];
+        for value in values {
+            let bin = (((value - range.start) / width) as usize).min(bins - 1);
+            counts[bin] += 1;
+        }
+        
+        let max_count = counts.iter().copied().max().unwrap_or(0).max(1) as f64;
+        render(column.name(), "count", range.clone(), 0.0..max_count * 1.05, |chart| {
+            chart.draw_series(counts.iter().enumerate().map(|(bin, &count)| {
+                let left = range.start + bin as f64 * width;
+                Rectangle::new([(left, 0.0), (left + width, count as f64)], BLUE.mix(0.6).filled())
+            }))?;
+            Ok(())
+        })
+    }
+    
+    fn points(&self, x: &str, y: &str) -> PolarsResult<Vec<(f64, f64)>> {
+        let xs = plot_values(self.inner(), x)?;
+        let ys = plot_values(self.inner(), y)?;
+        Ok(xs.into_iter().zip(ys).filter_map(|(x, y)| Some((x?, y?))).collect())
+    }
+}
+
+/// A column's values as `f64`, keeping nulls.
+fn plot_values(df: &DataFrame, name: &str) -> PolarsResult<Vec<Option<f64>>> {
+    let column = df.column(name)?;
+    if !column.dtype().is_numeric() && !column.dtype().is_temporal() {
+        return Err(PolarsError::SchemaMismatch(
+            format!("Column '{}' has type {:?}, which cannot be plotted", name, column.dtype()).into()
+        ));
+    }
+    let values = column.as_materialized_series().to_physical_repr().cast(&DataType::Float64)?;
+    Ok(values.f64()?.into_iter().collect())
+}
+
+/// The span of the values, widened when empty or constant so the axis is never degenerate.
+fn value_range(values: impl Iterator<Item = f64>) -> Range<f64> {
+    let (min, max) = values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| (min.min(v), max.max(v)));
+    if min > max {
+        0.0..1.0
+    } else if min == max {
+        min - 0.5..max + 0.5
+    } else {
+        min..max
+    }
+}
+
+type Chart<'a, 'b> = ChartContext<'a, SVGBackend<'b>, Cartesian2d<plotters::coord::types::RangedCoordf64, plotters::coord::types::RangedCoordf64>>;
+
+/// Render a chart with labelled axes into an SVG string, drawing its data with `draw`.
+fn render(
+    x_label: &str,
+    y_label: &str,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+    draw: impl FnOnce(&mut Chart<'_, '_>) -> Result<(), Box<dyn std::error::Error>>,
+) -> PolarsResult<String> {
+    let mut svg = String::new();
+    {
+        let root = SVGBackend::with_string(&mut svg, PLOT_SIZE).into_drawing_area();
+        let result = (|| -> Result<(), Box<dyn std::error::Error>> {
+            root.fill(&WHITE)?;
+            let mut chart = ChartBuilder::on(&root)
+                .margin(10)
+                .x_label_area_size(40)
+                .y_label_area_size(60)
+                .build_cartesian_2d(x_range, y_range)?;
+            chart.configure_mesh().x_desc(x_label).y_desc(y_label).draw()?;
+            draw(&mut chart)?;
+            root.present()?;
+            Ok(())
+        })();
+        result.map_err(|err| PolarsError::ComputeError(format!("Plotting error: {}", err).into()))?;
+    }
+    Ok(svg)
+}
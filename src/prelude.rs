@@ -3,16 +3,89 @@
 //! This module re-exports the most commonly used types and traits
 //! for working with typed Polars DataFrames.
 
-pub use crate::schema::{Schema, Column, ColumnType};
+pub use crate::schema::{Schema, Column, ColumnType, ColumnSet, Concat};
+pub use crate::error::{TypedPolarsError, TypedResult};
 pub use crate::series::TypedSeries;
-pub use crate::dataframe::TypedDataFrame;
+pub use crate::dataframe::{TypedDataFrame, TypedGroupBy};
+pub use crate::shared::ArcTypedDataFrame;
+pub use crate::sorted::Sorted;
+pub use crate::non_empty::NonEmpty;
+pub use crate::keyed::Keyed;
+pub use crate::diff::FrameDiff;
+pub use crate::profile::{DataQualityReport, ColumnProfile};
+pub use crate::anonymize::PiiStrategy;
+pub use crate::expectations::{expect, expect_row_count, expect_that, ExpectationSuite, ExpectationReport};
+pub use crate::stats::StatMatrix;
+pub use crate::scaling::{Scaler, ScalingMethod};
+pub use crate::quantile::TDigest;
+pub use crate::aggregate::{TypedAggregator, AggregateSummary};
+pub use crate::sampling::ReservoirSampler;
+pub use crate::lazy::TypedLazyFrame;
+pub use crate::config::Config;
+pub use crate::timing::NodeTiming;
+pub use crate::dictionary::{DataDictionary, ColumnEntry};
+pub use crate::cdc::{CdcSchema, CdcOp};
+pub use crate::crosstab::{Crosstab, CrosstabValue, Normalize};
+pub use crate::row::{Row, RowValue};
+pub use crate::join::{Joined, OuterJoined, JoinKind};
+#[cfg(feature = "derive")]
+pub use typed_polars_derive::Schema;
 pub use crate::expr::{TypedExpr, col};
-pub use crate::io::{CsvReader, CsvWriter, ParquetReader, ParquetWriter, TypedDataFrameIo};
-pub use crate::define_schema;
+pub use crate::io::{
+    CsvReader, CsvBatchedReader, CsvWriter, CsvCompression, FixedWidthReader,
+    TypedDataFrameIo, Coercion, ReadProgress, scan_csv,
+};
+#[cfg(feature = "parquet")]
+pub use crate::io::{ParquetReader, ParquetWriter, scan_parquet};
+#[cfg(feature = "ipc")]
+pub use crate::io::{IpcReader, IpcWriter, IpcStreamWriter};
+#[cfg(feature = "json")]
+pub use crate::io::{JsonReader, JsonWriter, NdJsonReader, NdJsonWriter};
+#[cfg(feature = "fs")]
+pub use crate::io::{DatasetWriter, DatasetFormat, read_csv_glob};
+#[cfg(all(feature = "fs", feature = "parquet"))]
+pub use crate::io::{ParallelParquetReader, ParquetDatasetAppender, read_parquet_glob};
+pub use crate::manifest::Manifest;
+pub use crate::mock::Mock;
+pub use crate::stream::{TypedBatchSource, ChannelBatchSource};
+pub use crate::codegen::infer_schema_code;
+pub use crate::{define_schema, assert_subschema, assert_schema_disjoint};
+#[cfg(feature = "sql")]
+pub use crate::sql::TypedSqlContext;
+#[cfg(feature = "excel")]
+pub use crate::excel::{ExcelReader, ExcelWriter};
+#[cfg(feature = "cloud")]
+pub use crate::cloud::{CloudConfig, RetryPolicy, MultipartUploadWriter};
+#[cfg(feature = "sqlite")]
+pub use crate::sqlite::{read_sqlite, write_sqlite};
+#[cfg(feature = "database")]
+pub use crate::database::read_sql;
+#[cfg(feature = "flight")]
+pub use crate::flight::{FlightReader, FlightBatches};
+#[cfg(feature = "delta")]
+pub use crate::delta::{read_delta, DeltaVersion};
+#[cfg(all(feature = "delta", feature = "cloud"))]
+pub use crate::delta::read_delta_with_config;
+#[cfg(feature = "avro")]
+pub use crate::avro::{SchemaRegistryClient, check_avro_schema};
+#[cfg(feature = "orc")]
+pub use crate::orc::OrcReader;
+#[cfg(feature = "diagnostics")]
+pub use crate::diagnostics::{SchemaReport, ColumnIssue};
+#[cfg(feature = "validator")]
+pub use crate::validator_support::RowViolation;
+#[cfg(feature = "async")]
+pub use crate::io_async::{read_csv_async, write_csv_async};
+#[cfg(all(feature = "async", feature = "parquet"))]
+pub use crate::io_async::{read_parquet_async, write_parquet_async};
 
 // Re-export commonly used Polars types
 pub use polars::prelude::{
     DataFrame, Series, PolarsResult, PolarsError,
     DataType, AnyValue, ChunkedArray, BooleanType,
-    IdxCa, LazyFrame, NamedFrom, IntoColumn,
+    IdxCa, LazyFrame, NamedFrom, IntoColumn, CsvEncoding, UniqueKeepStrategy,
 };
+#[cfg(feature = "parquet")]
+pub use polars::prelude::{ParquetCompression, ZstdLevel};
+#[cfg(feature = "asof_join")]
+pub use polars::prelude::AsofStrategy;
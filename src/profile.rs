@@ -0,0 +1,194 @@
+//! Data-quality profiles of typed frames.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Most frequent values kept per column.
+const TOP_VALUES: usize = 5;
+
+/// The data-quality profile of one column.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ColumnProfile {
+    /// The column name
+    pub column: String,
+    /// The column type
+    pub dtype: DataType,
+    /// The number of rows
+    pub rows: usize,
+    /// The number of nulls
+    pub null_count: usize,
+    /// The number of distinct values, counting null as one; `None` for types that cannot be compared
+    pub distinct_count: Option<usize>,
+    /// The smallest value, if the type is ordered and there is a non-null value
+    pub min: Option<AnyValue<'static>>,
+    /// The largest value, if the type is ordered and there is a non-null value
+    pub max: Option<AnyValue<'static>>,
+    /// The most frequent non-null values as text, with their counts, most frequent first
+    pub top_values: Vec<(String, usize)>,
+    /// The range declared with `#[range = MIN ..= MAX]`, if any
+    pub range: Option<(f64, f64)>,
+    /// The number of values outside the declared range; 0 without a range
+    pub out_of_range: usize,
+}
+
+impl ColumnProfile {
+    /// The fraction of rows that are null, between 0 and 1.
+    pub fn null_fraction(&self) -> f64 {
+        if self.rows == 0 {
+            0.0
+        } else {
+            self.null_count as f64 / self.rows as f64
+        }
+    }
+}
+
+/// A data-quality profile of every schema column of a frame.
+///
+/// Built by [`TypedDataFrame::profile`]. Pipelines can gate on the column
+/// profiles, and [`table`](Self::table) renders them for logs.
+///
+/// # Example
+///
+/// ```ignore
+/// let report = users.profile()?;
+/// let age = report.get(UserSchema::age).unwrap();
+/// if age.null_fraction() > 0.05 || age.out_of_range > 0 {
+///     return Err(format!("bad ages in today's load:\n{}", report.table()).into());
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DataQualityReport {
+    /// The number of rows profiled
+    pub rows: usize,
+    /// One profile per schema column, in schema order
+    pub columns: Vec<ColumnProfile>,
+}
+
+impl DataQualityReport {
+    /// The profile of a column.
+    pub fn get<T: ColumnType>(&self, column: Column<T>) -> Option<&ColumnProfile> {
+        self.columns.iter().find(|profile| profile.column == column.name())
+    }
+    
+    /// Whether any column has values outside its declared range.
+    pub fn has_out_of_range(&self) -> bool {
+        self.columns.iter().any(|profile| profile.out_of_range > 0)
+    }
+    
+    /// The report as a table with one line per column.
+    pub fn table(&self) -> String {
+        let format_value = |value: &Option<AnyValue<'static>>| match value {
+            Some(AnyValue::String(value)) => value.to_string(),
+            Some(AnyValue::StringOwned(value)) => value.to_string(),
+            Some(value) => value.to_string(),
+            None => String::new(),
+        };
+        let rows: Vec<[String; 8]> = self
+            .columns
+            .iter()
+            .map(|profile| [
+                profile.column.clone(),
+                format!("{:?}", profile.dtype),
+                format!("{:.1}%", profile.null_fraction() * 100.0),
+                profile.distinct_count.map(|count| count.to_string()).unwrap_or_default(),
+                format_value(&profile.min),
+                format_value(&profile.max),
+                match profile.range {
+                    Some(_) => profile.out_of_range.to_string(),
+                    None => String::new(),
+                },
+                profile
+                    .top_values
+                    .iter()
+                    .map(|(value, count)| format!("{} ({})", value, count))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ])
+            .collect();
+        
+        let header = ["column", "type", "null", "distinct", "min", "max", "out of range", "top values"].map(String::from);
+        let widths: Vec<usize> = (0..header.len())
+            .map(|i| rows.iter().chain([&header]).map(|row| row[i].len()).max().unwrap_or(0))
+            .collect();
+        std::iter::once(&header)
+            .chain(&rows)
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(cell, width)| format!("{:width$}", cell, width = *width))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for DataQualityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Data-quality report: {} rows", self.rows)?;
+        write!(f, "{}", self.table())
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Profile every schema column: nulls, distinct values, extremes, most
+    /// frequent values and values outside the ranges declared with
+    /// `#[range = MIN ..= MAX]`.
+    pub fn profile(&self) -> PolarsResult<DataQualityReport> {
+        let ranges = S::column_ranges();
+        let columns = S::column_names()
+            .into_iter()
+            .map(|name| {
+                let series = self.inner().column(name)?.as_materialized_series();
+                let extreme = |scalar: PolarsResult<Scalar>| {
+                    scalar.ok().map(|scalar| scalar.value().clone().into_static()).filter(|value| !value.is_null())
+                };
+                
+                let mut counts: HashMap<String, usize> = HashMap::new();
+                if let Ok(text) = series.cast(&DataType::String) {
+                    for value in text.str()?.into_iter().flatten() {
+                        *counts.entry(value.to_string()).or_default() += 1;
+                    }
+                }
+                let mut top_values: Vec<(String, usize)> = counts.into_iter().collect();
+                top_values.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+                top_values.truncate(TOP_VALUES);
+                
+                let range = ranges.iter().find(|(column, _, _)| *column == name).map(|(_, min, max)| (*min, *max));
+                let out_of_range = match range {
+                    Some((min, max)) => series
+                        .cast(&DataType::Float64)?
+                        .f64()?
+                        .into_iter()
+                        .flatten()
+                        .filter(|value| !(min..=max).contains(value))
+                        .count(),
+                    None => 0,
+                };
+                
+                Ok(ColumnProfile {
+                    column: name.to_string(),
+                    dtype: series.dtype().clone(),
+                    rows: series.len(),
+                    null_count: series.null_count(),
+                    distinct_count: series.n_unique().ok(),
+                    min: extreme(series.min_reduce()),
+                    max: extreme(series.max_reduce()),
+                    top_values,
+                    range,
+                    out_of_range,
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(DataQualityReport {
+            rows: self.height(),
+            columns,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+//! Conversion between typed frames and Python `polars.DataFrame` objects through PyO3.
+//!
+//! Requires the `python` feature.
+//!
+//! pyo3-polars 0.18, the release built against Polars 0.44, needs pyo3 0.21,
+//! which predates `IntoPyObject`; frames convert to Python through `IntoPy`
+//! instead.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3_polars::PyDataFrame;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+
+/// Hands the frame to Python as a `polars.DataFrame`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[pyfunction]
+/// fn load_orders(path: &str) -> PyResult<TypedDataFrame<OrderSchema>> {
+///     ParquetReader::new(path).finish().map_err(|err| PyIOError::new_err(err.to_string()))
+/// }
+/// ```
+impl<S: Schema> IntoPy<PyObject> for TypedDataFrame<S> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        PyDataFrame(self.into_inner()).into_py(py)
+    }
+}
+
+/// Accepts a `polars.DataFrame` from Python, raising `ValueError` if it does
+/// not match the schema.
+impl<'py, S: Schema> FromPyObject<'py> for TypedDataFrame<S> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let PyDataFrame(df) = ob.extract()?;
+        TypedDataFrame::new(df).map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+}
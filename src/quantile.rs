@@ -0,0 +1,241 @@
+//! Approximate quantiles with mergeable t-digests.
+
+use polars::prelude::*;
+use crate::schema::ColumnType;
+use crate::series::TypedSeries;
+use crate::expr::TypedExpr;
+use num_traits::NumCast;
+use std::f64::consts::PI;
+
+/// The compression used by [`TypedSeries::approx_quantile`] and [`TypedExpr::approx_quantile`].
+pub const DEFAULT_COMPRESSION: f64 = 100.0;
+
+/// A t-digest: a compact, mergeable summary of a distribution for estimating quantiles.
+///
+/// Values are clustered into at most about `compression` centroids, kept
+/// small near the tails so extreme quantiles stay accurate. Digests of
+/// separate batches [`merge`](Self::merge) into a digest of all of them, so
+/// quantiles of unbounded streams need constant memory and no sort.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut digest = TDigest::new(DEFAULT_COMPRESSION);
+/// for batch in batches {
+///     digest.extend(batch.column(LatencySchema::millis)?.inner().f64()?.into_iter().flatten());
+/// }
+/// println!("p99: {:?}", digest.quantile(0.99));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    buffer: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+impl TDigest {
+    /// An empty digest; higher compression is more accurate and larger.
+    pub fn new(compression: f64) -> Self {
+        Self {
+            compression: compression.max(1.0),
+            centroids: Vec::new(),
+            buffer: Vec::new(),
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+    
+    /// Add a value; `NaN` is ignored.
+    pub fn add(&mut self, value: f64) {
+        self.push(Centroid { mean: value, weight: 1.0 });
+    }
+    
+    /// Add every value of an iterator; `NaN`s are ignored.
+    pub fn extend(&mut self, values: impl IntoIterator<Item = f64>) {
+        for value in values {
+            self.add(value);
+        }
+    }
+    
+    /// Fold another digest into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        for centroid in other.centroids.iter().chain(&other.buffer) {
+            self.push(*centroid);
+        }
+        // Compressed centroids lie inside the range of the values they hold
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+    
+    /// The number of values added.
+    pub fn count(&self) -> u64 {
+        self.count as u64
+    }
+    
+    /// The estimated `q` quantile, with `q` clamped to `[0, 1]`; `None` if no values were added.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.count == 0.0 {
+            return None;
+        }
+        let mut digest = self.clone();
+        digest.compress();
+        let centroids = &digest.centroids;
+        
+        let target = q.clamp(0.0, 1.0) * digest.count;
+        let first = centroids[0];
+        if target <= first.weight / 2.0 {
+            return Some(interpolate(digest.min, first.mean, target / (first.weight / 2.0)));
+        }
+        let mut cumulative = 0.0;
+        for pair in centroids.windows(2) {
+            let (left, right) = (pair[0], pair[1]);
+            let left_center = cumulative + left.weight / 2.0;
+            let right_center = cumulative + left.weight + right.weight / 2.0;
+            if target <= right_center {
+                return Some(interpolate(left.mean, right.mean, (target - left_center) / (right_center - left_center)));
+            }
+            cumulative += left.weight;
+        }
+        let last = centroids[centroids.len() - 1];
+        let last_center = digest.count - last.weight / 2.0;
+        Some(interpolate(last.mean, digest.max, (target - last_center) / (last.weight / 2.0)))
+    }
+    
+    fn push(&mut self, centroid: Centroid) {
+        if centroid.mean.is_nan() {
+            return;
+        }
+        self.count += centroid.weight;
+        self.min = self.min.min(centroid.mean);
+        self.max = self.max.max(centroid.mean);
+        self.buffer.push(centroid);
+        if self.buffer.len() as f64 >= 5.0 * self.compression {
+            self.compress();
+        }
+    }
+    
+    /// Merge the buffered centroids into the digest, keeping each centroid
+    /// within one unit of the arcsine scale function.
+    fn compress(&mut self) {
+        if self.buffer.is_empty() {
+            return;
+        }
+        let mut all = std::mem::take(&mut self.centroids);
+        all.append(&mut self.buffer);
+        all.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        
+        let mut merged = Vec::with_capacity(all.len().min(self.compression as usize * 2));
+        let mut current = all[0];
+        let mut before = 0.0;
+        let mut limit = self.count * self.quantile_limit(0.0);
+        for centroid in &all[1..] {
+            if before + current.weight + centroid.weight <= limit {
+                current.mean += (centroid.mean - current.mean) * centroid.weight / (current.weight + centroid.weight);
+                current.weight += centroid.weight;
+            } else {
+                before += current.weight;
+                merged.push(current);
+                limit = self.count * self.quantile_limit(before / self.count);
+                current = *centroid;
+            }
+        }
+        merged.push(current);
+        self.centroids = merged;
+    }
+    
+    /// The largest quantile a centroid starting at quantile `q` may reach.
+    fn quantile_limit(&self, q: f64) -> f64 {
+        let scale = self.compression / (2.0 * PI);
+        let k = scale * (2.0 * q - 1.0).asin() + 1.0;
+        if k >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k / scale).sin() + 1.0) / 2.0
+        }
+    }
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+fn interpolate(from: f64, to: f64, fraction: f64) -> f64 {
+    from + (to - from) * fraction.clamp(0.0, 1.0)
+}
+
+fn check_quantile(q: f64) -> PolarsResult<()> {
+    if !(0.0..=1.0).contains(&q) {
+        return Err(PolarsError::ComputeError(
+            format!("Quantile must be between 0 and 1, got {}", q).into()
+        ));
+    }
+    Ok(())
+}
+
+fn digest_series(series: &Series) -> PolarsResult<TDigest> {
+    let mut digest = TDigest::default();
+    digest.extend(series.cast(&DataType::Float64)?.f64()?.into_iter().flatten());
+    Ok(digest)
+}
+
+impl<T: ColumnType + NumCast> TypedSeries<T> {
+    /// Estimate the `q` quantile with a t-digest, in one pass and without sorting.
+    ///
+    /// `None` if the series has no non-null values.
+    pub fn approx_quantile(&self, q: f64) -> PolarsResult<Option<f64>> {
+        check_quantile(q)?;
+        Ok(digest_series(self.inner())?.quantile(q))
+    }
+    
+    /// Estimate the median with a t-digest.
+    pub fn approx_median(&self) -> PolarsResult<Option<f64>> {
+        self.approx_quantile(0.5)
+    }
+    
+    /// A t-digest of the series, to merge with digests of other series.
+    pub fn digest(&self) -> PolarsResult<TDigest> {
+        digest_series(self.inner())
+    }
+}
+
+impl<T: ColumnType + NumCast> TypedExpr<T> {
+    /// Aggregate to the t-digest estimate of the `q` quantile.
+    ///
+    /// Works in `select` and in `group_by` aggregations, one estimate per
+    /// group. Fails if `q` is not between 0 and 1.
+    pub fn approx_quantile(self, q: f64) -> PolarsResult<TypedExpr<f64>> {
+        check_quantile(q)?;
+        Ok(self.digest_quantile(q))
+    }
+    
+    /// Aggregate to the t-digest estimate of the median.
+    pub fn approx_median(self) -> TypedExpr<f64> {
+        self.digest_quantile(0.5)
+    }
+    
+    fn digest_quantile(self, q: f64) -> TypedExpr<f64> {
+        TypedExpr::new(
+            self.into_inner()
+                .apply(
+                    move |column| {
+                        let estimate = digest_series(column.as_materialized_series())?.quantile(q);
+                        Ok(Some(Series::new(column.name().clone(), [estimate]).into_column()))
+                    },
+                    GetOutput::from_type(DataType::Float64),
+                )
+                .first(),
+        )
+    }
+}
@@ -0,0 +1,114 @@
+//! Range joins: matching rows to the intervals of another frame that contain them.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::{hstack_suffixed, TypedDataFrame};
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Join every row to each row of `right` whose interval
+    /// `[right_lower, right_upper]` contains its `left_key`, into the schema `Out`.
+    ///
+    /// Bounds are inclusive, rows appear once per containing interval (inner
+    /// join semantics) in the order of this frame, and null keys or bounds never
+    /// match. Columns of `right` whose names this frame already has get a
+    /// `_right` suffix, as in Polars joins. The three columns share one type,
+    /// so mismatched bounds do not compile; it must be a number or a temporal
+    /// type, and the frames must hold it.
+    ///
+    /// Rather than a cross join and a filter, both sides are sorted and swept
+    /// once, so the cost grows with the sizes of the inputs and the result.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Price every sale with the tariff valid at its time
+    /// let priced: TypedDataFrame<PricedSale> = sales.join_between(
+    ///     &tariffs,
+    ///     SaleSchema::sold_at,
+    ///     TariffSchema::valid_from,
+    ///     TariffSchema::valid_to,
+    /// )?;
+    /// ```
+    pub fn join_between<R: Schema, T: ColumnType, Out: Schema>(
+        &self,
+        right: &TypedDataFrame<R>,
+        left_key: Column<T>,
+        right_lower: Column<T>,
+        right_upper: Column<T>,
+    ) -> PolarsResult<TypedDataFrame<Out>> {
+        let keys = self.inner().column(left_key.name())?.as_materialized_series();
+        let lower = right.inner().column(right_lower.name())?.as_materialized_series();
+        let upper = right.inner().column(right_upper.name())?.as_materialized_series();
+        let dtype = keys.dtype();
+        if !(dtype.is_numeric() || dtype.is_temporal()) {
+            return Err(PolarsError::InvalidOperation(
+                format!("Range joins do not support keys of type {}", dtype).into()
+            ));
+        }
+        if let Some(bound) = [lower, upper].into_iter().find(|bound| bound.dtype() != dtype) {
+            return Err(PolarsError::SchemaMismatch(
+                format!(
+                    "Bound '{}' has type {}, but key '{}' has type {}",
+                    bound.name(), bound.dtype(), left_key.name(), dtype,
+                ).into()
+            ));
+        }
+        let pairs = if dtype.is_float() {
+            let values = |series: &Series| -> PolarsResult<Vec<Option<f64>>> {
+                Ok(series.strict_cast(&DataType::Float64)?.f64()?.into_iter().collect())
+            };
+            contained_pairs(&values(keys)?, &values(lower)?, &values(upper)?)
+        } else {
+            let values = |series: &Series| -> PolarsResult<Vec<Option<i64>>> {
+                Ok(series.to_physical_repr().strict_cast(&DataType::Int64)?.i64()?.into_iter().collect())
+            };
+            contained_pairs(&values(keys)?, &values(lower)?, &values(upper)?)
+        };
+        
+        let (left_rows, right_rows): (Vec<IdxSize>, Vec<IdxSize>) = pairs.into_iter().unzip();
+        let joined = self.inner().take(&IdxCa::from_vec("".into(), left_rows))?;
+        let matched = right.inner().take(&IdxCa::from_vec("".into(), right_rows))?;
+        TypedDataFrame::new(hstack_suffixed(joined, matched)?)
+    }
+}
+
+/// The `(left, right)` row pairs whose key lies within the right row's bounds,
+/// ordered by left row and then right row.
+fn contained_pairs<V: PartialOrd + Copy>(
+    keys: &[Option<V>],
+    lower: &[Option<V>],
+    upper: &[Option<V>],
+) -> Vec<(IdxSize, IdxSize)> {
+    // NaN is incomparable, so it matches nothing, like null
+    let comparable = |value: &Option<V>| value.filter(|value| value.partial_cmp(value).is_some());
+    let order = |a: &V, b: &V| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal);
+    let mut left: Vec<(V, IdxSize)> = keys
+        .iter()
+        .enumerate()
+        .filter_map(|(row, key)| Some((comparable(key)?, row as IdxSize)))
+        .collect();
+    left.sort_by(|(a, _), (b, _)| order(a, b));
+    let mut intervals: Vec<(V, V, IdxSize)> = lower
+        .iter()
+        .zip(upper)
+        .enumerate()
+        .filter_map(|(row, (lower, upper))| Some((comparable(lower)?, comparable(upper)?, row as IdxSize)))
+        .collect();
+    intervals.sort_by(|(a, _, _), (b, _, _)| order(a, b));
+    
+    // Sweep the keys in ascending order, keeping the intervals that start at or
+    // before the current key and have not ended before it
+    let mut pairs = Vec::new();
+    let mut active: Vec<(V, IdxSize)> = Vec::new();
+    let mut next = 0;
+    for (key, left_row) in left {
+        while next < intervals.len() && intervals[next].0 <= key {
+            active.push((intervals[next].1, intervals[next].2));
+            next += 1;
+        }
+        active.retain(|(upper, _)| *upper >= key);
+        pairs.extend(active.iter().map(|(_, right_row)| (left_row, *right_row)));
+    }
+    pairs.sort_unstable();
+    pairs
+}
@@ -0,0 +1,166 @@
+//! Row structs: building typed frames from Rust values and reading them back.
+
+use polars::prelude::*;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use crate::schema::{ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+
+/// A column type whose values can be held in a field of a row struct.
+///
+/// Implemented for the numeric types, `bool`, `String`, the chrono date and
+/// time types, and `Option`s of them for nullable columns; marker types such
+/// as `Categorical` have no Rust value.
+pub trait RowValue: ColumnType + Sized {
+    /// A series of the values, with `None` as null
+    #[doc(hidden)]
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series;
+    
+    /// The values of a series of this column type, with null as `None`
+    #[doc(hidden)]
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>>;
+}
+
+macro_rules! impl_row_value {
+    ($($ty:ty => $accessor:ident),* $(,)?) => {
+        $(
+            impl RowValue for $ty {
+                fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+                    Series::new(name.into(), values)
+                }
+                
+                fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+                    Ok(series.$accessor()?.into_iter().collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_row_value! {
+    i8 => i8,
+    i16 => i16,
+    i32 => i32,
+    i64 => i64,
+    u8 => u8,
+    u16 => u16,
+    u32 => u32,
+    u64 => u64,
+    f32 => f32,
+    f64 => f64,
+    bool => bool,
+}
+
+impl RowValue for String {
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+        Series::new(name.into(), values)
+    }
+    
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+        Ok(series.str()?.into_iter().map(|value| value.map(String::from)).collect())
+    }
+}
+
+impl<T: RowValue> RowValue for Option<T> {
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+        T::from_options(name, values.into_iter().map(Option::flatten).collect())
+    }
+    
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+        Ok(T::to_options(series)?.into_iter().map(Some).collect())
+    }
+}
+
+impl RowValue for NaiveDate {
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+        DateChunked::from_naive_date_options(name.into(), values).into_series()
+    }
+    
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+        Ok(series.date()?.as_date_iter().collect())
+    }
+}
+
+impl RowValue for NaiveDateTime {
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+        DatetimeChunked::from_naive_datetime_options(name.into(), values, TimeUnit::Microseconds).into_series()
+    }
+    
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+        // Any time unit reads back; the cast is a no-op for the schema's own
+        let series = series.cast(&Self::data_type())?;
+        Ok(series.datetime()?.as_datetime_iter().collect())
+    }
+}
+
+impl RowValue for NaiveTime {
+    fn from_options(name: &str, values: Vec<Option<Self>>) -> Series {
+        TimeChunked::from_naive_time_options(name.into(), values).into_series()
+    }
+    
+    fn to_options(series: &Series) -> PolarsResult<Vec<Option<Self>>> {
+        Ok(series.time()?.as_time_iter().collect())
+    }
+}
+
+/// A schema that is its own row type: a struct with one field per column,
+/// as generated by `#[derive(Schema)]`.
+///
+/// # Example
+///
+/// ```ignore
+/// #[derive(Schema, Debug)]
+/// struct Order {
+///     id: i64,
+///     customer: String,
+///     amount: f64,
+/// }
+///
+/// let orders = TypedDataFrame::from_rows(vec![
+///     Order { id: 1, customer: "acme".to_string(), amount: 9.5 },
+/// ])?;
+/// for order in orders.to_rows()? {
+///     println!("{:?}", order);
+/// }
+/// ```
+pub trait Row: Schema + Sized {
+    /// A frame with one row per struct, in order.
+    fn into_frame(rows: Vec<Self>) -> PolarsResult<DataFrame>;
+    
+    /// One struct per row of a frame of this schema.
+    fn from_frame(df: &DataFrame) -> PolarsResult<Vec<Self>>;
+}
+
+impl<S: Row> TypedDataFrame<S> {
+    /// Build a frame from row structs.
+    pub fn from_rows(rows: Vec<S>) -> PolarsResult<Self> {
+        TypedDataFrame::new(S::into_frame(rows)?)
+    }
+    
+    /// Read every row into its row struct.
+    ///
+    /// Fails on nulls in fields not declared as `Option<T>`.
+    pub fn to_rows(&self) -> PolarsResult<Vec<S>> {
+        S::from_frame(self.inner())
+    }
+}
+
+/// The column `name` of a frame as values; used by `#[derive(Schema)]`.
+#[doc(hidden)]
+pub fn read_column<T: RowValue>(df: &DataFrame, name: &str) -> PolarsResult<Vec<T>> {
+    let series = df.column(name)?.as_materialized_series();
+    T::to_options(series)?
+        .into_iter()
+        .enumerate()
+        .map(|(row, value)| {
+            value.ok_or_else(|| PolarsError::ComputeError(
+                format!("Column '{}' is null in row {}", name, row).into()
+            ))
+        })
+        .collect()
+}
+
+/// A column of values; used by `#[derive(Schema)]`.
+#[doc(hidden)]
+pub fn write_column<T: RowValue>(name: &str, values: Vec<T>) -> polars::prelude::Column {
+    T::from_options(name, values.into_iter().map(Some).collect()).into_column()
+}
@@ -0,0 +1,137 @@
+//! Reservoir sampling of streamed typed batches.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::stream::TypedBatchSource;
+use crate::rng::SplitMix64;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::marker::PhantomData;
+
+/// Keeps a uniform random sample of fixed size from a stream of typed batches.
+///
+/// After any number of rows, every row seen so far is in the sample with the
+/// same probability, while only `capacity` rows are held (reservoir sampling,
+/// Algorithm R). Samples are random per sampler unless [`seed`](Self::seed)
+/// makes them reproducible.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut sampler = ReservoirSampler::<EventSchema>::new(10_000).seed(42);
+/// sampler.update_from(&mut source)?;
+/// println!("{}", sampler.sample()?.profile()?);
+/// ```
+pub struct ReservoirSampler<S: Schema> {
+    capacity: usize,
+    seen: u64,
+    sample: Option<DataFrame>,
+    rng: SplitMix64,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> ReservoirSampler<S> {
+    /// An empty sampler keeping up to `capacity` rows.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            seen: 0,
+            sample: None,
+            rng: SplitMix64(RandomState::new().build_hasher().finish()),
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// Seed the random choices, for reproducible samples.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.rng = SplitMix64(seed);
+        self
+    }
+    
+    /// The number of rows seen so far.
+    pub fn seen(&self) -> u64 {
+        self.seen
+    }
+    
+    /// Offer every row of a batch to the sample.
+    pub fn update(&mut self, batch: &TypedDataFrame<S>) -> PolarsResult<()> {
+        let held = self.sample.as_ref().map_or(0, DataFrame::height);
+        let mut appended: Vec<IdxSize> = Vec::new();
+        let mut replaced: Vec<(usize, IdxSize)> = Vec::new();
+        for row in 0..batch.height() {
+            if held + appended.len() < self.capacity {
+                appended.push(row as IdxSize);
+            } else {
+                let slot = self.rng.next_below(self.seen as usize + 1);
+                if slot < self.capacity {
+                    replaced.push((slot, row as IdxSize));
+                }
+            }
+            self.seen += 1;
+        }
+        if appended.is_empty() && replaced.is_empty() {
+            return Ok(());
+        }
+        
+        let rows = batch.inner().select(S::column_names())?;
+        let mut sample = match self.sample.take() {
+            Some(mut sample) => {
+                sample.vstack_mut(&rows.take(&IdxCa::from_vec("".into(), appended))?)?;
+                sample
+            }
+            None => rows.take(&IdxCa::from_vec("".into(), appended))?,
+        };
+        if !replaced.is_empty() {
+            let height = sample.height() as IdxSize;
+            let mut indices: Vec<IdxSize> = (0..height).collect();
+            for (position, (slot, _)) in replaced.iter().enumerate() {
+                indices[*slot] = height + position as IdxSize;
+            }
+            let replacements: Vec<IdxSize> = replaced.into_iter().map(|(_, row)| row).collect();
+            sample.vstack_mut(&rows.take(&IdxCa::from_vec("".into(), replacements))?)?;
+            sample = sample.take(&IdxCa::from_vec("".into(), indices))?;
+        }
+        sample.as_single_chunk_par();
+        self.sample = Some(sample);
+        Ok(())
+    }
+    
+    /// Offer every remaining batch of a source to the sample.
+    pub fn update_from<B: TypedBatchSource<Schema = S>>(&mut self, source: &mut B) -> PolarsResult<()> {
+        while let Some(batch) = source.next_batch() {
+            self.update(&batch?)?;
+        }
+        Ok(())
+    }
+    
+    /// The current sample, in no particular order; empty before any row was seen.
+    pub fn sample(&self) -> PolarsResult<TypedDataFrame<S>> {
+        match &self.sample {
+            Some(sample) => TypedDataFrame::new_lenient(sample.clone()),
+            None => TypedDataFrame::new(DataFrame::empty_with_schema(&S::schema())),
+        }
+    }
+}
+
+impl<S: Schema> Clone for ReservoirSampler<S> {
+    fn clone(&self) -> Self {
+        Self {
+            capacity: self.capacity,
+            seen: self.seen,
+            sample: self.sample.clone(),
+            rng: self.rng.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for ReservoirSampler<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReservoirSampler")
+            .field("capacity", &self.capacity)
+            .field("seen", &self.seen)
+            .field("held", &self.sample.as_ref().map_or(0, DataFrame::height))
+            .finish()
+    }
+}
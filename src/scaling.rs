@@ -0,0 +1,200 @@
+//! Fit/transform feature scaling for typed frames.
+
+use polars::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use crate::schema::{ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::error::TypedPolarsError;
+use std::marker::PhantomData;
+
+/// How a [`Scaler`] centers and scales each column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScalingMethod {
+    /// Subtract the mean and divide by the (population) standard deviation
+    Standard,
+    /// Map the minimum to 0 and the maximum to 1
+    MinMax,
+    /// Subtract the median and divide by the interquartile range, ignoring outliers
+    Robust,
+}
+
+/// The fitted parameters of one column: values become `(value - center) / scale`.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ColumnScale {
+    /// The column name
+    pub column: String,
+    /// The value mapped to 0
+    pub center: f64,
+    /// The spread mapped to 1; 1 for constant columns, which are only centered
+    pub scale: f64,
+}
+
+/// Scales the floating-point columns of a schema with parameters fitted on one frame.
+///
+/// A scaler fitted on training data transforms any other frame of the same
+/// schema the same way, so training and serving preprocessing cannot drift
+/// apart. With the `serde` feature it serializes with its parameters and the
+/// schema fingerprint, and refuses to transform frames once the schema changes.
+///
+/// # Example
+///
+/// ```ignore
+/// let scaler = Scaler::<HouseSchema>::fit(ScalingMethod::Standard, &train)?;
+/// let train = scaler.transform(&train)?;
+/// std::fs::write("scaler.json", serde_json::to_string(&scaler)?)?;
+///
+/// // At serving time
+/// let scaler: Scaler<HouseSchema> = serde_json::from_str(&std::fs::read_to_string("scaler.json")?)?;
+/// let request = scaler.transform(&request)?;
+/// ```
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize), serde(bound = ""))]
+pub struct Scaler<S: Schema> {
+    method: ScalingMethod,
+    fingerprint: String,
+    columns: Vec<ColumnScale>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> Scaler<S> {
+    /// Fit on every floating-point column of the schema.
+    ///
+    /// Integer columns are left alone, since scaled values would not fit
+    /// their schema types.
+    pub fn fit(method: ScalingMethod, df: &TypedDataFrame<S>) -> PolarsResult<Self> {
+        let names: Vec<&str> = S::column_names()
+            .into_iter()
+            .zip(S::schema().iter_values())
+            .filter(|(_, dtype)| dtype.is_float())
+            .map(|(name, _)| name)
+            .collect();
+        Self::fit_names(method, df, &names)
+    }
+    
+    /// Fit on the given floating-point columns only.
+    pub fn fit_columns(method: ScalingMethod, df: &TypedDataFrame<S>, columns: impl ColumnSet) -> PolarsResult<Self> {
+        Self::fit_names(method, df, &columns.names())
+    }
+    
+    fn fit_names(method: ScalingMethod, df: &TypedDataFrame<S>, names: &[&str]) -> PolarsResult<Self> {
+        let columns = names
+            .iter()
+            .map(|name| {
+                let column = df.inner().column(name)?;
+                if !column.dtype().is_float() {
+                    return Err(PolarsError::SchemaMismatch(
+                        format!("Column '{}' has type {:?}; only floating-point columns can be scaled", name, column.dtype()).into()
+                    ));
+                }
+                let series = column.as_materialized_series().cast(&DataType::Float64)?;
+                let mut values: Vec<f64> = series.f64()?.into_iter().flatten().filter(|v| !v.is_nan()).collect();
+                if values.is_empty() {
+                    return Err(PolarsError::NoData(
+                        format!("Column '{}' has no values to fit a scaler on", name).into()
+                    ));
+                }
+                let (center, spread) = match method {
+                    ScalingMethod::Standard => {
+                        let n = values.len() as f64;
+                        let mean = values.iter().sum::<f64>() / n;
+                        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+                        (mean, variance.sqrt())
+                    }
+                    ScalingMethod::MinMax => {
+                        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+                        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+                        (min, max - min)
+                    }
+                    ScalingMethod::Robust => {
+                        values.sort_by(f64::total_cmp);
+                        (quantile(&values, 0.5), quantile(&values, 0.75) - quantile(&values, 0.25))
+                    }
+                };
+                Ok(ColumnScale {
+                    column: name.to_string(),
+                    center,
+                    scale: if spread > 0.0 { spread } else { 1.0 },
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        
+        Ok(Self {
+            method,
+            fingerprint: S::fingerprint(),
+            columns,
+            _phantom: PhantomData,
+        })
+    }
+    
+    /// The scaling method.
+    pub fn method(&self) -> ScalingMethod {
+        self.method
+    }
+    
+    /// The fitted parameters, one per scaled column.
+    pub fn columns(&self) -> &[ColumnScale] {
+        &self.columns
+    }
+    
+    /// Scale a frame with the fitted parameters.
+    pub fn transform(&self, df: &TypedDataFrame<S>) -> PolarsResult<TypedDataFrame<S>> {
+        self.apply(df, |value, scale| (value - lit(scale.center)) / lit(scale.scale))
+    }
+    
+    /// Undo [`transform`](Self::transform), e.g. on model predictions.
+    pub fn inverse_transform(&self, df: &TypedDataFrame<S>) -> PolarsResult<TypedDataFrame<S>> {
+        self.apply(df, |value, scale| value * lit(scale.scale) + lit(scale.center))
+    }
+    
+    fn apply(&self, df: &TypedDataFrame<S>, f: impl Fn(Expr, &ColumnScale) -> Expr) -> PolarsResult<TypedDataFrame<S>> {
+        let expected = S::fingerprint();
+        if self.fingerprint != expected {
+            return Err(TypedPolarsError::FingerprintMismatch {
+                expected,
+                actual: Some(self.fingerprint.clone()),
+                context: Some("Scaler".to_string()),
+            }.into());
+        }
+        let schema = S::schema();
+        let exprs: Vec<Expr> = self
+            .columns
+            .iter()
+            .map(|scale| {
+                let dtype = schema.get(&scale.column).cloned().unwrap_or(DataType::Float64);
+                f(col(scale.column.as_str()).cast(DataType::Float64), scale).cast(dtype)
+            })
+            .collect();
+        // Nulls pass through scaling, so a lenient frame stays valid
+        TypedDataFrame::new_lenient(df.inner().clone().lazy().with_columns(exprs).collect()?)
+    }
+}
+
+impl<S: Schema> Clone for Scaler<S> {
+    fn clone(&self) -> Self {
+        Self {
+            method: self.method,
+            fingerprint: self.fingerprint.clone(),
+            columns: self.columns.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for Scaler<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Scaler")
+            .field("method", &self.method)
+            .field("columns", &self.columns)
+            .finish()
+    }
+}
+
+/// The `q` quantile of sorted values, interpolating linearly between neighbours.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let (below, above) = (position.floor() as usize, position.ceil() as usize);
+    sorted[below] + (sorted[above] - sorted[below]) * (position - below as f64)
+}
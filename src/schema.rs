@@ -4,6 +4,10 @@
 //! typed schemas in Polars DataFrames.
 
 use polars::prelude::*;
+use crate::error::TypedPolarsError;
+use crate::series::TypedSeries;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use std::marker::PhantomData;
 
 /// Trait for types that represent a DataFrame schema.
@@ -11,6 +15,24 @@ use std::marker::PhantomData;
 /// This trait is typically implemented via a derive macro on a struct,
 /// where each field represents a column in the DataFrame.
 pub trait Schema: Sized {
+    /// The schema's columns as a tuple of [`Column`]s, in order
+    ///
+    /// Operations taking a [`ColumnSet`] use it to tie their output schema
+    /// to the selected columns.
+    ///
+    /// `define_schema!` and the derive fill this in. Hand-written impls must
+    /// now declare it too, as associated type defaults are not yet stable;
+    /// `type Columns = (Column<i64>, Column<String>);` for a schema with an
+    /// `i64` and a `String` column, or `type Columns = ();` to opt out of
+    /// the typed column operations.
+    type Columns;
+    
+    /// Column names and types as written, for compile-time checks in generic
+    /// code such as [`join`](crate::dataframe::TypedDataFrame::join); empty
+    /// unless generated by `define_schema!` or `#[derive(Schema)]`
+    #[doc(hidden)]
+    const COLUMN_TYPES: &'static [(&'static str, &'static str)] = &[];
+    
     /// Returns the schema as a Polars Schema
     fn schema() -> polars::prelude::Schema;
     
@@ -18,13 +40,230 @@ pub trait Schema: Sized {
     fn column_names() -> Vec<&'static str>;
     
     /// Validates that a DataFrame matches this schema
-    fn validate(df: &DataFrame) -> PolarsResult<()>;
+    fn validate(df: &DataFrame) -> PolarsResult<()> {
+        Self::check(df).map_err(PolarsError::from)
+    }
+    
+    /// Validates that a DataFrame matches this schema as a structured
+    /// [`TypedPolarsError`]; several failing columns are reported together
+    /// as [`TypedPolarsError::Multiple`]
+    fn check(df: &DataFrame) -> Result<(), TypedPolarsError> {
+        let mut errors = Self::check_columns(df);
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(TypedPolarsError::Multiple(errors)),
+        }
+    }
+    
+    /// Returns every column that does not match this schema, in schema order
+    ///
+    /// With the `parallel` feature, schemas with at least
+    /// [`PARALLEL_VALIDATION_COLUMNS`] columns are checked in parallel. A
+    /// column layout that passed once is not compared again on the same
+    /// thread; see [`validation`](crate::validation).
+    fn check_columns(df: &DataFrame) -> Vec<TypedPolarsError> {
+        crate::validation::column_errors::<Self>(df)
+    }
+    
+    /// Returns the parse format declared for each temporal column, e.g. `("date", "%d/%m/%Y")`
+    fn column_formats() -> Vec<(&'static str, &'static str)> {
+        Vec::new()
+    }
+    
+    /// Returns the inclusive value range declared for each numeric column, e.g. `("age", 0.0, 130.0)`
+    fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+        Vec::new()
+    }
+    
+    /// Returns the masking declared with `#[pii(...)]` for each personal-data column
+    fn column_pii() -> Vec<(&'static str, crate::anonymize::PiiStrategy)> {
+        Vec::new()
+    }
+    
+    /// Returns the columns that may hold nulls: those declared as `Option<T>`
+    fn nullable_columns() -> Vec<&'static str> {
+        Vec::new()
+    }
+    
+    /// Checks that no column outside [`nullable_columns`](Self::nullable_columns)
+    /// holds nulls, reporting each one as [`TypedPolarsError::UnexpectedNulls`]
+    ///
+    /// Not part of [`validate`](Self::validate), which only compares column
+    /// names and types; [`TypedDataFrame::new`](crate::dataframe::TypedDataFrame::new)
+    /// runs both, and [`new_lenient`](crate::dataframe::TypedDataFrame::new_lenient)
+    /// only `validate`. Missing columns are left to `validate`.
+    fn check_nulls(df: &DataFrame) -> Result<(), TypedPolarsError> {
+        let nullable = Self::nullable_columns();
+        let mut errors: Vec<TypedPolarsError> = Self::column_names()
+            .into_iter()
+            .filter(|name| !nullable.contains(name))
+            .filter_map(|name| {
+                let count = df.column(name).ok()?.null_count();
+                (count > 0).then(|| TypedPolarsError::UnexpectedNulls {
+                    column: name.to_string(),
+                    count,
+                    context: None,
+                })
+            })
+            .collect();
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(TypedPolarsError::Multiple(errors)),
+        }
+    }
+    
+    /// Returns the doc comment of the schema, or an empty string
+    fn description() -> String {
+        String::new()
+    }
+    
+    /// Returns the doc comment of each documented column, e.g. `("age", "Age in whole years")`
+    fn column_docs() -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+    
+    /// Returns the columns with their types, constraints and docs, for publishing
+    fn data_dictionary() -> crate::dictionary::DataDictionary {
+        crate::dictionary::DataDictionary::of::<Self>()
+    }
+    
+    /// Returns the schema version, declared with `#[version = N]` (default: 1)
+    fn version() -> u32 {
+        1
+    }
+    
+    /// Returns the version and columns serialized, e.g. `fp1;v2;id:i64;name:str`
+    ///
+    /// Result caches and checkpoints include it in their keys, so data written
+    /// under a different version of the schema is not reused. The leading `fp1` is the
+    /// version of the encoding itself, which does not depend on how Polars
+    /// prints its types.
+    fn fingerprint() -> String {
+        let mut fingerprint = format!("fp1;v{}", Self::version());
+        for (name, dtype) in Self::schema().iter() {
+            fingerprint.push_str(&format!(";{}:{}", name, dtype_code(dtype)));
+        }
+        fingerprint
+    }
+}
+
+/// The fingerprint encoding of a column type.
+fn dtype_code(dtype: &DataType) -> String {
+    let unit = |unit: &TimeUnit| match unit {
+        TimeUnit::Nanoseconds => "ns",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Milliseconds => "ms",
+    };
+    #[allow(unreachable_patterns)]
+    match dtype {
+        DataType::Boolean => "bool".into(),
+        DataType::UInt8 => "u8".into(),
+        DataType::UInt16 => "u16".into(),
+        DataType::UInt32 => "u32".into(),
+        DataType::UInt64 => "u64".into(),
+        DataType::Int8 => "i8".into(),
+        DataType::Int16 => "i16".into(),
+        DataType::Int32 => "i32".into(),
+        DataType::Int64 => "i64".into(),
+        DataType::Float32 => "f32".into(),
+        DataType::Float64 => "f64".into(),
+        DataType::String => "str".into(),
+        DataType::Binary => "binary".into(),
+        DataType::Date => "date".into(),
+        DataType::Datetime(time_unit, None) => format!("datetime[{}]", unit(time_unit)),
+        DataType::Datetime(time_unit, Some(zone)) => format!("datetime[{},{}]", unit(time_unit), zone),
+        DataType::Duration(time_unit) => format!("duration[{}]", unit(time_unit)),
+        DataType::Time => "time".into(),
+        DataType::List(inner) => format!("list[{}]", dtype_code(inner)),
+        DataType::Null => "null".into(),
+        #[cfg(feature = "dtype-full")]
+        DataType::Decimal(precision, scale) => format!(
+            "decimal[{},{}]",
+            precision.map_or("*".to_string(), |p| p.to_string()),
+            scale.map_or("*".to_string(), |s| s.to_string()),
+        ),
+        #[cfg(feature = "dtype-full")]
+        DataType::Array(inner, width) => format!("array[{},{}]", dtype_code(inner), width),
+        #[cfg(feature = "dtype-full")]
+        DataType::Struct(fields) => format!(
+            "struct[{}]",
+            fields.iter().map(|field| format!("{}:{}", field.name(), dtype_code(field.dtype()))).collect::<Vec<_>>().join(","),
+        ),
+        #[cfg(feature = "dtype-full")]
+        DataType::Categorical(_, _) => "cat".into(),
+        #[cfg(feature = "dtype-full")]
+        DataType::Enum(_, _) => "enum".into(),
+        #[cfg(feature = "dtype-full")]
+        DataType::Object(_, _) => "object".into(),
+        _ => "unknown".into(),
+    }
+}
+
+/// The columns of `df` missing from or mistyped against the schema `S`, in schema order.
+pub(crate) fn layout_errors<S: Schema>(df: &DataFrame) -> Vec<TypedPolarsError> {
+    let expected: Vec<_> = S::schema().iter().map(|(name, dtype)| (name.clone(), dtype.clone())).collect();
+    let actual_schema = df.schema();
+    let check = |(name, expected_dtype): &(PlSmallStr, DataType)| match actual_schema.get(name) {
+        Some(actual_dtype) if dtype_matches(expected_dtype, actual_dtype) => None,
+        Some(actual_dtype) => Some(TypedPolarsError::TypeMismatch {
+            column: name.to_string(),
+            expected: expected_dtype.clone(),
+            actual: actual_dtype.clone(),
+            context: None,
+        }),
+        None => Some(TypedPolarsError::ColumnNotFound {
+            column: name.to_string(),
+            context: None,
+        }),
+    };
+    #[cfg(feature = "parallel")]
+    if expected.len() >= PARALLEL_VALIDATION_COLUMNS {
+        let par = || expected.par_iter().filter_map(check).collect();
+        // Stay on the current pool inside Config::run, so its bound applies
+        if rayon::current_thread_index().is_some() {
+            return par();
+        }
+        return polars_core::POOL.install(par);
+    }
+    expected.iter().filter_map(check).collect()
+}
+
+/// Schema width from which columns are validated in parallel, with the `parallel` feature.
+pub const PARALLEL_VALIDATION_COLUMNS: usize = 128;
+
+/// Whether a column of type `actual` satisfies the declared type `expected`.
+///
+/// Types must be equal, except that an [`Object`] declaration accepts object
+/// columns of any Rust type, also inside lists.
+pub(crate) fn dtype_matches(expected: &DataType, actual: &DataType) -> bool {
+    match (expected, actual) {
+        #[cfg(feature = "dtype-full")]
+        (DataType::Object(OBJECT_PASSTHROUGH, _), DataType::Object(..)) => true,
+        (DataType::List(expected), DataType::List(actual)) => dtype_matches(expected, actual),
+        _ => expected == actual,
+    }
+}
+
+/// Reject schemas with non-numeric columns, for conversions into numeric containers.
+#[allow(dead_code)]
+pub(crate) fn ensure_numeric<S: Schema>() -> PolarsResult<()> {
+    match S::schema().iter().find(|(_, dtype)| !dtype.is_numeric()) {
+        Some((name, dtype)) => Err(PolarsError::SchemaMismatch(
+            format!("Column '{}' has non-numeric type {:?}", name, dtype).into()
+        )),
+        None => Ok(()),
+    }
 }
 
 /// Marker trait for column types that can be used in a schema
 pub trait ColumnType {
     /// The corresponding Polars DataType
     fn data_type() -> DataType;
+    
+    /// Whether the column may hold nulls; only `Option<T>` (and [`Null`]) columns do
+    const NULLABLE: bool = false;
 }
 
 // Implement ColumnType for common Rust types
@@ -80,6 +319,95 @@ impl ColumnType for str {
     fn data_type() -> DataType { DataType::String }
 }
 
+/// A nullable column: the same Polars type as `T`, with nulls allowed.
+impl<T: ColumnType> ColumnType for Option<T> {
+    fn data_type() -> DataType { T::data_type() }
+    
+    const NULLABLE: bool = true;
+}
+
+impl ColumnType for chrono::NaiveDate {
+    fn data_type() -> DataType { DataType::Date }
+}
+
+impl ColumnType for chrono::NaiveDateTime {
+    fn data_type() -> DataType { DataType::Datetime(TimeUnit::Microseconds, None) }
+}
+
+impl ColumnType for chrono::NaiveTime {
+    fn data_type() -> DataType { DataType::Time }
+}
+
+// The remaining Polars dtypes, behind the `dtype-full` feature
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for chrono::DateTime<chrono::Utc> {
+    fn data_type() -> DataType { DataType::Datetime(TimeUnit::Microseconds, Some("UTC".into())) }
+}
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for chrono::TimeDelta {
+    fn data_type() -> DataType { DataType::Duration(TimeUnit::Microseconds) }
+}
+
+#[cfg(feature = "dtype-full")]
+impl<T: ColumnType> ColumnType for Vec<T> {
+    fn data_type() -> DataType { DataType::List(Box::new(T::data_type())) }
+}
+
+/// Column type of a column that only holds nulls, e.g. a field not populated yet.
+#[cfg(feature = "dtype-full")]
+pub struct Null;
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for Null {
+    fn data_type() -> DataType { DataType::Null }
+    
+    const NULLABLE: bool = true;
+}
+
+/// Column type of a categorical string column with physical (first-seen) ordering.
+#[cfg(feature = "dtype-full")]
+pub struct Categorical;
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for Categorical {
+    fn data_type() -> DataType { DataType::Categorical(None, CategoricalOrdering::Physical) }
+}
+
+/// Column type of a decimal column with `PRECISION` digits, `SCALE` of them after the point.
+#[cfg(feature = "dtype-full")]
+pub struct Decimal<const PRECISION: usize, const SCALE: usize>;
+
+#[cfg(feature = "dtype-full")]
+impl<const PRECISION: usize, const SCALE: usize> ColumnType for Decimal<PRECISION, SCALE> {
+    fn data_type() -> DataType { DataType::Decimal(Some(PRECISION), Some(SCALE)) }
+}
+
+/// Column type of a binary column.
+#[cfg(feature = "dtype-full")]
+pub struct Binary;
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for Binary {
+    fn data_type() -> DataType { DataType::Binary }
+}
+
+/// Column type of an object column, passed through as is.
+///
+/// Any Polars object column is accepted, whatever Rust type it holds.
+#[cfg(feature = "dtype-full")]
+pub struct Object;
+
+/// Type name of the [`Object`] column type; see [`dtype_matches`].
+#[cfg(feature = "dtype-full")]
+const OBJECT_PASSTHROUGH: &str = "object";
+
+#[cfg(feature = "dtype-full")]
+impl ColumnType for Object {
+    fn data_type() -> DataType { DataType::Object(OBJECT_PASSTHROUGH, None) }
+}
+
 /// Marker type for a specific column in a schema
 ///
 /// This allows compile-time verification that a column exists and has the correct type.
@@ -119,30 +447,150 @@ impl<T: ColumnType> Clone for Column<T> {
 
 impl<T: ColumnType> Copy for Column<T> {}
 
+/// A set of typed columns: a single [`Column`] or a tuple of up to 12 columns.
+///
+/// This lets operations take any number of columns, e.g. `(S::date, S::region)`,
+/// and compute their output from the column types: a selection of
+/// `(S::id, S::name)` has the columns `(Column<i64>, Column<String>)` and
+/// reads as `(TypedSeries<i64>, TypedSeries<String>)`.
+pub trait ColumnSet {
+    /// The columns as a tuple type, matched against [`Schema::Columns`]
+    type Columns;
+    
+    /// The typed series of the columns as a tuple
+    type Series;
+    
+    /// Returns the column names in order
+    fn names(&self) -> Vec<&'static str>;
+    
+    /// Reads the columns out of a DataFrame as typed series
+    fn series(&self, df: &DataFrame) -> PolarsResult<Self::Series>;
+}
+
+impl<T: ColumnType> ColumnSet for Column<T> {
+    type Columns = (Column<T>,);
+    type Series = TypedSeries<T>;
+    
+    fn names(&self) -> Vec<&'static str> {
+        vec![self.name]
+    }
+    
+    fn series(&self, df: &DataFrame) -> PolarsResult<Self::Series> {
+        TypedSeries::new(df.column(self.name)?.as_materialized_series().clone())
+    }
+}
+
+/// Two column sets one after the other, e.g. to select every column of a
+/// [`Joined`](crate::join::Joined) frame: `Concat((A::id, A::name), (B::region,))`.
+#[derive(Clone, Copy)]
+pub struct Concat<L: ColumnSet, R: ColumnSet>(pub L, pub R);
+
+impl<L: ColumnSet, R: ColumnSet> ColumnSet for Concat<L, R> {
+    type Columns = (L::Columns, R::Columns);
+    type Series = (L::Series, R::Series);
+    
+    fn names(&self) -> Vec<&'static str> {
+        self.0.names().into_iter().chain(self.1.names()).collect()
+    }
+    
+    fn series(&self, df: &DataFrame) -> PolarsResult<Self::Series> {
+        Ok((self.0.series(df)?, self.1.series(df)?))
+    }
+}
+
+macro_rules! impl_column_set_for_tuple {
+    ($($ty:ident $idx:tt),+) => {
+        impl<$($ty: ColumnType),+> ColumnSet for ($(Column<$ty>,)+) {
+            type Columns = Self;
+            type Series = ($(TypedSeries<$ty>,)+);
+            
+            fn names(&self) -> Vec<&'static str> {
+                vec![$(self.$idx.name),+]
+            }
+            
+            fn series(&self, df: &DataFrame) -> PolarsResult<Self::Series> {
+                Ok(($(self.$idx.series(df)?,)+))
+            }
+        }
+    };
+}
+
+impl_column_set_for_tuple!(A 0);
+impl_column_set_for_tuple!(A 0, B 1);
+impl_column_set_for_tuple!(A 0, B 1, C 2);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_column_set_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
 /// Macro to define a schema with compile-time type information
 ///
+/// Temporal columns may declare the format they are stored in with
+/// `#[format = "..."]`; readers of text formats parse them with it.
+///
+/// Numeric columns may declare the range their values should fall in with
+/// `#[range = MIN ..= MAX]`, after any `#[format]`. It is not enforced on
+/// construction; data-quality profiles count the values outside it.
+///
+/// Columns holding personal data may declare how
+/// [`anonymize`](crate::dataframe::TypedDataFrame::anonymize) masks them with
+/// `#[pii(hash)]`, `#[pii(truncate = N)]`, `#[pii(redact)]` or
+/// `#[pii(bucket = W)]`, after any `#[format]` and `#[range]`.
+///
+/// A `#[version = N]` attribute on the schema bumps its version, which is part
+/// of the fingerprint that keys result caches and checkpoints, when the meaning of columns
+/// changes without their names or types changing.
+///
+/// Doc comments on the schema and its columns, before any other attribute,
+/// document the generated items and appear in the schema's data dictionary.
+///
 /// # Example
 ///
 /// ```ignore
 /// define_schema! {
+///     /// Registered users, one row per account
+///     #[version = 2]
 ///     UserSchema {
 ///         id: i64,
 ///         name: String,
+///         /// Age in whole years
+///         #[range = 0 ..= 130]
+///         #[pii(bucket = 5)]
 ///         age: i32,
 ///         active: bool,
+///         #[format = "%d/%m/%Y"]
+///         joined: chrono::NaiveDate,
 ///     }
 /// }
 /// ```
 #[macro_export]
 macro_rules! define_schema {
     (
+        $(#[doc = $schema_doc:literal])*
+        $(#[version = $version:literal])?
         $schema_name:ident {
-            $($field_name:ident: $field_type:ty),* $(,)?
+            $(
+                $(#[doc = $doc:literal])*
+                $(#[format = $format:literal])?
+                $(#[range = $min:literal ..= $max:literal])?
+                $(#[pii($($pii:tt)*)])?
+                $field_name:ident: $field_type:ty
+            ),* $(,)?
         }
     ) => {
+        $(#[doc = $schema_doc])*
         pub struct $schema_name;
         
         impl $crate::schema::Schema for $schema_name {
+            type Columns = ($($crate::schema::Column<$field_type>,)*);
+            
+            const COLUMN_TYPES: &'static [(&'static str, &'static str)] = Self::__COLUMN_TYPES;
+            
             fn schema() -> polars::prelude::Schema {
                 use polars::prelude::*;
                 use $crate::schema::ColumnType;
@@ -160,39 +608,178 @@ macro_rules! define_schema {
                 ]
             }
             
-            fn validate(df: &DataFrame) -> PolarsResult<()> {
-                let expected_schema = Self::schema();
-                let actual_schema = df.schema();
-                
-                for (name, expected_dtype) in expected_schema.iter() {
-                    match actual_schema.get(name) {
-                        Some(actual_dtype) if actual_dtype == expected_dtype => {},
-                        Some(actual_dtype) => {
-                            return Err(PolarsError::SchemaMismatch(
-                                format!(
-                                    "Column '{}' has type {:?}, expected {:?}",
-                                    name, actual_dtype, expected_dtype
-                                ).into()
-                            ));
-                        }
-                        None => {
-                            return Err(PolarsError::ColumnNotFound(
-                                format!("Column '{}' not found in DataFrame", name).into()
-                            ));
-                        }
-                    }
-                }
-                
-                Ok(())
+            fn column_formats() -> Vec<(&'static str, &'static str)> {
+                #[allow(unused_mut)]
+                let mut formats = Vec::new();
+                $($(
+                    formats.push((stringify!($field_name), $format));
+                )?)*
+                formats
+            }
+            
+            fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+                #[allow(unused_mut)]
+                let mut ranges = Vec::new();
+                $($(
+                    ranges.push((stringify!($field_name), $min as f64, $max as f64));
+                )?)*
+                ranges
+            }
+            
+            fn column_pii() -> Vec<(&'static str, $crate::anonymize::PiiStrategy)> {
+                #[allow(unused_mut)]
+                let mut pii = Vec::new();
+                $($(
+                    pii.push((stringify!($field_name), $crate::__pii_strategy!($($pii)*)));
+                )?)*
+                pii
+            }
+            
+            fn nullable_columns() -> Vec<&'static str> {
+                vec![
+                    $((stringify!($field_name), <$field_type as $crate::schema::ColumnType>::NULLABLE),)*
+                ]
+                .into_iter()
+                .filter(|(_, nullable)| *nullable)
+                .map(|(name, _)| name)
+                .collect()
+            }
+            
+            fn description() -> String {
+                $crate::schema::doc_text(concat!($($schema_doc, "\n",)*))
             }
+            
+            fn column_docs() -> Vec<(&'static str, String)> {
+                vec![
+                    $((stringify!($field_name), $crate::schema::doc_text(concat!($($doc, "\n",)*))),)*
+                ]
+                .into_iter()
+                .filter(|(_, doc)| !doc.is_empty())
+                .collect()
+            }
+            
+            $(
+                fn version() -> u32 {
+                    $version
+                }
+            )?
         }
         
         // Create column accessors directly on the schema struct
         #[allow(non_upper_case_globals)]
         impl $schema_name {
             $(
+                $(#[doc = $doc])*
                 pub const $field_name: $crate::schema::Column<$field_type> = $crate::schema::Column::new(stringify!($field_name));
             )*
+            
+            /// Column names and types as written, for compile-time schema assertions
+            #[doc(hidden)]
+            pub const __COLUMN_TYPES: &'static [(&'static str, &'static str)] = &[
+                $((stringify!($field_name), stringify!($field_type)),)*
+            ];
         }
     };
 }
+
+/// Fails to compile unless every column of the first schema appears in the
+/// second with the same type.
+///
+/// Types are compared as written in `define_schema!`, so declare shared
+/// columns with the same spelling (e.g. `String`, not `std::string::String`).
+///
+/// # Example
+///
+/// ```ignore
+/// // A projection must stay readable from the full export
+/// typed_polars::assert_subschema!(OrderSummary, OrderExport);
+/// ```
+#[macro_export]
+macro_rules! assert_subschema {
+    ($narrow:ty, $wide:ty $(,)?) => {
+        const _: () = assert!(
+            $crate::schema::is_subschema(<$narrow>::__COLUMN_TYPES, <$wide>::__COLUMN_TYPES),
+            concat!("`", stringify!($narrow), "` is not a subschema of `", stringify!($wide), "`"),
+        );
+    };
+}
+
+/// Fails to compile if the two schemas share a column name, e.g. to make sure
+/// a join of the two cannot produce suffixed duplicates.
+#[macro_export]
+macro_rules! assert_schema_disjoint {
+    ($left:ty, $right:ty $(,)?) => {
+        const _: () = assert!(
+            $crate::schema::is_disjoint(<$left>::__COLUMN_TYPES, <$right>::__COLUMN_TYPES),
+            concat!("`", stringify!($left), "` and `", stringify!($right), "` share a column"),
+        );
+    };
+}
+
+/// The name a schema was declared with, without its module path.
+pub(crate) fn schema_name<S: Schema>() -> &'static str {
+    std::any::type_name::<S>().rsplit("::").next().unwrap_or_default()
+}
+
+/// Doc-comment text as written: the lines without the space after `///`, trimmed.
+#[doc(hidden)]
+pub fn doc_text(raw: &str) -> String {
+    raw.lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line).trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+#[doc(hidden)]
+pub const fn is_subschema(narrow: &[(&str, &str)], wide: &[(&str, &str)]) -> bool {
+    let mut i = 0;
+    while i < narrow.len() {
+        match position(wide, narrow[i].0) {
+            Some(j) if str_eq(narrow[i].1, wide[j].1) => {}
+            _ => return false,
+        }
+        i += 1;
+    }
+    true
+}
+
+#[doc(hidden)]
+pub const fn is_disjoint(left: &[(&str, &str)], right: &[(&str, &str)]) -> bool {
+    let mut i = 0;
+    while i < left.len() {
+        if position(right, left[i].0).is_some() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn position(columns: &[(&str, &str)], name: &str) -> Option<usize> {
+    let mut i = 0;
+    while i < columns.len() {
+        if str_eq(columns[i].0, name) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+// `==` on `str` is not callable in const fns
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,209 @@
+//! Serde integration: converting between typed frames and serde-compatible structs,
+//! and serializing typed frames themselves.
+//!
+//! Requires the `serde` feature.
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use polars::prelude::*;
+use serde::de::{self, DeserializeOwned, Deserializer};
+use serde::ser::{self, Serializer};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::io::IpcReader;
+use crate::error::TypedPolarsError;
+
+/// Days from 0001-01-01 (CE day 1) to the Unix epoch.
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Deserialize every row into a struct, matching serde field names to schema columns.
+    ///
+    /// Field renames and defaults declared with `#[serde(...)]` apply as usual;
+    /// nullable fields should be `Option`s. Temporal columns use chrono's
+    /// serde formats, so `chrono` fields deserialize directly.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize)]
+    /// struct Order { id: i64, #[serde(rename = "total")] amount: f64 }
+    ///
+    /// let orders: Vec<Order> = df.to_structs()?;
+    /// ```
+    pub fn to_structs<T: DeserializeOwned>(&self) -> PolarsResult<Vec<T>> {
+        let columns = S::column_names()
+            .into_iter()
+            .map(|name| Ok((name, self.inner().column(name)?.as_materialized_series())))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        
+        (0..self.height())
+            .map(|row| {
+                let mut record = Map::new();
+                for (name, series) in &columns {
+                    record.insert(name.to_string(), json_value(series.get(row)?));
+                }
+                serde_json::from_value(Value::Object(record)).map_err(|err| PolarsError::ComputeError(
+                    format!("Cannot deserialize row {}: {}", row, err).into()
+                ))
+            })
+            .collect()
+    }
+    
+    /// Build a frame from serializable structs, matching serde field names to schema columns.
+    ///
+    /// Fields missing from a struct (e.g. skipped `None`s) become nulls and
+    /// fields not in the schema are ignored.
+    pub fn from_structs<T: Serialize>(rows: &[T]) -> PolarsResult<Self> {
+        let schema = S::schema();
+        let mut columns: Vec<Vec<AnyValue<'static>>> = vec![Vec::with_capacity(rows.len()); schema.len()];
+        for (index, row) in rows.iter().enumerate() {
+            let record = match serde_json::to_value(row) {
+                Ok(Value::Object(record)) => record,
+                Ok(_) => {
+                    return Err(PolarsError::ComputeError(
+                        format!("Row {} does not serialize to a struct", index).into()
+                    ));
+                }
+                Err(err) => {
+                    return Err(PolarsError::ComputeError(
+                        format!("Cannot serialize row {}: {}", index, err).into()
+                    ));
+                }
+            };
+            for (values, (name, dtype)) in columns.iter_mut().zip(schema.iter()) {
+                let value = record.get(name.as_str()).unwrap_or(&Value::Null);
+                values.push(any_value(value, dtype).map_err(|err| PolarsError::ComputeError(
+                    format!("Row {}, column '{}': {}", index, name, err).into()
+                ))?);
+            }
+        }
+        
+        let columns = schema
+            .iter()
+            .zip(columns)
+            .map(|((name, dtype), values)| {
+                Series::from_any_values(name.clone(), &values, false)?
+                    .strict_cast(dtype)
+                    .map(|s| s.into_column())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Self::new(DataFrame::new(columns)?)
+    }
+}
+
+/// Wire form of a serialized frame: the schema fingerprint and the schema
+/// columns as an Arrow IPC file.
+#[derive(Serialize, Deserialize)]
+struct SerializedFrame {
+    fingerprint: String,
+    #[serde(with = "serde_bytes")]
+    ipc: Vec<u8>,
+}
+
+/// Serializes the schema columns as Arrow IPC bytes tagged with [`Schema::fingerprint`].
+///
+/// Works with any serde format; compact binary formats such as bincode store
+/// the IPC bytes as-is.
+impl<S: Schema> Serialize for TypedDataFrame<S> {
+    fn serialize<Z: Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        let mut df = self.select(S::column_names()).map_err(ser::Error::custom)?;
+        let mut ipc = Vec::new();
+        polars::prelude::IpcWriter::new(&mut ipc)
+            .finish(&mut df)
+            .map_err(ser::Error::custom)?;
+        SerializedFrame { fingerprint: S::fingerprint(), ipc }.serialize(serializer)
+    }
+}
+
+/// Deserializes a frame written by the `Serialize` impl, rejecting frames
+/// serialized under a different schema fingerprint and revalidating the data.
+impl<'de, S: Schema> Deserialize<'de> for TypedDataFrame<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let frame = SerializedFrame::deserialize(deserializer)?;
+        let expected = S::fingerprint();
+        if frame.fingerprint != expected {
+            return Err(de::Error::custom(TypedPolarsError::FingerprintMismatch {
+                expected,
+                actual: Some(frame.fingerprint),
+                context: Some("Serialized frame".to_string()),
+            }));
+        }
+        IpcReader::<S>::from_bytes(&frame.ipc).finish().map_err(de::Error::custom)
+    }
+}
+
+/// A frame value as JSON, with temporal values in chrono's serde formats.
+fn json_value(value: AnyValue) -> Value {
+    match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(b) => Value::Bool(b),
+        AnyValue::String(s) => Value::String(s.to_string()),
+        AnyValue::StringOwned(s) => Value::String(s.to_string()),
+        AnyValue::Float32(f) => serde_json::Number::from_f64(f64::from(f)).map_or(Value::Null, Value::Number),
+        AnyValue::Float64(f) => serde_json::Number::from_f64(f).map_or(Value::Null, Value::Number),
+        AnyValue::UInt64(u) => Value::from(u),
+        AnyValue::Date(days) => NaiveDate::from_num_days_from_ce_opt(days + EPOCH_DAYS_FROM_CE)
+            .map_or(Value::Null, |date| Value::String(date.to_string())),
+        AnyValue::Datetime(value, unit, _) => {
+            let datetime = match unit {
+                TimeUnit::Nanoseconds => Some(chrono::DateTime::from_timestamp_nanos(value)),
+                TimeUnit::Microseconds => chrono::DateTime::from_timestamp_micros(value),
+                TimeUnit::Milliseconds => chrono::DateTime::from_timestamp_millis(value),
+            };
+            datetime.map_or(Value::Null, |datetime| {
+                Value::String(datetime.naive_utc().format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+            })
+        }
+        AnyValue::Time(ns) => NaiveTime::from_num_seconds_from_midnight_opt(
+            (ns / 1_000_000_000) as u32,
+            (ns % 1_000_000_000) as u32,
+        )
+        .map_or(Value::Null, |time| Value::String(time.format("%H:%M:%S%.f").to_string())),
+        other => match other.extract::<i64>() {
+            Some(i) => Value::from(i),
+            None => Value::String(other.to_string()),
+        },
+    }
+}
+
+/// A JSON value as a frame value for a column of type `dtype`.
+fn any_value(value: &Value, dtype: &DataType) -> PolarsResult<AnyValue<'static>> {
+    let invalid = || PolarsError::ComputeError(format!("cannot convert {} to {:?}", value, dtype).into());
+    let value = match (value, dtype) {
+        (Value::Null, _) => AnyValue::Null,
+        (Value::String(s), DataType::Date) => {
+            let date = NaiveDate::parse_from_str(s, "%Y-%m-%d").map_err(|_| invalid())?;
+            AnyValue::Date(date.num_days_from_ce() - EPOCH_DAYS_FROM_CE)
+        }
+        (Value::String(s), DataType::Datetime(unit, _)) => {
+            let datetime = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f")
+                .map_err(|_| invalid())?
+                .and_utc();
+            let value = match unit {
+                TimeUnit::Nanoseconds => datetime.timestamp_nanos_opt().ok_or_else(invalid)?,
+                TimeUnit::Microseconds => datetime.timestamp_micros(),
+                TimeUnit::Milliseconds => datetime.timestamp_millis(),
+            };
+            AnyValue::Datetime(value, *unit, None)
+        }
+        (Value::String(s), DataType::Time) => {
+            let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f").map_err(|_| invalid())?;
+            AnyValue::Time(time.num_seconds_from_midnight() as i64 * 1_000_000_000 + time.nanosecond() as i64)
+        }
+        (Value::String(s), _) => AnyValue::StringOwned(s.as_str().into()),
+        (Value::Bool(b), _) => AnyValue::Boolean(*b),
+        (Value::Number(n), _) => {
+            if let Some(i) = n.as_i64() {
+                AnyValue::Int64(i)
+            } else if let Some(u) = n.as_u64() {
+                AnyValue::UInt64(u)
+            } else {
+                AnyValue::Float64(n.as_f64().ok_or_else(invalid)?)
+            }
+        }
+        _ => return Err(invalid()),
+    };
+    Ok(value)
+}
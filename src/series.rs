@@ -1,7 +1,10 @@
 //! Typed wrapper around Polars Series with compile-time type information.
 
 use polars::prelude::*;
-use crate::schema::ColumnType;
+use crate::schema::{dtype_matches, ColumnType};
+use crate::error::TypedPolarsError;
+use chrono::{Months, NaiveDate, NaiveDateTime, TimeDelta};
+use num_traits::PrimInt;
 use std::marker::PhantomData;
 
 /// A typed wrapper around a Polars Series that carries type information at compile time.
@@ -21,14 +24,13 @@ impl<T: ColumnType> TypedSeries<T> {
     /// Returns an error if the series data type doesn't match the expected type.
     pub fn new(series: Series) -> PolarsResult<Self> {
         let expected_dtype = T::data_type();
-        if series.dtype() != &expected_dtype {
-            return Err(PolarsError::SchemaMismatch(
-                format!(
-                    "Series has type {:?}, expected {:?}",
-                    series.dtype(),
-                    expected_dtype
-                ).into()
-            ));
+        if !dtype_matches(&expected_dtype, series.dtype()) {
+            return Err(TypedPolarsError::TypeMismatch {
+                column: series.name().to_string(),
+                expected: expected_dtype,
+                actual: series.dtype().clone(),
+                context: None,
+            }.into());
         }
         
         Ok(Self {
@@ -96,6 +98,45 @@ impl<T: ColumnType> std::fmt::Debug for TypedSeries<T> {
     }
 }
 
+impl<T: ColumnType> TypedSeries<Option<T>> {
+    /// The number of nulls.
+    pub fn null_count(&self) -> usize {
+        self.inner.null_count()
+    }
+    
+    /// Whether any value is null.
+    pub fn has_nulls(&self) -> bool {
+        self.null_count() > 0
+    }
+    
+    /// The non-null values, as a series of the non-nullable type.
+    pub fn drop_nulls(&self) -> TypedSeries<T> {
+        TypedSeries {
+            inner: self.inner.drop_nulls(),
+            _phantom: PhantomData,
+        }
+    }
+    
+    /// The series as the non-nullable type, if it holds no nulls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the null count if any value is null.
+    pub fn require_non_null(self) -> PolarsResult<TypedSeries<T>> {
+        if self.has_nulls() {
+            return Err(TypedPolarsError::UnexpectedNulls {
+                column: self.name().to_string(),
+                count: self.null_count(),
+                context: None,
+            }.into());
+        }
+        Ok(TypedSeries {
+            inner: self.inner,
+            _phantom: PhantomData,
+        })
+    }
+}
+
 // Conversions from native Rust types to TypedSeries
 impl TypedSeries<i32> {
     pub fn from_vec(name: &str, data: Vec<i32>) -> Self {
@@ -157,3 +198,146 @@ impl TypedSeries<String> {
         }
     }
 }
+
+// Sequence constructors for index columns and calendar spines
+impl TypedSeries<i32> {
+    /// The integers from `start` up to, not including, `stop`, `step` apart.
+    ///
+    /// A negative `step` counts down; a zero `step` is an error.
+    pub fn arange(name: &str, start: i32, stop: i32, step: i32) -> PolarsResult<Self> {
+        Ok(Self::from_vec(name, arange_values(start, stop, step)?))
+    }
+}
+
+impl TypedSeries<i64> {
+    /// The integers from `start` up to, not including, `stop`, `step` apart.
+    ///
+    /// A negative `step` counts down; a zero `step` is an error.
+    pub fn arange(name: &str, start: i64, stop: i64, step: i64) -> PolarsResult<Self> {
+        Ok(Self::from_vec(name, arange_values(start, stop, step)?))
+    }
+}
+
+impl TypedSeries<NaiveDate> {
+    /// The dates from `start` through `end`, `every` apart.
+    ///
+    /// `every` is a count and a unit: `d` (days), `w` (weeks), `mo` (months),
+    /// `q` (quarters) or `y` (years), e.g. `"1d"` or `"3mo"`. Month steps are
+    /// taken from `start`, so month-end spines stay on month ends where they exist.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let days = TypedSeries::<NaiveDate>::date_range("day", jan_1, dec_31, "1d")?;
+    /// ```
+    pub fn date_range(name: &str, start: NaiveDate, end: NaiveDate, every: &str) -> PolarsResult<Self> {
+        let step = Step::parse(every)?;
+        if let Step::Duration(duration) = step {
+            if duration.num_seconds() % 86_400 != 0 {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Date ranges need whole-day steps, got '{}'", every).into()
+                ));
+            }
+        }
+        let dates = step
+            .range(start.into(), end.into())
+            .into_iter()
+            .map(|datetime| datetime.date())
+            .collect::<Vec<_>>();
+        Ok(Self {
+            inner: DateChunked::from_naive_date(name.into(), dates).into_series(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+impl TypedSeries<NaiveDateTime> {
+    /// The datetimes from `start` through `end`, `every` apart.
+    ///
+    /// Besides the date units `d`, `w`, `mo`, `q` and `y`, `every` may use
+    /// `h` (hours), `m` (minutes) or `s` (seconds), e.g. `"15m"`.
+    pub fn date_range(name: &str, start: NaiveDateTime, end: NaiveDateTime, every: &str) -> PolarsResult<Self> {
+        let datetimes = Step::parse(every)?.range(start, end);
+        Ok(Self {
+            inner: DatetimeChunked::from_naive_datetime(name.into(), datetimes, TimeUnit::Microseconds).into_series(),
+            _phantom: PhantomData,
+        })
+    }
+}
+
+fn arange_values<T: PrimInt>(start: T, stop: T, step: T) -> PolarsResult<Vec<T>> {
+    if step.is_zero() {
+        return Err(PolarsError::InvalidOperation("arange step must not be zero".into()));
+    }
+    let ascending = step > T::zero();
+    Ok(std::iter::successors(Some(start), |value| value.checked_add(&step))
+        .take_while(|value| if ascending { *value < stop } else { *value > stop })
+        .collect())
+}
+
+/// The interval between consecutive values of a date range.
+#[derive(Clone, Copy)]
+enum Step {
+    Months(u32),
+    Duration(TimeDelta),
+}
+
+impl Step {
+    /// Parse a count and unit such as `1d`, `2w`, `3mo`, `1q`, `1y`, `6h`, `15m` or `30s`.
+    fn parse(every: &str) -> PolarsResult<Self> {
+        let invalid = || PolarsError::InvalidOperation(
+            format!("Invalid interval '{}'; expected e.g. '1d', '2w', '1mo', '1y' or '6h'", every).into()
+        );
+        let digits = every.find(|c: char| !c.is_ascii_digit()).unwrap_or(every.len());
+        let (count, unit) = every.split_at(digits);
+        let count: u32 = count.parse().ok().filter(|count| *count > 0).ok_or_else(invalid)?;
+        let step = match unit {
+            "s" => Step::Duration(TimeDelta::seconds(count.into())),
+            "m" => Step::Duration(TimeDelta::minutes(count.into())),
+            "h" => Step::Duration(TimeDelta::hours(count.into())),
+            "d" => Step::Duration(TimeDelta::days(count.into())),
+            "w" => Step::Duration(TimeDelta::weeks(count.into())),
+            "mo" => Step::Months(count),
+            "q" => Step::Months(count.checked_mul(3).ok_or_else(invalid)?),
+            "y" => Step::Months(count.checked_mul(12).ok_or_else(invalid)?),
+            _ => return Err(invalid()),
+        };
+        Ok(step)
+    }
+    
+    /// The values `start + k * step` up to and including `end`.
+    fn range(self, start: NaiveDateTime, end: NaiveDateTime) -> Vec<NaiveDateTime> {
+        let mut values = Vec::new();
+        for k in 0u32.. {
+            let value = match self {
+                Step::Months(months) => k
+                    .checked_mul(months)
+                    .and_then(|months| start.checked_add_months(Months::new(months))),
+                Step::Duration(duration) => i32::try_from(k)
+                    .ok()
+                    .and_then(|k| duration.checked_mul(k))
+                    .and_then(|duration| start.checked_add_signed(duration)),
+            };
+            match value {
+                Some(value) if value <= end => values.push(value),
+                _ => break,
+            }
+        }
+        values
+    }
+}
+
+/// The values of a numeric series, failing if it contains nulls.
+#[allow(dead_code)]
+pub(crate) fn no_null_values<T>(series: &Series) -> PolarsResult<Vec<T>>
+where
+    T: NumericNative,
+    T::PolarsType: PolarsNumericType<Native = T>,
+{
+    if series.null_count() > 0 {
+        return Err(PolarsError::ComputeError(
+            format!("Column '{}' contains nulls and cannot be converted to a numeric array", series.name()).into()
+        ));
+    }
+    Ok(series.unpack::<T::PolarsType>()?.into_no_null_iter().collect())
+}
@@ -0,0 +1,74 @@
+//! Gap-based sessionization of event frames.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use crate::series::TypedSeries;
+use std::time::Duration;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Number each user's sessions: runs of events no more than `gap` apart.
+    ///
+    /// The result is a `session_id` series aligned with the rows of the frame.
+    /// Sessions are numbered from 0 across all users, in order of user and
+    /// session start. `timestamp` must be a `Date` or `Datetime` column;
+    /// rows with a null timestamp get a null session.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let sessions = clicks.sessionize(ClickSchema::user_id, ClickSchema::time, Duration::from_secs(30 * 60))?;
+    /// let clicks = clicks.inner().clone().with_column(sessions.into_inner())?;
+    /// ```
+    pub fn sessionize<U: ColumnType, T: ColumnType>(
+        &self,
+        user: Column<U>,
+        timestamp: Column<T>,
+        gap: Duration,
+    ) -> PolarsResult<TypedSeries<u32>> {
+        let gap = match self.inner().column(timestamp.name())?.dtype() {
+            DataType::Datetime(TimeUnit::Nanoseconds, _) => gap.as_nanos() as i64,
+            DataType::Datetime(TimeUnit::Microseconds, _) => gap.as_micros() as i64,
+            DataType::Datetime(TimeUnit::Milliseconds, _) => gap.as_millis() as i64,
+            DataType::Date => (gap.as_secs() / 86_400) as i64,
+            dtype => {
+                return Err(PolarsError::SchemaMismatch(
+                    format!("Column '{}' has type {:?}; sessions need a Date or Datetime column", timestamp.name(), dtype).into()
+                ));
+            }
+        };
+        
+        let ordered = self
+            .inner()
+            .select([user.name(), timestamp.name()])?
+            .with_row_index("__row".into(), None)?
+            .sort([user.name(), timestamp.name()], SortMultipleOptions::default())?;
+        let rows = ordered.column("__row")?.idx()?.clone();
+        let users = ordered.column(user.name())?.as_materialized_series();
+        let same_user = users.equal_missing(&users.shift(1))?;
+        let times = ordered
+            .column(timestamp.name())?
+            .as_materialized_series()
+            .to_physical_repr()
+            .cast(&DataType::Int64)?;
+        let times = times.i64()?;
+        
+        let mut sessions: Vec<Option<u32>> = vec![None; self.height()];
+        let mut next = 0;
+        let mut previous = None;
+        for (i, (row, time)) in rows.into_iter().zip(times).enumerate() {
+            if same_user.get(i) != Some(true) {
+                previous = None;
+            }
+            let (Some(row), Some(time)) = (row, time) else {
+                continue;
+            };
+            if !previous.is_some_and(|previous: i64| time - previous <= gap) {
+                next += 1;
+            }
+            sessions[row as usize] = Some(next - 1);
+            previous = Some(time);
+        }
+        TypedSeries::new(Series::new("session_id".into(), sessions))
+    }
+}
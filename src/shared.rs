@@ -0,0 +1,90 @@
+//! Reference-counted typed frames for sharing between threads and pipeline stages.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// A typed frame behind an `Arc`, with copy-on-write updates.
+///
+/// Cloning copies one pointer regardless of the number of columns, and
+/// readers on other threads keep a consistent snapshot while the owner
+/// [`update`](Self::update)s it: the update replaces the frame in place when
+/// no one else holds it, and otherwise swaps in a new `Arc`. Column buffers
+/// are never copied either way, since Polars columns are reference-counted.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut events = ArcTypedDataFrame::new(read_events()?);
+/// let snapshot = events.clone();
+/// std::thread::spawn(move || publish(&snapshot));
+///
+/// events.update(|df| df.filter(&df.column(EventSchema::valid)?.inner().bool()?.clone()))?;
+/// ```
+pub struct ArcTypedDataFrame<S: Schema> {
+    inner: Arc<TypedDataFrame<S>>,
+}
+
+impl<S: Schema> ArcTypedDataFrame<S> {
+    /// Share a typed frame.
+    pub fn new(df: TypedDataFrame<S>) -> Self {
+        Self { inner: Arc::new(df) }
+    }
+    
+    /// Replace the frame with the result of `f`, leaving other holders' snapshots untouched.
+    ///
+    /// On error the frame is left unchanged.
+    pub fn update(&mut self, f: impl FnOnce(&TypedDataFrame<S>) -> PolarsResult<TypedDataFrame<S>>) -> PolarsResult<()> {
+        let updated = f(&self.inner)?;
+        match Arc::get_mut(&mut self.inner) {
+            Some(df) => *df = updated,
+            None => self.inner = Arc::new(updated),
+        }
+        Ok(())
+    }
+    
+    /// Whether both handles point to the same frame.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+    
+    /// Take the frame out, cloning the column handles only if it is still shared.
+    pub fn into_inner(self) -> TypedDataFrame<S> {
+        Arc::try_unwrap(self.inner).unwrap_or_else(|shared| (*shared).clone())
+    }
+}
+
+impl<S: Schema> Deref for ArcTypedDataFrame<S> {
+    type Target = TypedDataFrame<S>;
+    
+    fn deref(&self) -> &TypedDataFrame<S> {
+        &self.inner
+    }
+}
+
+impl<S: Schema> Clone for ArcTypedDataFrame<S> {
+    fn clone(&self) -> Self {
+        Self { inner: Arc::clone(&self.inner) }
+    }
+}
+
+impl<S: Schema> From<TypedDataFrame<S>> for ArcTypedDataFrame<S> {
+    fn from(df: TypedDataFrame<S>) -> Self {
+        Self::new(df)
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for ArcTypedDataFrame<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Move the frame behind an `Arc` for cheap sharing; see [`ArcTypedDataFrame`].
+    pub fn into_shared(self) -> ArcTypedDataFrame<S> {
+        ArcTypedDataFrame::new(self)
+    }
+}
@@ -0,0 +1,62 @@
+//! Deterministic text rendering of typed frames for snapshot tests.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use std::fmt::Write;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Render the schema columns as text that only changes when the data does.
+    ///
+    /// Unlike `Display`, the output does not depend on Polars' formatting
+    /// configuration, terminal width or chunk layout: every row and column is
+    /// printed, floats use the shortest representation that round-trips,
+    /// strings are quoted and escaped, and nulls are written as `null`.
+    ///
+    /// ```text
+    /// shape: (2, 3)
+    /// id: Int64 | name: String | value: Float64
+    /// 1 | "a" | 0.1
+    /// 2 | null | 1e-7
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// insta::assert_snapshot!(report.to_snapshot_string()?);
+    /// ```
+    pub fn to_snapshot_string(&self) -> PolarsResult<String> {
+        let names = S::column_names();
+        let columns = names
+            .iter()
+            .map(|name| Ok(self.inner().column(name)?.as_materialized_series()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        
+        let mut out = format!("shape: ({}, {})\n", self.height(), columns.len());
+        let header: Vec<String> = columns.iter().map(|s| format!("{}: {:?}", s.name(), s.dtype())).collect();
+        out.push_str(&header.join(" | "));
+        out.push('\n');
+        for row in 0..self.height() {
+            for (i, series) in columns.iter().enumerate() {
+                if i > 0 {
+                    out.push_str(" | ");
+                }
+                write_value(&mut out, series.get(row)?);
+            }
+            out.push('\n');
+        }
+        Ok(out)
+    }
+}
+
+fn write_value(out: &mut String, value: AnyValue) {
+    // Writing into a String cannot fail
+    let _ = match value {
+        AnyValue::Null => write!(out, "null"),
+        AnyValue::Float32(f) => write!(out, "{:?}", f),
+        AnyValue::Float64(f) => write!(out, "{:?}", f),
+        AnyValue::String(s) => write!(out, "{:?}", s),
+        AnyValue::StringOwned(s) => write!(out, "{:?}", s.as_str()),
+        other => write!(out, "{}", other),
+    };
+}
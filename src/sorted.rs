@@ -0,0 +1,267 @@
+//! Sortedness witnesses: typed frames certified to be sorted by a column.
+
+use polars::prelude::*;
+use polars::series::IsSorted;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::{hstack_suffixed, TypedDataFrame};
+use std::cmp::Ordering;
+use std::ops::Deref;
+
+/// A typed frame known to be sorted by one of its columns.
+///
+/// Returned by [`TypedDataFrame::sort`], or checked with [`Sorted::new`].
+/// Operations that are only correct on sorted input, such as
+/// [`search_sorted`](Self::search_sorted), [`merge_join`](Self::merge_join)
+/// and [`join_asof`](Self::join_asof),
+/// take a `Sorted` instead of trusting the caller. Nulls sort first, as
+/// Polars' `sort` puts them.
+///
+/// # Example
+///
+/// ```ignore
+/// let trades = trades.sort(TradeSchema::time, false)?;
+/// let quotes = quotes.sort(QuoteSchema::time, false)?;
+/// let priced: TypedDataFrame<PricedTrade> = trades.join_asof(&quotes, AsofStrategy::Backward)?;
+/// ```
+pub struct Sorted<S: Schema, T: ColumnType> {
+    df: TypedDataFrame<S>,
+    column: Column<T>,
+    descending: bool,
+}
+
+impl<S: Schema, T: ColumnType> Sorted<S, T> {
+    /// Certify a frame as sorted by `column`, checking that it is.
+    ///
+    /// Columns Polars already flags as sorted in that direction are not
+    /// scanned, unless they have nulls after the first row, as a sort with
+    /// `nulls_last` leaves them.
+    pub fn new(df: TypedDataFrame<S>, column: Column<T>, descending: bool) -> PolarsResult<Self> {
+        let series = df.inner().column(column.name())?.as_materialized_series();
+        let flag = if descending { IsSorted::Descending } else { IsSorted::Ascending };
+        let nulls_first = series.null_count() == 0 || series.get(0)?.is_null();
+        let sorted = (series.is_sorted_flag() == flag && nulls_first) || {
+            let options = SortOptions::default().with_order_descending(descending);
+            series.sort(options)?.equals_missing(series)
+        };
+        if !sorted {
+            return Err(PolarsError::InvalidOperation(
+                format!(
+                    "Column '{}' is not sorted in {} order",
+                    column.name(),
+                    if descending { "descending" } else { "ascending" },
+                ).into()
+            ));
+        }
+        Ok(Self { df, column, descending })
+    }
+    
+    /// Certify a frame as sorted by `column` without checking.
+    ///
+    /// # Safety
+    ///
+    /// Operations on the result give wrong answers if the frame is not sorted
+    /// by `column` in the given order, with nulls first.
+    pub unsafe fn new_unchecked(df: TypedDataFrame<S>, column: Column<T>, descending: bool) -> Self {
+        Self { df, column, descending }
+    }
+    
+    /// The column the frame is sorted by.
+    pub fn column(&self) -> Column<T> {
+        self.column
+    }
+    
+    /// Whether the frame is sorted in descending order.
+    pub fn descending(&self) -> bool {
+        self.descending
+    }
+    
+    /// Drop the witness and return the frame.
+    pub fn into_inner(self) -> TypedDataFrame<S> {
+        self.df
+    }
+    
+    /// The first row whose value is not before `value` in sort order, by
+    /// binary search; the height of the frame if every value comes before it.
+    ///
+    /// `value` is cast to the type of the column, failing if it does not fit
+    /// or is null. The leading nulls are skipped, so the result is at least
+    /// the number of nulls.
+    pub fn search_sorted<'v>(&self, value: impl Into<AnyValue<'v>>) -> PolarsResult<usize> {
+        let series = self.df.inner().column(self.column.name())?.as_materialized_series();
+        let value = Series::from_any_values("".into(), &[value.into()], true)?.strict_cast(series.dtype())?;
+        let value = value.get(0)?;
+        if value.is_null() {
+            return Err(PolarsError::InvalidOperation(
+                format!("Cannot search column '{}' for a null value", self.column.name()).into()
+            ));
+        }
+        let (mut low, mut high) = (series.null_count(), series.len());
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let current = series.get(mid)?;
+            let before = if self.descending { current > value } else { current < value };
+            if before {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        Ok(low)
+    }
+    
+    /// Inner-join `right` on the sort columns by merging the two sorted frames, into the schema `Out`.
+    ///
+    /// The key columns are walked in step, so unlike a hash join no hash
+    /// table is built. Both frames and the result are held in memory, and the
+    /// keys are copied once, so this is not a streaming join. Keys must be
+    /// strings, booleans, numbers or temporals. Rows appear in key order; rows
+    /// with equal keys pair up in every combination. Null keys never match.
+    /// The key column of `right` is dropped and its other columns whose names
+    /// this frame already has get a `_right` suffix, as in Polars joins.
+    ///
+    /// Both frames must be sorted in the same direction.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let orders = Sorted::new(orders, OrderSchema::customer_id, false)?;
+    /// let payments = Sorted::new(payments, PaymentSchema::customer_id, false)?;
+    /// let matched: TypedDataFrame<OrderPayment> = orders.merge_join(&payments)?;
+    /// ```
+    pub fn merge_join<R: Schema, Out: Schema>(&self, right: &Sorted<R, T>) -> PolarsResult<TypedDataFrame<Out>> {
+        if self.descending != right.descending {
+            return Err(PolarsError::InvalidOperation(
+                "Merge joins require both frames sorted in the same direction".into()
+            ));
+        }
+        let left_keys = self.df.inner().column(self.column.name())?.as_materialized_series();
+        let right_keys = right.df.inner().column(right.column.name())?.as_materialized_series();
+        let pairs = match left_keys.dtype() {
+            DataType::String => merge_pairs(
+                &left_keys.str()?.into_iter().collect::<Vec<_>>(),
+                &right_keys.str()?.into_iter().collect::<Vec<_>>(),
+                self.descending,
+            ),
+            dtype if dtype.is_float() => {
+                let values = |series: &Series| -> PolarsResult<Vec<Option<f64>>> {
+                    Ok(series.strict_cast(&DataType::Float64)?.f64()?.into_iter().collect())
+                };
+                merge_pairs(&values(left_keys)?, &values(right_keys)?, self.descending)
+            }
+            dtype if dtype.is_bool() || dtype.is_integer() || dtype.is_temporal() => {
+                let values = |series: &Series| -> PolarsResult<Vec<Option<i64>>> {
+                    Ok(series.to_physical_repr().strict_cast(&DataType::Int64)?.i64()?.into_iter().collect())
+                };
+                merge_pairs(&values(left_keys)?, &values(right_keys)?, self.descending)
+            }
+            dtype => {
+                return Err(PolarsError::InvalidOperation(
+                    format!("Merge joins do not support keys of type {}", dtype).into()
+                ));
+            }
+        };
+        
+        let (left_rows, right_rows): (Vec<IdxSize>, Vec<IdxSize>) = pairs.into_iter().unzip();
+        let joined = self.df.inner().take(&IdxCa::from_vec("".into(), left_rows))?;
+        let matched = right.df.inner().drop(right.column.name())?.take(&IdxCa::from_vec("".into(), right_rows))?;
+        TypedDataFrame::new(hstack_suffixed(joined, matched)?)
+    }
+    
+    /// Join every row to the last row of `right` whose key is at or before it
+    /// (or after it, or nearest, depending on `strategy`), into the schema `Out`.
+    ///
+    /// Both frames must be sorted in ascending order.
+    #[cfg(feature = "asof_join")]
+    pub fn join_asof<R: Schema, Out: Schema>(
+        &self,
+        right: &Sorted<R, T>,
+        strategy: AsofStrategy,
+    ) -> PolarsResult<TypedDataFrame<Out>> {
+        if self.descending || right.descending {
+            return Err(PolarsError::InvalidOperation(
+                "As-of joins require both frames sorted in ascending order".into()
+            ));
+        }
+        let options = AsOfOptions {
+            strategy,
+            ..Default::default()
+        };
+        let df = self
+            .df
+            .inner()
+            .clone()
+            .lazy()
+            .join_builder()
+            .with(right.df.inner().clone().lazy())
+            .left_on([col(self.column.name())])
+            .right_on([col(right.column.name())])
+            .how(JoinType::AsOf(options))
+            .finish()
+            .collect()?;
+        TypedDataFrame::new(df)
+    }
+}
+
+impl<S: Schema, T: ColumnType> Deref for Sorted<S, T> {
+    type Target = TypedDataFrame<S>;
+    
+    fn deref(&self) -> &Self::Target {
+        &self.df
+    }
+}
+
+impl<S: Schema, T: ColumnType> Clone for Sorted<S, T> {
+    fn clone(&self) -> Self {
+        Self {
+            df: self.df.clone(),
+            column: self.column,
+            descending: self.descending,
+        }
+    }
+}
+
+impl<S: Schema, T: ColumnType> From<Sorted<S, T>> for TypedDataFrame<S> {
+    fn from(sorted: Sorted<S, T>) -> Self {
+        sorted.df
+    }
+}
+
+impl<S: Schema, T: ColumnType> std::fmt::Debug for Sorted<S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.df.fmt(f)
+    }
+}
+
+impl<S: Schema, T: ColumnType> std::fmt::Display for Sorted<S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.df.fmt(f)
+    }
+}
+
+/// The `(left, right)` row pairs with equal keys, found by walking two key
+/// columns sorted in the same direction with nulls first.
+fn merge_pairs<V: PartialOrd + Copy>(left: &[Option<V>], right: &[Option<V>], descending: bool) -> Vec<(IdxSize, IdxSize)> {
+    let compare = |a: V, b: V| {
+        let ordering = a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+        if descending { ordering.reverse() } else { ordering }
+    };
+    let mut pairs = Vec::new();
+    let mut i = left.iter().take_while(|key| key.is_none()).count();
+    let mut j = right.iter().take_while(|key| key.is_none()).count();
+    while let (Some(Some(a)), Some(Some(b))) = (left.get(i), right.get(j)) {
+        match compare(*a, *b) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                let left_run = left[i..].iter().take_while(|key| **key == Some(*a)).count();
+                let right_run = right[j..].iter().take_while(|key| **key == Some(*b)).count();
+                for l in i..i + left_run {
+                    pairs.extend((j..j + right_run).map(|r| (l as IdxSize, r as IdxSize)));
+                }
+                i += left_run.max(1);
+                j += right_run.max(1);
+            }
+        }
+    }
+    pairs
+}
@@ -0,0 +1,61 @@
+//! SQL queries over typed DataFrames.
+//!
+//! Requires the `sql` feature.
+
+use polars::prelude::*;
+use polars::sql::SQLContext;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::lazy::TypedLazyFrame;
+
+/// A SQL context whose tables are typed frames and whose results are validated against a schema.
+///
+/// # Example
+///
+/// ```ignore
+/// let mut ctx = TypedSqlContext::new();
+/// ctx.register("users", &users);
+///
+/// let adults = ctx.query::<AdultSchema>("SELECT id, name FROM users WHERE age >= 18")?;
+/// ```
+#[derive(Clone, Default)]
+pub struct TypedSqlContext {
+    inner: SQLContext,
+}
+
+impl TypedSqlContext {
+    /// Create an empty context.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Register a DataFrame as a table.
+    pub fn register<S: Schema>(&mut self, name: &str, df: &TypedDataFrame<S>) {
+        self.inner.register(name, df.inner().clone().lazy());
+    }
+    
+    /// Register a lazy query as a table; it is only executed as part of a query.
+    pub fn register_lazy<S: Schema>(&mut self, name: &str, lf: TypedLazyFrame<S>) {
+        self.inner.register(name, lf.into_inner());
+    }
+    
+    /// Remove a table from the context.
+    pub fn unregister(&mut self, name: &str) {
+        self.inner.unregister(name);
+    }
+    
+    /// Names of the registered tables.
+    pub fn tables(&self) -> Vec<String> {
+        self.inner.get_tables()
+    }
+    
+    /// Run a query and validate its result against the output schema.
+    pub fn query<Out: Schema>(&mut self, sql: &str) -> PolarsResult<TypedDataFrame<Out>> {
+        self.query_lazy::<Out>(sql)?.collect()
+    }
+    
+    /// Plan a query without executing it; the result is validated on collect.
+    pub fn query_lazy<Out: Schema>(&mut self, sql: &str) -> PolarsResult<TypedLazyFrame<Out>> {
+        Ok(TypedLazyFrame::new(self.inner.execute(sql)?))
+    }
+}
@@ -0,0 +1,262 @@
+//! SQLite reading and writing for typed DataFrames.
+//!
+//! Requires the `sqlite` feature.
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use polars::prelude::*;
+use rusqlite::types::{Value, ValueRef};
+use rusqlite::Connection;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::error::TypedPolarsError;
+use std::path::Path;
+
+/// Run a query against a SQLite database and validate the result against the schema.
+///
+/// Result columns are matched to schema columns by name. SQLite's storage
+/// classes are converted to the schema types: `INTEGER` 0/1 for booleans,
+/// ISO-8601 `TEXT` for dates, datetimes (in UTC) and times, and `INTEGER`
+/// counts of the column's time unit for durations, as [`write_sqlite`]
+/// stores them.
+///
+/// # Example
+///
+/// ```ignore
+/// let users = read_sqlite::<UserSchema>("app.db", "SELECT id, name, age FROM users")?;
+/// ```
+pub fn read_sqlite<Sch: Schema>(path: impl AsRef<Path>, query: &str) -> PolarsResult<TypedDataFrame<Sch>> {
+    let conn = Connection::open(path).map_err(sqlite_error)?;
+    let mut stmt = conn.prepare(query).map_err(sqlite_error)?;
+    let result_names: Vec<String> = stmt.column_names().into_iter().map(String::from).collect();
+    
+    let schema = Sch::schema();
+    let indices = schema
+        .iter_names()
+        .map(|name| {
+            result_names.iter().position(|n| n == name.as_str()).ok_or_else(|| {
+                TypedPolarsError::ColumnNotFound { column: name.to_string(), context: Some(query.to_string()) }.into()
+            })
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    
+    let mut builders: Vec<ColumnBuilder> = schema.iter_values().map(ColumnBuilder::for_dtype).collect();
+    let mut rows = stmt.query([]).map_err(sqlite_error)?;
+    while let Some(row) = rows.next().map_err(sqlite_error)? {
+        for (builder, &index) in builders.iter_mut().zip(&indices) {
+            builder.push(row.get_ref(index).map_err(sqlite_error)?)?;
+        }
+    }
+    
+    let columns = schema
+        .iter()
+        .zip(builders)
+        .map(|((name, dtype), builder)| builder.finish(name.clone(), dtype).map(|s| s.into_column()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    
+    TypedDataFrame::from_source(DataFrame::new(columns)?, query)
+}
+
+/// Write a DataFrame into a SQLite table, creating the table if it doesn't exist.
+///
+/// Column types are derived from the schema, and temporal values are stored
+/// as [`read_sqlite`] reads them. All rows are inserted in one transaction.
+pub fn write_sqlite<Sch: Schema>(
+    df: &TypedDataFrame<Sch>,
+    path: impl AsRef<Path>,
+    table: &str,
+) -> PolarsResult<()> {
+    let mut conn = Connection::open(path).map_err(sqlite_error)?;
+    let schema = Sch::schema();
+    
+    let column_defs: Vec<String> = schema
+        .iter()
+        .map(|(name, dtype)| format!("{} {}", quote_identifier(name), sqlite_type(dtype)))
+        .collect();
+    conn.execute(
+        &format!("CREATE TABLE IF NOT EXISTS {} ({})", quote_identifier(table), column_defs.join(", ")),
+        [],
+    )
+    .map_err(sqlite_error)?;
+    
+    let column_names: Vec<String> = schema.iter_names().map(|name| quote_identifier(name)).collect();
+    let placeholders = vec!["?"; column_names.len()].join(", ");
+    let insert = format!(
+        "INSERT INTO {} ({}) VALUES ({})",
+        quote_identifier(table),
+        column_names.join(", "),
+        placeholders
+    );
+    
+    let columns = schema
+        .iter_names()
+        .map(|name| df.inner().column(name))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    
+    let tx = conn.transaction().map_err(sqlite_error)?;
+    {
+        let mut stmt = tx.prepare(&insert).map_err(sqlite_error)?;
+        for row in 0..df.height() {
+            let values = columns
+                .iter()
+                .map(|column| to_sql_value(column.get(row)?))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            stmt.execute(rusqlite::params_from_iter(values)).map_err(sqlite_error)?;
+        }
+    }
+    tx.commit().map_err(sqlite_error)?;
+    
+    Ok(())
+}
+
+/// `name` as a quoted SQLite identifier.
+fn quote_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// The SQLite column type used to store a Polars type.
+fn sqlite_type(dtype: &DataType) -> &'static str {
+    if dtype.is_integer() || matches!(dtype, DataType::Boolean | DataType::Duration(_)) {
+        "INTEGER"
+    } else if dtype.is_float() {
+        "REAL"
+    } else {
+        "TEXT"
+    }
+}
+
+fn to_sql_value(value: AnyValue) -> PolarsResult<Value> {
+    let value = match value {
+        AnyValue::Null => Value::Null,
+        AnyValue::Boolean(b) => Value::Integer(b as i64),
+        AnyValue::String(s) => Value::Text(s.to_string()),
+        AnyValue::StringOwned(s) => Value::Text(s.to_string()),
+        AnyValue::Float32(f) => Value::Real(f as f64),
+        AnyValue::Float64(f) => Value::Real(f),
+        other if other.dtype().is_integer() => Value::Integer(other.extract::<i64>().ok_or_else(|| {
+            PolarsError::ComputeError(format!("Value {} does not fit in a SQLite INTEGER", other).into())
+        })?),
+        AnyValue::Date(days) => {
+            let date = NaiveDate::from_num_days_from_ce_opt(days + EPOCH_DAYS_FROM_CE).ok_or_else(|| out_of_range(&value))?;
+            Value::Text(date.format(DATE_FORMAT).to_string())
+        }
+        AnyValue::Datetime(timestamp, unit, _) => {
+            let datetime = match unit {
+                TimeUnit::Nanoseconds => Some(chrono::DateTime::from_timestamp_nanos(timestamp)),
+                TimeUnit::Microseconds => chrono::DateTime::from_timestamp_micros(timestamp),
+                TimeUnit::Milliseconds => chrono::DateTime::from_timestamp_millis(timestamp),
+            };
+            let datetime = datetime.ok_or_else(|| out_of_range(&value))?;
+            Value::Text(datetime.naive_utc().format(DATETIME_FORMAT).to_string())
+        }
+        AnyValue::Time(ns) => {
+            let time = NaiveTime::from_num_seconds_from_midnight_opt((ns / 1_000_000_000) as u32, (ns % 1_000_000_000) as u32)
+                .ok_or_else(|| out_of_range(&value))?;
+            Value::Text(time.format(TIME_FORMAT).to_string())
+        }
+        AnyValue::Duration(count, _) => Value::Integer(count),
+        other => Value::Text(other.to_string()),
+    };
+    Ok(value)
+}
+
+/// Days from 0001-01-01 to the Unix epoch, as chrono counts them.
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+fn out_of_range(value: &AnyValue) -> PolarsError {
+    PolarsError::ComputeError(format!("Value {} is out of range for SQLite", value).into())
+}
+
+/// Parse ISO-8601 text into a temporal series of type `dtype`.
+fn parse_temporal(name: PlSmallStr, values: Vec<Option<String>>, dtype: &DataType) -> PolarsResult<Series> {
+    fn parse_all<T>(values: &[Option<String>], parse: impl Fn(&str) -> Option<T>) -> PolarsResult<Vec<Option<T>>> {
+        values
+            .iter()
+            .map(|value| match value {
+                None => Ok(None),
+                Some(text) => parse(text).map(Some).ok_or_else(|| PolarsError::SchemaMismatch(
+                    format!("Cannot parse '{}' as an ISO-8601 value", text).into()
+                )),
+            })
+            .collect()
+    }
+    let series = match dtype {
+        DataType::Date => {
+            let dates = parse_all(&values, |text| NaiveDate::parse_from_str(text, DATE_FORMAT).ok())?;
+            DateChunked::from_naive_date_options(name, dates).into_series()
+        }
+        DataType::Datetime(unit, _) => {
+            // Also accept the space SQLite's own date functions put between date and time
+            let datetimes = parse_all(&values, |text| {
+                NaiveDateTime::parse_from_str(&text.replacen(' ', "T", 1), DATETIME_FORMAT).ok()
+            })?;
+            DatetimeChunked::from_naive_datetime_options(name, datetimes, *unit).into_series()
+        }
+        DataType::Time => {
+            let times = parse_all(&values, |text| NaiveTime::parse_from_str(text, TIME_FORMAT).ok())?;
+            TimeChunked::from_naive_time_options(name, times).into_series()
+        }
+        _ => Series::new(name, values),
+    };
+    series.strict_cast(dtype)
+}
+
+/// Accumulates the values of one result column before conversion to a series.
+enum ColumnBuilder {
+    Int(Vec<Option<i64>>),
+    Float(Vec<Option<f64>>),
+    Bool(Vec<Option<bool>>),
+    Text(Vec<Option<String>>),
+}
+
+impl ColumnBuilder {
+    fn for_dtype(dtype: &DataType) -> Self {
+        if dtype.is_integer() || matches!(dtype, DataType::Duration(_)) {
+            ColumnBuilder::Int(Vec::new())
+        } else if dtype.is_float() {
+            ColumnBuilder::Float(Vec::new())
+        } else if dtype == &DataType::Boolean {
+            ColumnBuilder::Bool(Vec::new())
+        } else {
+            ColumnBuilder::Text(Vec::new())
+        }
+    }
+    
+    fn push(&mut self, value: ValueRef) -> PolarsResult<()> {
+        match (self, value) {
+            (ColumnBuilder::Int(values), ValueRef::Null) => values.push(None),
+            (ColumnBuilder::Float(values), ValueRef::Null) => values.push(None),
+            (ColumnBuilder::Bool(values), ValueRef::Null) => values.push(None),
+            (ColumnBuilder::Text(values), ValueRef::Null) => values.push(None),
+            (ColumnBuilder::Int(values), ValueRef::Integer(i)) => values.push(Some(i)),
+            (ColumnBuilder::Float(values), ValueRef::Integer(i)) => values.push(Some(i as f64)),
+            (ColumnBuilder::Float(values), ValueRef::Real(f)) => values.push(Some(f)),
+            (ColumnBuilder::Bool(values), ValueRef::Integer(i)) => values.push(Some(i != 0)),
+            (ColumnBuilder::Text(values), ValueRef::Text(text)) => {
+                values.push(Some(String::from_utf8_lossy(text).into_owned()))
+            }
+            (_, value) => {
+                return Err(PolarsError::SchemaMismatch(
+                    format!("Unexpected SQLite value of type {}", value.data_type()).into()
+                ));
+            }
+        }
+        Ok(())
+    }
+    
+    fn finish(self, name: PlSmallStr, dtype: &DataType) -> PolarsResult<Series> {
+        let series = match self {
+            ColumnBuilder::Int(values) => Series::new(name, values),
+            ColumnBuilder::Float(values) => Series::new(name, values),
+            ColumnBuilder::Bool(values) => Series::new(name, values),
+            ColumnBuilder::Text(values) => return parse_temporal(name, values, dtype),
+        };
+        series.strict_cast(dtype)
+    }
+}
+
+fn sqlite_error(err: rusqlite::Error) -> PolarsError {
+    PolarsError::ComputeError(format!("SQLite error: {}", err).into())
+}
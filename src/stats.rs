@@ -0,0 +1,141 @@
+//! Pairwise statistics over the numeric columns of typed frames.
+
+use polars::prelude::*;
+use crate::schema::{Column, ColumnType, Schema};
+use crate::dataframe::TypedDataFrame;
+use std::marker::PhantomData;
+
+/// A square matrix of a pairwise statistic over the numeric columns of `S`.
+///
+/// Entries are looked up with typed columns, and [`to_frame`](Self::to_frame)
+/// lays the matrix out as a frame with one row and one `Float64` column per
+/// numeric column.
+///
+/// # Example
+///
+/// ```ignore
+/// let corr = houses.corr_matrix()?;
+/// if corr.get(HouseSchema::rooms, HouseSchema::area).is_some_and(|r| r.abs() > 0.9) {
+///     println!("rooms and area are nearly collinear");
+/// }
+/// println!("{}", corr.to_frame()?);
+/// ```
+pub struct StatMatrix<S: Schema> {
+    columns: Vec<&'static str>,
+    values: Vec<f64>,
+    _phantom: PhantomData<S>,
+}
+
+impl<S: Schema> StatMatrix<S> {
+    /// The columns of the matrix, in schema order.
+    pub fn columns(&self) -> &[&'static str] {
+        &self.columns
+    }
+    
+    /// The statistic of two columns; `None` if either is not numeric.
+    ///
+    /// The value is `NaN` when it is undefined, e.g. the correlation of a
+    /// constant column.
+    pub fn get<A: ColumnType, B: ColumnType>(&self, a: Column<A>, b: Column<B>) -> Option<f64> {
+        let i = self.columns.iter().position(|name| *name == a.name())?;
+        let j = self.columns.iter().position(|name| *name == b.name())?;
+        Some(self.values[i * self.columns.len() + j])
+    }
+    
+    /// The matrix as a frame: a `column` column naming each row, followed by one
+    /// `Float64` column per numeric column.
+    pub fn to_frame(&self) -> PolarsResult<DataFrame> {
+        let n = self.columns.len();
+        let mut columns = vec![Series::new("column".into(), &self.columns).into_column()];
+        for (j, name) in self.columns.iter().enumerate() {
+            let values: Vec<f64> = (0..n).map(|i| self.values[i * n + j]).collect();
+            columns.push(Series::new((*name).into(), values).into_column());
+        }
+        DataFrame::new(columns)
+    }
+}
+
+impl<S: Schema> Clone for StatMatrix<S> {
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            values: self.values.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<S: Schema> std::fmt::Debug for StatMatrix<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StatMatrix")
+            .field("columns", &self.columns)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Pearson correlation of every pair of numeric schema columns.
+    ///
+    /// Each pair uses the rows where both values are present.
+    pub fn corr_matrix(&self) -> PolarsResult<StatMatrix<S>> {
+        self.pairwise(|pairs| {
+            let (cov, var_x, var_y) = moments(pairs)?;
+            Some(cov / (var_x * var_y).sqrt())
+        })
+    }
+    
+    /// Sample covariance (normalized by `n - 1`) of every pair of numeric schema columns.
+    ///
+    /// Each pair uses the rows where both values are present.
+    pub fn cov_matrix(&self) -> PolarsResult<StatMatrix<S>> {
+        self.pairwise(|pairs| moments(pairs).map(|(cov, _, _)| cov))
+    }
+    
+    fn pairwise(&self, stat: impl Fn(&[(f64, f64)]) -> Option<f64>) -> PolarsResult<StatMatrix<S>> {
+        let mut columns = Vec::new();
+        let mut data = Vec::new();
+        for (name, dtype) in S::column_names().into_iter().zip(S::schema().iter_values()) {
+            if !dtype.is_numeric() {
+                continue;
+            }
+            let series = self.inner().column(name)?.as_materialized_series().cast(&DataType::Float64)?;
+            columns.push(name);
+            data.push(series.f64()?.into_iter().collect::<Vec<_>>());
+        }
+        
+        let n = columns.len();
+        let mut values = vec![f64::NAN; n * n];
+        for i in 0..n {
+            for j in i..n {
+                let pairs: Vec<(f64, f64)> = data[i]
+                    .iter()
+                    .zip(&data[j])
+                    .filter_map(|(x, y)| Some(((*x)?, (*y)?)))
+                    .collect();
+                let value = stat(&pairs).unwrap_or(f64::NAN);
+                values[i * n + j] = value;
+                values[j * n + i] = value;
+            }
+        }
+        Ok(StatMatrix { columns, values, _phantom: PhantomData })
+    }
+}
+
+/// Sample covariance and variances of paired values, or `None` for fewer than two pairs.
+fn moments(pairs: &[(f64, f64)]) -> Option<(f64, f64, f64)> {
+    if pairs.len() < 2 {
+        return None;
+    }
+    let n = pairs.len() as f64;
+    let mean_x = pairs.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = pairs.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (x, y) in pairs {
+        let (dx, dy) = (x - mean_x, y - mean_y);
+        cov += dx * dy;
+        var_x += dx * dx;
+        var_y += dy * dy;
+    }
+    Some((cov / (n - 1.0), var_x / (n - 1.0), var_y / (n - 1.0)))
+}
@@ -0,0 +1,108 @@
+//! Sources of validated micro-batches for near-real-time pipelines.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::io::CsvBatchedReader;
+use std::sync::mpsc::{Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+/// A source that yields typed micro-batches at an ingestion boundary.
+///
+/// Every batch is validated against the schema before it is handed out, so
+/// downstream stages only ever see typed frames.
+pub trait TypedBatchSource {
+    /// The schema every batch is validated against.
+    type Schema: Schema;
+    
+    /// Wait for the next batch; `None` once the source is exhausted.
+    fn next_batch(&mut self) -> Option<PolarsResult<TypedDataFrame<Self::Schema>>>;
+}
+
+impl<Sch: Schema> TypedBatchSource for CsvBatchedReader<Sch> {
+    type Schema = Sch;
+    
+    fn next_batch(&mut self) -> Option<PolarsResult<TypedDataFrame<Sch>>> {
+        self.next()
+    }
+}
+
+/// Micro-batches assembled from DataFrames sent over a channel.
+///
+/// Incoming frames are stacked until a batch holds at least `max_rows` rows
+/// or `max_wait` has passed since its first frame arrived. The source is
+/// exhausted once all senders are dropped and the last batch was handed out.
+///
+/// # Example
+///
+/// ```ignore
+/// let (sender, receiver) = std::sync::mpsc::channel();
+/// std::thread::spawn(move || consume_kafka_into(sender));
+///
+/// let mut source = ChannelBatchSource::<EventSchema>::new(receiver)
+///     .max_rows(50_000)
+///     .max_wait(Duration::from_millis(500));
+/// while let Some(batch) = source.next_batch() {
+///     process(batch?);
+/// }
+/// ```
+pub struct ChannelBatchSource<Sch: Schema> {
+    receiver: Receiver<DataFrame>,
+    max_rows: usize,
+    max_wait: Duration,
+    _phantom: std::marker::PhantomData<Sch>,
+}
+
+impl<Sch: Schema> ChannelBatchSource<Sch> {
+    /// Create a source over the receiving end of a channel.
+    pub fn new(receiver: Receiver<DataFrame>) -> Self {
+        Self {
+            receiver,
+            max_rows: 10_000,
+            max_wait: Duration::from_secs(1),
+            _phantom: std::marker::PhantomData,
+        }
+    }
+    
+    /// Set the row count at which a batch is complete (default: 10000).
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+    
+    /// Set how long to collect frames for a batch after its first one (default: 1s).
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+}
+
+impl<Sch: Schema> TypedBatchSource for ChannelBatchSource<Sch> {
+    type Schema = Sch;
+    
+    fn next_batch(&mut self) -> Option<PolarsResult<TypedDataFrame<Sch>>> {
+        let mut batch = self.receiver.recv().ok()?;
+        let deadline = Instant::now() + self.max_wait;
+        while batch.height() < self.max_rows {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.receiver.recv_timeout(remaining) {
+                Ok(df) => {
+                    if let Err(err) = batch.vstack_mut(&df) {
+                        return Some(Err(err));
+                    }
+                }
+                // A disconnect ends this batch; the next call then returns `None`
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => break,
+            }
+        }
+        Some(TypedDataFrame::new(batch))
+    }
+}
+
+impl<Sch: Schema> Iterator for ChannelBatchSource<Sch> {
+    type Item = PolarsResult<TypedDataFrame<Sch>>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch()
+    }
+}
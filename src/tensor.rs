@@ -0,0 +1,53 @@
+//! Export of numeric column groups to deep-learning tensors.
+//!
+//! `to_candle_tensor` requires the `candle` feature and `to_burn_tensor`
+//! the `burn` feature.
+
+use polars::prelude::*;
+use crate::schema::{ColumnSet, Schema};
+use crate::dataframe::TypedDataFrame;
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Copy numeric columns into a `rows x columns` candle tensor of the given dtype on `device`.
+    ///
+    /// Columns are cast through `f64`; nulls are an error.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let inputs = batch.to_candle_tensor(
+    ///     (Pixels::r, Pixels::g, Pixels::b),
+    ///     candle_core::DType::F32,
+    ///     &candle_core::Device::Cpu,
+    /// )?;
+    /// ```
+    #[cfg(feature = "candle")]
+    pub fn to_candle_tensor(
+        &self,
+        columns: impl ColumnSet,
+        dtype: candle_core::DType,
+        device: &candle_core::Device,
+    ) -> PolarsResult<candle_core::Tensor> {
+        let names = columns.names();
+        let values = self.numeric_values(&names)?;
+        // The values are column after column, so build the transpose and flip it
+        candle_core::Tensor::from_vec(values, (names.len(), self.height()), device)
+            .and_then(|tensor| tensor.t()?.to_dtype(dtype)?.contiguous())
+            .map_err(|err| PolarsError::ComputeError(format!("Candle error: {}", err).into()))
+    }
+    
+    /// Copy numeric columns into a `rows x columns` float tensor of backend `B` on `device`.
+    ///
+    /// The element type is the backend's float element; nulls are an error.
+    #[cfg(feature = "burn")]
+    pub fn to_burn_tensor<B: burn_tensor::backend::Backend>(
+        &self,
+        columns: impl ColumnSet,
+        device: &B::Device,
+    ) -> PolarsResult<burn_tensor::Tensor<B, 2>> {
+        let names = columns.names();
+        let values = self.numeric_values(&names)?;
+        let data = burn_tensor::TensorData::new(values, [names.len(), self.height()]);
+        Ok(burn_tensor::Tensor::<B, 2>::from_data(data, device).transpose())
+    }
+}
@@ -0,0 +1,58 @@
+//! Per-node execution times of typed lazy pipelines.
+
+use polars::prelude::*;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+use crate::lazy::TypedLazyFrame;
+use std::time::Duration;
+
+crate::define_schema! {
+    NodeTiming {
+        node: String,
+        start: u64,
+        end: u64,
+    }
+}
+
+impl<S: Schema> TypedLazyFrame<S> {
+    /// Execute the query, timing each node of the plan.
+    ///
+    /// Returns the validated result and one row per executed node, with
+    /// `start` and `end` in microseconds since the query began.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let (report, timings) = sales.profile()?;
+    /// assert!(timings.total()? < Duration::from_millis(200), "{}", timings.inner());
+    /// ```
+    pub fn profile(self) -> PolarsResult<(TypedDataFrame<S>, TypedDataFrame<NodeTiming>)> {
+        let (df, timings) = self.into_inner().profile()?;
+        Ok((TypedDataFrame::new(df)?, TypedDataFrame::new(timings)?))
+    }
+}
+
+impl TypedDataFrame<NodeTiming> {
+    /// The time from the start of the query until its last node finished.
+    pub fn total(&self) -> PolarsResult<Duration> {
+        let end = self.column(NodeTiming::end)?.inner().u64()?.max();
+        Ok(Duration::from_micros(end.unwrap_or(0)))
+    }
+    
+    /// The summed time of the nodes whose name starts with `prefix`, e.g. `"sort"`.
+    pub fn node_time(&self, prefix: &str) -> PolarsResult<Duration> {
+        let nodes = self.column(NodeTiming::node)?;
+        let starts = self.column(NodeTiming::start)?;
+        let ends = self.column(NodeTiming::end)?;
+        let micros = nodes
+            .inner()
+            .str()?
+            .into_iter()
+            .zip(starts.inner().u64()?)
+            .zip(ends.inner().u64()?)
+            .filter(|((node, _), _)| node.is_some_and(|node| node.starts_with(prefix)))
+            .map(|((_, start), end)| end.unwrap_or(0).saturating_sub(start.unwrap_or(0)))
+            .sum();
+        Ok(Duration::from_micros(micros))
+    }
+}
@@ -0,0 +1,86 @@
+//! Caching of successful column checks.
+//!
+//! Whether a frame's columns match a schema depends only on the frame's
+//! column names and types, so once a column layout has passed the built-in
+//! check of [`Schema::check_columns`] for a schema, identical layouts skip the
+//! comparison. Layouts are remembered per thread, up to
+//! [`MAX_LAYOUTS_PER_SCHEMA`] per schema.
+//!
+//! Only that comparison is cached: overrides of [`Schema::validate`],
+//! [`Schema::check`] or [`Schema::check_columns`], which may look at the
+//! values, run for every frame.
+
+use polars::prelude::*;
+use crate::error::TypedPolarsError;
+use crate::schema::{self, Schema};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Column layouts remembered per schema before the oldest is evicted.
+const MAX_LAYOUTS_PER_SCHEMA: usize = 8;
+
+type Layout = Vec<(PlSmallStr, DataType)>;
+
+thread_local! {
+    // Keyed by type name, which unlike `TypeId` needs no `'static` schema
+    static VALIDATED: RefCell<HashMap<&'static str, Vec<Layout>>> = RefCell::new(HashMap::new());
+}
+
+/// The columns of `df` that do not match `S`, skipping the comparison for
+/// layouts that already passed for `S` on this thread.
+pub(crate) fn column_errors<S: Schema>(df: &DataFrame) -> Vec<TypedPolarsError> {
+    if is_validated::<S>(df) {
+        return Vec::new();
+    }
+    let errors = schema::layout_errors::<S>(df);
+    if errors.is_empty() {
+        remember::<S>(df);
+    }
+    errors
+}
+
+/// Forget the layouts that passed for `S` on this thread.
+pub(crate) fn forget<S: Schema>() {
+    VALIDATED.with(|validated| validated.borrow_mut().remove(std::any::type_name::<S>()));
+}
+
+/// Forget all validated layouts on this thread, so the next validations run in full.
+pub fn clear_validation_cache() {
+    VALIDATED.with(|validated| validated.borrow_mut().clear());
+}
+
+/// Whether a frame with the same column layout already passed for `S` on this thread.
+fn is_validated<S: Schema>(df: &DataFrame) -> bool {
+    VALIDATED.with(|validated| {
+        validated
+            .borrow()
+            .get(std::any::type_name::<S>())
+            .is_some_and(|layouts| layouts.iter().any(|layout| same_layout(layout, df)))
+    })
+}
+
+/// Remember that the frame's column layout passed for `S`.
+fn remember<S: Schema>(df: &DataFrame) {
+    let layout = df
+        .get_columns()
+        .iter()
+        .map(|column| (column.name().clone(), column.dtype().clone()))
+        .collect();
+    VALIDATED.with(|validated| {
+        let mut validated = validated.borrow_mut();
+        let layouts = validated.entry(std::any::type_name::<S>()).or_default();
+        if layouts.len() == MAX_LAYOUTS_PER_SCHEMA {
+            layouts.remove(0);
+        }
+        layouts.push(layout);
+    });
+}
+
+fn same_layout(layout: &Layout, df: &DataFrame) -> bool {
+    let columns = df.get_columns();
+    layout.len() == columns.len()
+        && layout
+            .iter()
+            .zip(columns)
+            .all(|((name, dtype), column)| name == column.name() && dtype == column.dtype())
+}
@@ -0,0 +1,72 @@
+//! Record-level validation of typed frames with the `validator` crate.
+//!
+//! Requires the `validator` feature.
+
+use polars::prelude::*;
+use serde::de::DeserializeOwned;
+use validator::Validate;
+use crate::schema::Schema;
+use crate::dataframe::TypedDataFrame;
+
+/// One failed `#[validate(...)]` check on one row.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RowViolation {
+    /// The row index in the frame
+    pub row: usize,
+    /// The struct field whose check failed
+    pub field: String,
+    /// The check's message if it has one, otherwise its code, e.g. `range`
+    pub reason: String,
+}
+
+impl<S: Schema> TypedDataFrame<S> {
+    /// Deserialize every row into the row struct `T` and run its
+    /// `#[validate(...)]` checks, returning every failure by row.
+    ///
+    /// Complements the column checks of the schema with rules that span
+    /// fields or need the `validator` crate's checks (emails, URLs, custom
+    /// functions). Rows are matched to `T` as in
+    /// [`to_structs`](Self::to_structs). An empty result means every row passed.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// #[derive(Deserialize, Validate)]
+    /// struct Signup {
+    ///     #[validate(email)]
+    ///     email: String,
+    ///     #[validate(range(min = 13, message = "too young"))]
+    ///     age: i32,
+    /// }
+    ///
+    /// for violation in signups.validate_rows::<Signup>()? {
+    ///     eprintln!("row {}: {} {}", violation.row, violation.field, violation.reason);
+    /// }
+    /// ```
+    pub fn validate_rows<T: DeserializeOwned + Validate>(&self) -> PolarsResult<Vec<RowViolation>> {
+        let mut violations = Vec::new();
+        for (row, record) in self.to_structs::<T>()?.into_iter().enumerate() {
+            let Err(errors) = record.validate() else {
+                continue;
+            };
+            let mut failed: Vec<RowViolation> = errors
+                .field_errors()
+                .into_iter()
+                .flat_map(|(field, errors)| {
+                    errors.iter().map(move |error| RowViolation {
+                        row,
+                        field: field.to_string(),
+                        reason: match &error.message {
+                            Some(message) => message.to_string(),
+                            None => error.code.to_string(),
+                        },
+                    })
+                })
+                .collect();
+            // Field errors come from a map; keep the report stable
+            failed.sort_by(|a, b| a.field.cmp(&b.field));
+            violations.extend(failed);
+        }
+        Ok(violations)
+    }
+}
@@ -9,6 +9,39 @@ typed_polars::define_schema! {
     }
 }
 
+/// A `TestSchema` frame with one `(id, name, value)` tuple per row
+fn test_df(rows: &[(i64, &str, i32)]) -> DataFrame {
+    DataFrame::new(vec![
+        Series::new("id".into(), rows.iter().map(|row| row.0).collect::<Vec<_>>()).into_column(),
+        Series::new("name".into(), rows.iter().map(|row| row.1).collect::<Vec<_>>()).into_column(),
+        Series::new("value".into(), rows.iter().map(|row| row.2).collect::<Vec<_>>()).into_column(),
+    ]).unwrap()
+}
+
+typed_polars::define_schema! {
+    TestSummary {
+        id: i64,
+        value: i32,
+    }
+}
+
+typed_polars::define_schema! {
+    OtherSchema {
+        label: String,
+    }
+}
+
+// Checked at compile time
+typed_polars::assert_subschema!(TestSummary, TestSchema);
+typed_polars::assert_schema_disjoint!(TestSchema, OtherSchema);
+
+#[test]
+fn test_schema_relationships() {
+    assert!(typed_polars::schema::is_subschema(TestSummary::__COLUMN_TYPES, TestSchema::__COLUMN_TYPES));
+    assert!(!typed_polars::schema::is_subschema(TestSchema::__COLUMN_TYPES, TestSummary::__COLUMN_TYPES));
+    assert!(!typed_polars::schema::is_disjoint(TestSummary::__COLUMN_TYPES, TestSchema::__COLUMN_TYPES));
+}
+
 #[test]
 fn test_create_typed_dataframe() {
     let df = DataFrame::new(vec![
@@ -107,3 +140,1409 @@ fn test_dataframe_sort() {
     
     assert_eq!(sorted.height(), 3);
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_struct_round_trip() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct Row {
+        id: i64,
+        #[serde(rename = "name")]
+        label: String,
+        value: i32,
+    }
+    
+    let rows = vec![
+        Row { id: 1, label: "a".to_string(), value: 10 },
+        Row { id: 2, label: "b".to_string(), value: 20 },
+    ];
+    let typed_df = TypedDataFrame::<TestSchema>::from_structs(&rows).unwrap();
+    assert_eq!(typed_df.height(), 2);
+    assert_eq!(typed_df.inner().column("value").unwrap().dtype(), &DataType::Int32);
+    
+    let back: Vec<Row> = typed_df.to_structs().unwrap();
+    assert_eq!(back, rows);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_round_trip() {
+    typed_polars::define_schema! {
+        #[version = 2]
+        TestSchemaV2 {
+            id: i64,
+            name: String,
+            value: i32,
+        }
+    }
+    
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    let typed_df = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let bytes = bincode::serialize(&typed_df).unwrap();
+    let back: TypedDataFrame<TestSchema> = bincode::deserialize(&bytes).unwrap();
+    assert!(back.inner().equals(typed_df.inner()));
+    
+    // Frames serialized under another schema version are rejected
+    assert!(bincode::deserialize::<TypedDataFrame<TestSchemaV2>>(&bytes).is_err());
+}
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_ndarray_round_trip() {
+    typed_polars::define_schema! {
+        PointSchema {
+            x: f64,
+            y: i32,
+        }
+    }
+    
+    let array = ndarray::array![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+    let typed_df = TypedDataFrame::<PointSchema>::from_ndarray(array.view()).unwrap();
+    assert_eq!(typed_df.shape(), (3, 2));
+    assert_eq!(typed_df.inner().column("y").unwrap().dtype(), &DataType::Int32);
+    assert_eq!(typed_df.to_ndarray::<f64>().unwrap(), array);
+    
+    // Non-numeric schemas are rejected
+    let strings = ndarray::array![[1i64, 2, 3]];
+    assert!(TypedDataFrame::<TestSchema>::from_ndarray(strings.view()).is_err());
+}
+
+#[cfg(feature = "nalgebra")]
+#[test]
+fn test_nalgebra_export() {
+    typed_polars::define_schema! {
+        SampleSchema {
+            x: f64,
+            y: i32,
+        }
+    }
+    
+    let df = DataFrame::new(vec![
+        Series::new("x".into(), vec![1.0f64, 3.0]).into_column(),
+        Series::new("y".into(), vec![2i32, 4]).into_column(),
+    ]).unwrap();
+    let typed_df = TypedDataFrame::<SampleSchema>::new(df).unwrap();
+    
+    let matrix = typed_df.to_dmatrix::<f64>().unwrap();
+    assert_eq!(matrix, nalgebra::DMatrix::from_row_slice(2, 2, &[1.0, 2.0, 3.0, 4.0]));
+    
+    let y = typed_df.column(SampleSchema::y).unwrap().to_dvector().unwrap();
+    assert_eq!(y, nalgebra::DVector::from_vec(vec![2, 4]));
+}
+
+#[cfg(feature = "linfa")]
+#[test]
+fn test_linfa_dataset() {
+    use linfa::prelude::Records;
+    
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    let typed_df = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let dataset = typed_df.to_linfa_dataset((TestSchema::id,), TestSchema::value).unwrap();
+    assert_eq!(dataset.nsamples(), 3);
+    assert_eq!(dataset.nfeatures(), 1);
+    assert_eq!(dataset.records()[[2, 0]], 3.0);
+    assert_eq!(dataset.targets()[1], 20);
+    assert_eq!(dataset.feature_names(), vec!["id".to_string()]);
+    
+    // String features are rejected
+    assert!(typed_df.to_linfa_dataset(TestSchema::name, TestSchema::value).is_err());
+}
+
+#[cfg(feature = "candle")]
+#[test]
+fn test_candle_tensor() {
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    let typed_df = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let tensor = typed_df
+        .to_candle_tensor((TestSchema::id, TestSchema::value), candle_core::DType::F32, &candle_core::Device::Cpu)
+        .unwrap();
+    assert_eq!(tensor.dims(), &[3, 2]);
+    assert_eq!(tensor.to_vec2::<f32>().unwrap(), vec![vec![1.0, 10.0], vec![2.0, 20.0], vec![3.0, 30.0]]);
+}
+
+#[cfg(feature = "plot")]
+#[test]
+fn test_plots_render_svg() {
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    let typed_df = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    assert!(typed_df.plot_scatter(TestSchema::id, TestSchema::value).unwrap().starts_with("<svg"));
+    assert!(typed_df.plot_line(TestSchema::id, TestSchema::value).unwrap().contains("<polyline"));
+    assert!(typed_df.plot_hist(TestSchema::value, 3).unwrap().contains("<rect"));
+    assert!(typed_df.plot_hist(TestSchema::name, 3).is_err());
+}
+
+#[test]
+fn test_structured_validation_errors() {
+    let mut df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    df.with_column(Series::new("value".into(), vec![10.5f64, 20.5, 30.5])).unwrap();
+    
+    match TypedDataFrame::<TestSchema>::try_new(df.clone()) {
+        Err(TypedPolarsError::TypeMismatch { column, expected, actual, .. }) => {
+            assert_eq!(column, "value");
+            assert_eq!(expected, DataType::Int32);
+            assert_eq!(actual, DataType::Float64);
+        }
+        other => panic!("expected a type mismatch, got {:?}", other.map(|_| ())),
+    }
+    
+    let missing = df.drop("name").unwrap().drop("value").unwrap()
+        .hstack(&[Series::new("value".into(), vec![10i32, 20, 30]).into_column()]).unwrap();
+    let err = TestSchema::check(&missing).unwrap_err().with_context("orders.csv");
+    assert_eq!(err.column(), Some("name"));
+    assert_eq!(err.to_string(), "orders.csv: Column 'name' not found in DataFrame");
+    
+    // PolarsResult APIs keep the matching Polars error kinds
+    assert!(matches!(TypedDataFrame::<TestSchema>::new(missing), Err(PolarsError::ColumnNotFound(_))));
+}
+
+#[cfg(feature = "diagnostics")]
+#[test]
+fn test_schema_report() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2]).into_column(),
+        Series::new("nmae".into(), vec!["a", "b"]).into_column(),
+        Series::new("value".into(), vec![1.5f64, 2.5]).into_column(),
+    ]).unwrap();
+    
+    let report = SchemaReport::check::<TestSchema>(&df).unwrap_err();
+    assert_eq!(report.issues, vec![
+        ColumnIssue::Missing { column: "name".to_string(), expected: DataType::String, suggestion: Some("nmae".to_string()) },
+        ColumnIssue::WrongType { column: "value".to_string(), expected: DataType::Int32, actual: DataType::Float64 },
+    ]);
+    assert!(report.table().contains("missing (did you mean `nmae`?)"));
+}
+
+#[test]
+fn test_mock_data() {
+    let default = TypedDataFrame::<TestSchema>::mock(50).unwrap();
+    assert_eq!(default.height(), 50);
+    assert!(default.inner().equals(TypedDataFrame::<TestSchema>::mock(50).unwrap().inner()));
+    
+    let custom = Mock::<TestSchema>::new()
+        .seed(7)
+        .range(TestSchema::value, 1.0..=6.0)
+        .pattern(TestSchema::name, "user-##")
+        .null_probability(TestSchema::id, 1.0)
+        .finish(100)
+        .unwrap();
+    let values = custom.inner().column("value").unwrap().i32().unwrap().clone();
+    assert!(values.into_no_null_iter().all(|v| (1..=6).contains(&v)));
+    let names = custom.inner().column("name").unwrap().str().unwrap().clone();
+    assert!(names.into_no_null_iter().all(|n| n.len() == 7 && n.starts_with("user-")));
+    assert_eq!(custom.inner().column("id").unwrap().null_count(), 100);
+}
+
+#[test]
+fn test_snapshot_string() {
+    let first = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64]).into_column(),
+        Series::new("name".into(), vec![Some("a \"quoted\" name")]).into_column(),
+        Series::new("value".into(), vec![10i32]).into_column(),
+    ]).unwrap();
+    let second = DataFrame::new(vec![
+        Series::new("id".into(), vec![2i64]).into_column(),
+        Series::new("name".into(), vec![None::<&str>]).into_column(),
+        Series::new("value".into(), vec![20i32]).into_column(),
+    ]).unwrap();
+    // Two chunks render the same as one
+    let typed_df = TypedDataFrame::<TestSchema>::new_lenient(first.vstack(&second).unwrap()).unwrap();
+    
+    assert_eq!(
+        typed_df.to_snapshot_string().unwrap(),
+        "shape: (2, 3)\nid: Int64 | name: String | value: Int32\n1 | \"a \\\"quoted\\\" name\" | 10\n2 | null | 20\n"
+    );
+}
+
+#[test]
+fn test_shared_frame_copy_on_write() {
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)]);
+    let mut shared = TypedDataFrame::<TestSchema>::new(df).unwrap().into_shared();
+    let snapshot = shared.clone();
+    assert!(shared.ptr_eq(&snapshot));
+    
+    let reader = std::thread::spawn(move || snapshot.height());
+    shared.update(|df| Ok(df.head(Some(1)))).unwrap();
+    
+    assert_eq!(reader.join().unwrap(), 3);
+    assert_eq!(shared.height(), 1);
+}
+
+#[test]
+fn test_validation_cache() {
+    let make = |values: [i32; 3]| test_df(&[(1, "a", values[0]), (2, "b", values[1]), (3, "c", values[2])]);
+    
+    let first = TypedDataFrame::<TestSchema>::new(make([10, 20, 30])).unwrap();
+    // Same layout, served from the cache
+    let second = TypedDataFrame::<TestSchema>::new(make([40, 50, 60])).unwrap();
+    assert!(second.revalidate().is_ok());
+    assert!(first.revalidate().is_ok());
+    
+    // A different layout is still validated
+    let mut wrong = make([10, 20, 30]);
+    wrong.with_column(Series::new("value".into(), vec![10i64, 20, 30])).unwrap();
+    assert!(TypedDataFrame::<TestSchema>::new(wrong).is_err());
+    
+    typed_polars::validation::clear_validation_cache();
+    assert!(TypedDataFrame::<TestSchema>::new(make([1, 2, 3])).is_ok());
+    
+    // Overridden validation sees every frame, whatever the cache holds
+    assert!(TypedDataFrame::<PositiveValues>::new(make([1, 2, 3]).select(["value"]).unwrap()).is_ok());
+    assert!(TypedDataFrame::<PositiveValues>::new(make([1, -2, 3]).select(["value"]).unwrap()).is_err());
+}
+
+/// A hand-written schema whose validation also checks the values
+struct PositiveValues;
+
+impl typed_polars::schema::Schema for PositiveValues {
+    type Columns = (Column<i32>,);
+    
+    fn schema() -> polars::prelude::Schema {
+        polars::prelude::Schema::from_iter(vec![polars::prelude::Field::new("value".into(), DataType::Int32)])
+    }
+    
+    fn column_names() -> Vec<&'static str> {
+        vec!["value"]
+    }
+    
+    fn validate(df: &DataFrame) -> PolarsResult<()> {
+        Self::check(df).map_err(PolarsError::from)?;
+        if df.column("value")?.i32()?.into_no_null_iter().any(|value| value <= 0) {
+            return Err(PolarsError::ComputeError("values must be positive".into()));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_all_mismatches_reported() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i32, 2]).into_column(),
+        Series::new("value".into(), vec![1.5f64, 2.5]).into_column(),
+    ]).unwrap();
+    
+    match TestSchema::check(&df) {
+        Err(TypedPolarsError::Multiple(errors)) => {
+            let columns: Vec<_> = errors.iter().map(|err| err.column().unwrap()).collect();
+            assert_eq!(columns, vec!["id", "name", "value"]);
+        }
+        other => panic!("expected several mismatches, got {:?}", other),
+    }
+}
+
+#[cfg(feature = "dtype-full")]
+typed_polars::define_schema! {
+    ExtendedSchema {
+        segment: typed_polars::schema::Categorical,
+        pending: typed_polars::schema::Null,
+    }
+}
+
+#[cfg(feature = "dtype-full")]
+#[test]
+fn test_extended_dtypes() {
+    let df = DataFrame::new(vec![
+        Series::new("segment".into(), vec!["a", "b"])
+            .cast(&DataType::Categorical(None, Default::default()))
+            .unwrap()
+            .into_column(),
+        Series::full_null("pending".into(), 2, &DataType::Null).into_column(),
+    ]).unwrap();
+    let typed = TypedDataFrame::<ExtendedSchema>::new(df).unwrap();
+    assert_eq!(typed.column(ExtendedSchema::segment).unwrap().len(), 2);
+    
+    let mock = TypedDataFrame::<ExtendedSchema>::mock(5).unwrap();
+    assert_eq!(mock.height(), 5);
+}
+
+typed_polars::define_schema! {
+    IdName {
+        id: i64,
+        name: String,
+    }
+}
+
+typed_polars::define_schema! {
+    NameTotal {
+        name: String,
+        value: i32,
+    }
+}
+
+#[test]
+fn test_column_tuples() {
+    let df = test_df(&[(3, "c", 30), (1, "a", 10), (2, "b", 20), (1, "a", 5)]);
+    let typed = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let selected: TypedDataFrame<IdName> = typed.select_as((TestSchema::id, TestSchema::name)).unwrap();
+    assert_eq!(selected.width(), 2);
+    
+    let (ids, names) = typed.columns((TestSchema::id, TestSchema::name)).unwrap();
+    assert_eq!(ids.len(), names.len());
+    
+    let sorted = typed.sort_by((TestSchema::id, TestSchema::value), false).unwrap();
+    assert_eq!(sorted.inner().column("value").unwrap().i32().unwrap().get(0), Some(5));
+    
+    let unique = typed.unique_by((TestSchema::id, TestSchema::name), UniqueKeepStrategy::First).unwrap();
+    assert_eq!(unique.height(), 3);
+    
+    let totals = typed
+        .group_by(TestSchema::name)
+        .agg::<NameTotal>([typed_polars::expr::col(TestSchema::value).sum().into_inner()])
+        .unwrap();
+    assert_eq!(totals.height(), 3);
+}
+
+#[test]
+fn test_sorted_witness() {
+    let df = test_df(&[(3, "c", 30), (1, "a", 10), (2, "b", 20)]);
+    let typed = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    assert!(Sorted::new(typed.clone(), TestSchema::value, false).is_err());
+    
+    let sorted = typed.sort(TestSchema::value, false).unwrap();
+    assert_eq!(sorted.column().name(), "value");
+    assert_eq!(sorted.search_sorted(20i32).unwrap(), 1);
+    assert_eq!(sorted.search_sorted(35i32).unwrap(), 3);
+    assert_eq!(sorted.search_sorted(20i64).unwrap(), 1);
+    assert!(sorted.search_sorted(i64::MAX).is_err());
+    
+    let rechecked = Sorted::new(sorted.into_inner(), TestSchema::value, false).unwrap();
+    assert_eq!(rechecked.height(), 3);
+}
+
+#[test]
+fn test_non_empty_witness() {
+    let df = test_df(&[(3, "c", 30), (1, "a", 10), (2, "b", 20)]);
+    let typed = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    assert!(typed.head(Some(0)).non_empty().is_err());
+    
+    let rows = typed.non_empty().unwrap();
+    assert_eq!(rows.height().get(), 3);
+    assert_eq!(rows.max(TestSchema::value).unwrap(), 30);
+    assert_eq!(rows.min(TestSchema::id).unwrap(), 1);
+    assert_eq!(rows.first(TestSchema::id).unwrap(), AnyValue::Int64(3));
+}
+
+#[test]
+fn test_keyed_witness() {
+    let df = test_df(&[(1, "a", 10), (2, "b", 20), (2, "b2", 21)]);
+    let typed = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    assert!(typed.clone().validate_keys(TestSchema::id).is_err());
+    
+    let keyed = typed.unique_by(TestSchema::id, UniqueKeepStrategy::First).unwrap();
+    assert_eq!(keyed.height(), 2);
+    
+    let updates = test_df(&[(2, "B", 200), (3, "c", 30)]);
+    let updates = TypedDataFrame::<TestSchema>::new(updates).unwrap().validate_keys(TestSchema::id).unwrap();
+    let upserted = keyed.upsert(&updates).unwrap();
+    assert_eq!(upserted.height(), 3);
+    assert_eq!(upserted.inner().column("value").unwrap().i32().unwrap().get(1), Some(200));
+}
+
+#[test]
+fn test_sequence_constructors() {
+    use typed_polars::chrono::NaiveDate;
+    
+    let index = TypedSeries::<i64>::arange("row", 0, 10, 3).unwrap();
+    assert_eq!(index.len(), 4);
+    let countdown = TypedSeries::<i32>::arange("n", 5, 0, -2).unwrap();
+    assert_eq!(countdown.len(), 3);
+    assert!(TypedSeries::<i64>::arange("row", 0, 10, 0).is_err());
+    
+    let start = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+    let end = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+    let months = TypedSeries::<NaiveDate>::date_range("month", start, end, "1mo").unwrap();
+    assert_eq!(months.len(), 12);
+    assert_eq!(months.inner().get(1).unwrap().to_string(), "2024-02-29");
+    
+    let days = TypedSeries::<NaiveDate>::date_range("day", start, end, "1d").unwrap();
+    assert_eq!(days.len(), 336);
+    assert!(TypedSeries::<NaiveDate>::date_range("day", start, end, "6h").is_err());
+}
+
+#[test]
+fn test_corr_and_cov_matrix() {
+    let df = test_df(&[(1, "a", 8), (2, "b", 6), (3, "c", 4), (4, "d", 2)]);
+    let typed = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let corr = typed.corr_matrix().unwrap();
+    assert_eq!(corr.columns(), &["id", "value"]);
+    assert!((corr.get(TestSchema::id, TestSchema::value).unwrap() + 1.0).abs() < 1e-12);
+    assert!(corr.get(TestSchema::id, TestSchema::name).is_none());
+    
+    let cov = typed.cov_matrix().unwrap();
+    assert!((cov.get(TestSchema::id, TestSchema::id).unwrap() - 5.0 / 3.0).abs() < 1e-12);
+    assert_eq!(cov.to_frame().unwrap().shape(), (2, 3));
+}
+
+typed_polars::define_schema! {
+    EncodedNames {
+        id: i64,
+        name_a: bool,
+        name_b: u8,
+    }
+}
+
+#[test]
+fn test_one_hot() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3]).into_column(),
+        Series::new("name".into(), vec![Some("a"), Some("b"), None]).into_column(),
+        Series::new("value".into(), vec![10i32, 20, 30]).into_column(),
+    ]).unwrap();
+    let typed = TypedDataFrame::<TestSchema>::new_lenient(df).unwrap();
+    
+    let encoded = typed.one_hot::<_, EncodedNames>(TestSchema::name).unwrap();
+    assert_eq!(encoded.width(), 3);
+    assert_eq!(encoded.inner().column("name_b").unwrap().u8().unwrap().get(1), Some(1));
+    assert_eq!(encoded.inner().column("name_a").unwrap().bool().unwrap().get(2), Some(false));
+    
+    let other = test_df(&[(4, "c", 40)]);
+    let other = TypedDataFrame::<TestSchema>::new(other).unwrap();
+    assert!(other.one_hot::<_, EncodedNames>(TestSchema::name).is_err());
+}
+
+#[test]
+fn test_target_and_hash_encoding() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3, 4]).into_column(),
+        Series::new("name".into(), vec![Some("a"), Some("a"), Some("b"), None]).into_column(),
+        Series::new("value".into(), vec![10i32, 20, 60, 30]).into_column(),
+    ]).unwrap();
+    let typed = TypedDataFrame::<TestSchema>::new_lenient(df).unwrap();
+    
+    let target = typed.target_encode(TestSchema::name, TestSchema::value, 0.0).unwrap();
+    assert_eq!(target.name(), "name_target");
+    let target = target.inner().f64().unwrap().clone();
+    assert_eq!(target.get(0), Some(15.0));
+    assert_eq!(target.get(2), Some(60.0));
+    
+    let smoothed = typed.target_encode(TestSchema::name, TestSchema::value, 2.0).unwrap();
+    assert_eq!(smoothed.inner().f64().unwrap().get(2), Some((60.0 + 2.0 * 30.0) / 3.0));
+    assert!(typed.target_encode(TestSchema::value, TestSchema::name, 1.0).is_err());
+    
+    let hashed = typed.hash_encode(TestSchema::name, 8).unwrap();
+    let buckets = hashed.inner().u32().unwrap().clone();
+    assert_eq!(buckets.get(0), buckets.get(1));
+    assert!(buckets.get(2).is_some_and(|bucket| bucket < 8));
+    assert_eq!(buckets.get(3), None);
+    assert!(typed.hash_encode(TestSchema::name, 0).is_err());
+}
+
+typed_polars::define_schema! {
+    Measurements {
+        id: i64,
+        height: f64,
+    }
+}
+
+#[test]
+fn test_scaler_fit_transform() {
+    let make = |heights: Vec<f64>| {
+        let ids: Vec<i64> = (0..heights.len() as i64).collect();
+        TypedDataFrame::<Measurements>::new(DataFrame::new(vec![
+            Series::new("id".into(), ids).into_column(),
+            Series::new("height".into(), heights).into_column(),
+        ]).unwrap()).unwrap()
+    };
+    let train = make(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    
+    let scaler = Scaler::fit(ScalingMethod::MinMax, &train).unwrap();
+    assert_eq!(scaler.columns().len(), 1);
+    let scaled = scaler.transform(&make(vec![3.0, 7.0])).unwrap();
+    let heights = scaled.inner().column("height").unwrap().f64().unwrap().clone();
+    assert_eq!(heights.get(0), Some(0.5));
+    assert_eq!(heights.get(1), Some(1.5));
+    
+    let restored = scaler.inverse_transform(&scaled).unwrap();
+    assert_eq!(restored.inner().column("height").unwrap().f64().unwrap().get(1), Some(7.0));
+    
+    let robust = Scaler::fit(ScalingMethod::Robust, &train).unwrap();
+    assert_eq!(robust.columns()[0].center, 3.0);
+    assert_eq!(robust.columns()[0].scale, 2.0);
+    assert!(Scaler::fit_columns(ScalingMethod::Standard, &train, Measurements::id).is_err());
+}
+
+#[test]
+fn test_approx_quantiles() {
+    let values: Vec<f64> = (1..=10_000).map(|v| v as f64).collect();
+    let series = TypedSeries::<f64>::from_vec("value", values);
+    
+    let median = series.approx_median().unwrap().unwrap();
+    assert!((median - 5000.0).abs() < 50.0);
+    let p99 = series.approx_quantile(0.99).unwrap().unwrap();
+    assert!((p99 - 9900.0).abs() < 20.0);
+    assert_eq!(series.approx_quantile(0.0).unwrap(), Some(1.0));
+    assert!(series.approx_quantile(1.5).is_err());
+    
+    let mut digest = TypedSeries::<f64>::from_vec("value", vec![1.0, 2.0]).digest().unwrap();
+    digest.merge(&TypedSeries::<f64>::from_vec("value", vec![3.0]).digest().unwrap());
+    assert_eq!(digest.count(), 3);
+    assert_eq!(digest.quantile(1.0), Some(3.0));
+    assert_eq!(TDigest::default().quantile(0.5), None);
+    
+    // Merging keeps the extremes, not just the centroid means
+    let mut merged = TDigest::default();
+    merged.merge(&series.digest().unwrap());
+    assert_eq!(merged.quantile(0.0), Some(1.0));
+    assert_eq!(merged.quantile(1.0), Some(10_000.0));
+    
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3]).into_column(),
+        Series::new("height".into(), vec![1.0, 2.0, 3.0]).into_column(),
+    ]).unwrap();
+    let median = df.lazy().select([typed_polars::expr::col(Measurements::height).approx_median().into_inner()]).collect().unwrap();
+    assert_eq!(median.column("height").unwrap().f64().unwrap().get(0), Some(2.0));
+    assert!(typed_polars::expr::col(Measurements::height).approx_quantile(-0.1).is_err());
+}
+
+#[test]
+fn test_streaming_aggregator() {
+    let batch = |ids: Vec<i64>, heights: Vec<Option<f64>>| {
+        TypedDataFrame::<Measurements>::new_lenient(DataFrame::new(vec![
+            Series::new("id".into(), ids).into_column(),
+            Series::new("height".into(), heights).into_column(),
+        ]).unwrap()).unwrap()
+    };
+    let mut aggregator = TypedAggregator::<Measurements>::new();
+    aggregator.update(&batch(vec![1, 2], vec![Some(1.0), None])).unwrap();
+    let mut other = TypedAggregator::<Measurements>::new();
+    other.update(&batch(vec![3, 4], vec![Some(3.0), Some(5.0)])).unwrap();
+    aggregator.merge(&other);
+    
+    let summary = aggregator.finalize();
+    assert_eq!(summary.rows(), 4);
+    assert_eq!(summary.count(Measurements::height), 3);
+    assert_eq!(summary.null_count(Measurements::height), 1);
+    assert_eq!(summary.sum(Measurements::id), Some(10.0));
+    assert_eq!(summary.mean(Measurements::height), Some(3.0));
+    assert_eq!(summary.min(Measurements::height), Some(1.0));
+    assert_eq!(summary.max(Measurements::height), Some(5.0));
+    assert_eq!(summary.quantile(Measurements::height, 0.5), Some(3.0));
+    assert_eq!(summary.to_frame().unwrap().height(), 2);
+}
+
+typed_polars::define_schema! {
+    Clicks {
+        user: String,
+        time: typed_polars::chrono::NaiveDateTime,
+    }
+}
+
+#[test]
+fn test_sessionize() {
+    let minute = 60_000_000i64;
+    let times = Series::new("time".into(), vec![Some(0), Some(50 * minute), Some(10 * minute), Some(5 * minute), None])
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+        .unwrap();
+    let df = DataFrame::new(vec![
+        Series::new("user".into(), vec!["a", "a", "a", "b", "b"]).into_column(),
+        times.into_column(),
+    ]).unwrap();
+    let clicks = TypedDataFrame::<Clicks>::new_lenient(df).unwrap();
+    
+    let sessions = clicks.sessionize(Clicks::user, Clicks::time, std::time::Duration::from_secs(30 * 60)).unwrap();
+    assert_eq!(sessions.name(), "session_id");
+    let sessions: Vec<Option<u32>> = sessions.inner().u32().unwrap().into_iter().collect();
+    assert_eq!(sessions, vec![Some(0), Some(1), Some(0), Some(2), None]);
+    assert!(clicks.sessionize(Clicks::time, Clicks::user, std::time::Duration::from_secs(60)).is_err());
+}
+
+#[test]
+fn test_frame_diff() {
+    let frame = |ids: Vec<i64>, names: Vec<Option<&str>>, values: Vec<i32>| {
+        TypedDataFrame::<TestSchema>::new_lenient(DataFrame::new(vec![
+            Series::new("id".into(), ids).into_column(),
+            Series::new("name".into(), names).into_column(),
+            Series::new("value".into(), values).into_column(),
+        ]).unwrap()).unwrap()
+    };
+    let old = frame(vec![1, 2, 3], vec![Some("a"), Some("b"), None], vec![10, 20, 30]);
+    let new = frame(vec![4, 3, 2], vec![Some("d"), None, Some("b")], vec![40, 30, 25]);
+    
+    let diff = old.diff(&new, TestSchema::id).unwrap();
+    assert_eq!(diff.inserted().column(TestSchema::id).unwrap().inner().i64().unwrap().get(0), Some(4));
+    assert_eq!(diff.deleted().column(TestSchema::id).unwrap().inner().i64().unwrap().get(0), Some(1));
+    assert_eq!(diff.changed_before().height(), 1);
+    assert_eq!(diff.changed_after().column(TestSchema::value).unwrap().inner().i32().unwrap().get(0), Some(25));
+    
+    let changes = diff.changes().unwrap();
+    assert_eq!(changes.height(), 1);
+    assert_eq!(changes.column("column").unwrap().str().unwrap().get(0), Some("value"));
+    assert_eq!(changes.column("before").unwrap().str().unwrap().get(0), Some("20"));
+    
+    assert!(old.diff(&old, TestSchema::id).unwrap().is_empty());
+    let duplicated = frame(vec![1, 1], vec![None, None], vec![1, 2]);
+    assert!(old.diff(&duplicated, TestSchema::id).is_err());
+    
+    // Null keys match each other rather than showing as a delete and an insert
+    let null_keys = |ids: Vec<Option<i64>>, values: Vec<i32>| {
+        TypedDataFrame::<TestSchema>::new_lenient(DataFrame::new(vec![
+            Series::new("id".into(), ids).into_column(),
+            Series::new("name".into(), vec!["a", "b"]).into_column(),
+            Series::new("value".into(), values).into_column(),
+        ]).unwrap()).unwrap()
+    };
+    let diff = null_keys(vec![None, Some(1)], vec![10, 20])
+        .diff(&null_keys(vec![Some(1), None], vec![20, 15]), TestSchema::id)
+        .unwrap();
+    assert_eq!(diff.inserted().height(), 0);
+    assert_eq!(diff.deleted().height(), 0);
+    assert_eq!(diff.changed_after().column(TestSchema::value).unwrap().inner().i32().unwrap().get(0), Some(15));
+}
+
+typed_polars::define_schema! {
+    People {
+        name: String,
+        #[range = 0 ..= 130]
+        age: i32,
+    }
+}
+
+#[test]
+fn test_profile() {
+    let df = DataFrame::new(vec![
+        Series::new("name".into(), vec![Some("ann"), Some("bob"), Some("ann"), None]).into_column(),
+        Series::new("age".into(), vec![Some(30), Some(-1), Some(150), None]).into_column(),
+    ]).unwrap();
+    let people = TypedDataFrame::<People>::new_lenient(df).unwrap();
+    
+    let report = people.profile().unwrap();
+    assert_eq!(report.rows, 4);
+    let name = report.get(People::name).unwrap();
+    assert_eq!(name.null_fraction(), 0.25);
+    assert_eq!(name.distinct_count, Some(3));
+    assert_eq!(name.top_values[0], ("ann".to_string(), 2));
+    let age = report.get(People::age).unwrap();
+    assert_eq!(age.min, Some(AnyValue::Int32(-1)));
+    assert_eq!(age.max, Some(AnyValue::Int32(150)));
+    assert_eq!(age.range, Some((0.0, 130.0)));
+    assert_eq!(age.out_of_range, 2);
+    assert!(report.has_out_of_range());
+    assert!(report.table().lines().nth(2).unwrap().starts_with("age"));
+}
+
+#[test]
+fn test_expectation_suite() {
+    let df = DataFrame::new(vec![
+        Series::new("name".into(), vec![Some("ann"), Some("bob"), Some("ann"), None]).into_column(),
+        Series::new("age".into(), vec![Some(30), Some(-1), Some(150), None]).into_column(),
+    ]).unwrap();
+    let people = TypedDataFrame::<People>::new_lenient(df).unwrap();
+    
+    let suite = ExpectationSuite::<People>::new()
+        .expect(expect(People::age).between(0, 130))
+        .expect(expect(People::name).unique())
+        .expect(expect(People::name).not_null())
+        .expect(expect_row_count().between(1, 10))
+        .expect(expect_row_count().gt(1000));
+    let report = suite.run(&people).unwrap();
+    assert!(!report.success());
+    let observed: Vec<(bool, usize)> = report.results.iter().map(|result| (result.success, result.observed)).collect();
+    assert_eq!(observed, vec![(false, 2), (false, 2), (false, 1), (true, 4), (false, 4)]);
+    assert_eq!(report.failures().count(), 4);
+    assert!(report.table().starts_with("FAIL  age between 0 and 130"));
+    assert!(suite.validate(&people).is_err());
+    
+    let passing = ExpectationSuite::<People>::new().expect(expect_row_count().lt(5));
+    assert!(passing.validate(&people).is_ok());
+}
+
+typed_polars::define_schema! {
+    Patients {
+        #[pii(hash)]
+        patient_id: String,
+        #[pii(truncate = 3)]
+        zip: String,
+        #[range = 0 ..= 130]
+        #[pii(bucket = 10)]
+        age: i32,
+        #[pii(redact)]
+        notes: String,
+        diagnosis: String,
+    }
+}
+
+#[test]
+fn test_anonymize() {
+    let df = DataFrame::new(vec![
+        Series::new("patient_id".into(), vec!["p1", "p1", "p2"]).into_column(),
+        Series::new("zip".into(), vec!["10115", "10117", "80331"]).into_column(),
+        Series::new("age".into(), vec![34, 39, 71]).into_column(),
+        Series::new("notes".into(), vec![Some("allergic"), None, Some("smoker")]).into_column(),
+        Series::new("diagnosis".into(), vec!["flu", "flu", "cold"]).into_column(),
+    ]).unwrap();
+    let patients = TypedDataFrame::<Patients>::new_lenient(df).unwrap();
+    
+    let shared = patients.anonymize(&[7; 32]).unwrap();
+    let ids = shared.inner().column("patient_id").unwrap().str().unwrap().clone();
+    assert_eq!(ids.get(0), ids.get(1));
+    assert_ne!(ids.get(0), ids.get(2));
+    assert_ne!(ids.get(0), Some("p1"));
+    assert_eq!(shared.inner().column("zip").unwrap().str().unwrap().get(1), Some("101"));
+    let ages: Vec<Option<i32>> = shared.inner().column("age").unwrap().i32().unwrap().into_iter().collect();
+    assert_eq!(ages, vec![Some(30), Some(30), Some(70)]);
+    assert_eq!(shared.inner().column("notes").unwrap().str().unwrap().get(0), Some("[REDACTED]"));
+    assert_eq!(shared.inner().column("diagnosis").unwrap().str().unwrap().get(2), Some("cold"));
+    
+    assert_eq!(ids.get(0).unwrap().len(), 64);
+    
+    let rekeyed = patients.anonymize(&[8; 32]).unwrap();
+    assert_ne!(rekeyed.inner().column("patient_id").unwrap().str().unwrap().get(0), ids.get(0));
+}
+
+#[test]
+fn test_reservoir_sampler() {
+    let batch = |ids: std::ops::Range<i64>| {
+        let heights: Vec<f64> = ids.clone().map(|id| id as f64).collect();
+        TypedDataFrame::<Measurements>::new(DataFrame::new(vec![
+            Series::new("id".into(), ids.collect::<Vec<_>>()).into_column(),
+            Series::new("height".into(), heights).into_column(),
+        ]).unwrap()).unwrap()
+    };
+    let mut sampler = ReservoirSampler::<Measurements>::new(3).seed(7);
+    assert_eq!(sampler.sample().unwrap().height(), 0);
+    sampler.update(&batch(0..2)).unwrap();
+    assert_eq!(sampler.sample().unwrap().height(), 2);
+    sampler.update(&batch(2..10)).unwrap();
+    assert_eq!(sampler.seen(), 10);
+    let sample = sampler.sample().unwrap();
+    let mut ids: Vec<i64> = sample.inner().column("id").unwrap().i64().unwrap().into_no_null_iter().collect();
+    ids.sort();
+    ids.dedup();
+    assert_eq!(ids.len(), 3);
+    assert!(ids.iter().all(|id| (0..10).contains(id)));
+    
+    let (mut first, mut last) = (0, 0);
+    for seed in 0..1000 {
+        let mut sampler = ReservoirSampler::<Measurements>::new(3).seed(seed);
+        sampler.update(&batch(0..10)).unwrap();
+        let sample = sampler.sample().unwrap();
+        let ids = sample.inner().column("id").unwrap().i64().unwrap().clone();
+        first += ids.into_no_null_iter().filter(|id| *id == 0).count();
+        last += ids.into_no_null_iter().filter(|id| *id == 9).count();
+    }
+    assert!((200..400).contains(&first), "row 0 sampled {} times", first);
+    assert!((200..400).contains(&last), "row 9 sampled {} times", last);
+}
+
+typed_polars::define_schema! {
+    Tariffs {
+        id: i64,
+        valid_from: f64,
+        valid_to: f64,
+    }
+}
+
+typed_polars::define_schema! {
+    PricedMeasurements {
+        id: i64,
+        height: f64,
+        id_right: i64,
+    }
+}
+
+#[test]
+fn test_join_between() {
+    let measurements = TypedDataFrame::<Measurements>::new_lenient(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3, 4]).into_column(),
+        Series::new("height".into(), vec![Some(5.0), Some(10.0), None, Some(50.0)]).into_column(),
+    ]).unwrap()).unwrap();
+    let tariffs = TypedDataFrame::<Tariffs>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![100i64, 200]).into_column(),
+        Series::new("valid_from".into(), vec![0.0, 10.0]).into_column(),
+        Series::new("valid_to".into(), vec![10.0, 20.0]).into_column(),
+    ]).unwrap()).unwrap();
+    
+    let joined: TypedDataFrame<PricedMeasurements> = measurements
+        .join_between(&tariffs, Measurements::height, Tariffs::valid_from, Tariffs::valid_to)
+        .unwrap();
+    let pairs: Vec<(i64, i64)> = joined.inner().column("id").unwrap().i64().unwrap().into_no_null_iter()
+        .zip(joined.inner().column("id_right").unwrap().i64().unwrap().into_no_null_iter())
+        .collect();
+    assert_eq!(pairs, vec![(1, 100), (2, 100), (2, 200)]);
+}
+
+typed_polars::define_schema! {
+    MeasuredTariffs {
+        id: i64,
+        height: f64,
+        valid_from: f64,
+    }
+}
+
+#[test]
+fn test_merge_join() {
+    let measurements = TypedDataFrame::<Measurements>::new_lenient(DataFrame::new(vec![
+        Series::new("id".into(), vec![None, Some(1i64), Some(2), Some(2), Some(4)]).into_column(),
+        Series::new("height".into(), vec![0.0, 1.0, 2.0, 2.5, 4.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let tariffs = TypedDataFrame::<Tariffs>::new_lenient(DataFrame::new(vec![
+        Series::new("id".into(), vec![None, Some(2i64), Some(3), Some(4)]).into_column(),
+        Series::new("valid_from".into(), vec![0.0, 20.0, 30.0, 40.0]).into_column(),
+        Series::new("valid_to".into(), vec![0.0, 21.0, 31.0, 41.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let measurements = Sorted::new(measurements, Measurements::id, false).unwrap();
+    let tariffs = Sorted::new(tariffs, Tariffs::id, false).unwrap();
+    
+    let joined: TypedDataFrame<MeasuredTariffs> = measurements.merge_join(&tariffs).unwrap();
+    let heights: Vec<f64> = joined.inner().column("height").unwrap().f64().unwrap().into_no_null_iter().collect();
+    let starts: Vec<f64> = joined.inner().column("valid_from").unwrap().f64().unwrap().into_no_null_iter().collect();
+    assert_eq!(heights, vec![2.0, 2.5, 4.0]);
+    assert_eq!(starts, vec![20.0, 20.0, 40.0]);
+    assert!(joined.inner().column("id_right").is_err());
+}
+
+#[cfg(feature = "streaming")]
+#[test]
+fn test_collect_streaming() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![3i64, 1, 2]).into_column(),
+        Series::new("height".into(), vec![3.0, 1.0, 2.0]).into_column(),
+    ]).unwrap();
+    let sorted = || TypedLazyFrame::<Measurements>::new(df.clone().lazy().sort(["id"], Default::default()));
+    
+    let streamed = sorted().collect_streaming().unwrap();
+    assert_eq!(streamed.inner(), &df.sort(["id"], Default::default()).unwrap());
+}
+
+#[test]
+fn test_config() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![3i64, 1, 2]).into_column(),
+        Series::new("height".into(), vec![3.0, 1.0, 2.0]).into_column(),
+    ]).unwrap();
+    let config = Config::new().max_threads(0).chunk_size(2);
+    assert_eq!(config.threads(), Some(1));
+    assert_eq!(config.chunk_size_hint(), Some(2));
+    assert_eq!(Config::new().max_threads(8).sequential().threads(), Some(1));
+    assert_eq!(Config::new().threads(), None);
+    
+    // Bounds the global pool meets run on it, tighter ones on a pool of their own
+    let pool = polars_core::POOL.current_num_threads();
+    let installed = Config::new().max_threads(pool);
+    assert!(installed.install_global().is_ok());
+    let on_pool = installed.run(|| Ok(polars_core::POOL.current_thread_index().is_some())).unwrap();
+    assert!(on_pool);
+    let validated = installed.run(|| TypedDataFrame::<Measurements>::new(df.clone())).unwrap();
+    assert_eq!(validated.height(), 3);
+    let threads = Config::new().sequential().run(|| Ok(rayon::current_num_threads())).unwrap();
+    assert_eq!(threads, 1);
+    assert!(Config::new().max_threads(pool + 1).install_global().is_err());
+    
+    let sorted = TypedLazyFrame::<Measurements>::new(df.clone().lazy().sort(["id"], Default::default()));
+    let collected = sorted.clone().collect_with(&installed).unwrap();
+    assert_eq!(collected.inner(), &df.sort(["id"], Default::default()).unwrap());
+    assert!(sorted.collect_with(&config).is_err());
+}
+
+#[test]
+fn test_plan_description() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![3i64, 1, 2]).into_column(),
+        Series::new("height".into(), vec![3.0, 1.0, 2.0]).into_column(),
+    ]).unwrap();
+    let tall = TypedLazyFrame::<Measurements>::new(df.clone().lazy())
+        .filter(typed_polars::expr::col(Measurements::height).gt(typed_polars::expr::lit(1.5)));
+    
+    let description = tall.describe_optimized_plan().unwrap();
+    assert!(description.starts_with("OUTPUT Measurements {id: i64, height: f64}\n"));
+    assert!(description.contains("FILTER") || description.contains("SELECTION"));
+    assert!(tall.describe_plan().unwrap().starts_with("OUTPUT Measurements"));
+    
+    let dot = tall.to_dot().unwrap();
+    assert!(dot.starts_with("digraph plan {"));
+    assert!(dot.contains("output [label=\"Measurements {id: i64, height: f64}\", style=bold];"));
+    assert!(dot.contains("n0 -> output;"));
+    
+    let mismatched = TypedLazyFrame::<Measurements>::new(df.lazy().select([polars::prelude::col("id")]));
+    assert!(mismatched.to_dot().is_err());
+}
+
+#[test]
+fn test_lazy_profile() {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![3i64, 1, 2]).into_column(),
+        Series::new("height".into(), vec![3.0, 1.0, 2.0]).into_column(),
+    ]).unwrap();
+    let sorted = TypedLazyFrame::<Measurements>::new(df.clone().lazy().sort(["id"], Default::default()));
+    
+    let (result, timings) = sorted.profile().unwrap();
+    assert_eq!(result.inner(), &df.sort(["id"], Default::default()).unwrap());
+    assert!(timings.height() > 0);
+    assert!(timings.node_time("").unwrap() <= timings.total().unwrap() * timings.height() as u32);
+    assert!(timings.node_time("no such node").unwrap().is_zero());
+}
+
+typed_polars::define_schema! {
+    /// Registered users, one row per account
+    #[version = 2]
+    Accounts {
+        id: i64,
+        /// Display name,
+        /// as entered at sign-up
+        #[pii(truncate = 1)]
+        name: String,
+        /// Age in whole years
+        #[range = 0 ..= 130]
+        age: i32,
+    }
+}
+
+#[test]
+fn test_data_dictionary() {
+    let dictionary = Accounts::data_dictionary();
+    assert_eq!(dictionary.name, "Accounts");
+    assert_eq!(dictionary.description, "Registered users, one row per account");
+    assert_eq!(dictionary.version, 2);
+    assert_eq!(dictionary.columns.len(), 3);
+    assert_eq!(dictionary.columns[0].description, "");
+    assert_eq!(dictionary.columns[1].description, "Display name,\nas entered at sign-up");
+    assert_eq!(dictionary.columns[1].constraints, vec!["pii(truncate = 1)"]);
+    assert_eq!(dictionary.columns[2].constraints, vec!["range = 0 ..= 130"]);
+    
+    let markdown = dictionary.to_markdown();
+    assert!(markdown.starts_with("# Accounts\n\nRegistered users, one row per account\n\nVersion 2\n"));
+    assert!(markdown.contains("| `name` | str | pii(truncate = 1) | Display name,<br>as entered at sign-up |"));
+    assert!(dictionary.to_html().contains("<tr><td><code>age</code></td><td>i32</td><td>range = 0 ..= 130</td><td>Age in whole years</td></tr>"));
+    let json = dictionary.to_json();
+    assert!(json.starts_with("{\"name\":\"Accounts\",\"description\":\"Registered users, one row per account\",\"version\":2,"));
+    assert!(json.contains("{\"name\":\"name\",\"dtype\":\"str\",\"constraints\":[\"pii(truncate = 1)\"],\"description\":\"Display name,\\nas entered at sign-up\"}"));
+}
+
+#[cfg(feature = "validator")]
+#[test]
+fn test_validate_rows() {
+    #[derive(serde::Deserialize, validator::Validate)]
+    struct Row {
+        #[validate(length(min = 2, message = "name too short"))]
+        name: String,
+        #[validate(range(min = 0, max = 100))]
+        value: i32,
+    }
+    
+    let df = test_df(&[(1, "ab", 10), (2, "c", 20), (3, "d", 200)]);
+    let typed_df = TypedDataFrame::<TestSchema>::new(df).unwrap();
+    
+    let violations = typed_df.validate_rows::<Row>().unwrap();
+    let summary: Vec<(usize, &str, &str)> = violations
+        .iter()
+        .map(|violation| (violation.row, violation.field.as_str(), violation.reason.as_str()))
+        .collect();
+    assert_eq!(summary, vec![
+        (1, "name", "name too short"),
+        (2, "name", "name too short"),
+        (2, "value", "range"),
+    ]);
+}
+
+#[test]
+fn test_apply_cdc() {
+    let state = TypedDataFrame::<Measurements>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3]).into_column(),
+        Series::new("height".into(), vec![1.0, 2.0, 3.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let events = TypedDataFrame::<CdcSchema<Measurements>>::new(DataFrame::new(vec![
+        Series::new("op".into(), vec!["update", "delete", "insert", "update", "delete", "insert"]).into_column(),
+        Series::new("id".into(), vec![1i64, 2, 4, 1, 5, 5]).into_column(),
+        Series::new("height".into(), vec![1.5, 0.0, 4.0, 1.75, 0.0, 5.0]).into_column(),
+    ]).unwrap()).unwrap();
+    
+    let keyed = state.clone().validate_keys(Measurements::id).unwrap();
+    let applied = keyed.apply_cdc(&events).unwrap();
+    let ids: Vec<i64> = applied.inner().column("id").unwrap().i64().unwrap().into_no_null_iter().collect();
+    let heights: Vec<f64> = applied.inner().column("height").unwrap().f64().unwrap().into_no_null_iter().collect();
+    assert_eq!(ids, vec![3, 4, 1, 5]);
+    assert_eq!(heights, vec![3.0, 4.0, 1.75, 5.0]);
+    assert_eq!(applied.apply_cdc(&events).unwrap().inner(), applied.inner());
+    assert_eq!(state.apply_cdc(&events, Measurements::id).unwrap().inner(), applied.inner());
+    
+    let unknown = TypedDataFrame::<CdcSchema<Measurements>>::new(DataFrame::new(vec![
+        Series::new("op".into(), vec![CdcOp::Insert.as_str(), "upsert"]).into_column(),
+        Series::new("id".into(), vec![6i64, 7]).into_column(),
+        Series::new("height".into(), vec![6.0, 7.0]).into_column(),
+    ]).unwrap()).unwrap();
+    assert!(state.apply_cdc(&unknown, Measurements::id).is_err());
+}
+
+typed_polars::define_schema! {
+    Responses {
+        region: String,
+        answer: String,
+        amount: f64,
+    }
+}
+
+#[test]
+fn test_crosstab() {
+    let responses = TypedDataFrame::<Responses>::new_lenient(DataFrame::new(vec![
+        Series::new("region".into(), vec![Some("north"), Some("south"), Some("north"), Some("north"), None]).into_column(),
+        Series::new("answer".into(), vec!["yes", "no", "no", "yes", "yes"]).into_column(),
+        Series::new("amount".into(), vec![Some(1.0), Some(2.0), Some(3.0), None, Some(5.0)]).into_column(),
+    ]).unwrap()).unwrap();
+    
+    let counts = responses.crosstab(Responses::region, Responses::answer, CrosstabValue::count()).unwrap();
+    assert_eq!(counts.rows(), &["north", "south", "null"]);
+    assert_eq!(counts.columns(), &["no", "yes"]);
+    assert_eq!(counts.get("north", "yes"), Some(2.0));
+    assert_eq!(counts.get("south", "yes"), Some(0.0));
+    assert_eq!(counts.get("east", "yes"), None);
+    
+    let sums = responses.crosstab(Responses::region, Responses::answer, CrosstabValue::sum(Responses::amount)).unwrap();
+    assert_eq!(sums.get("north", "yes"), Some(1.0));
+    assert_eq!(sums.get("null", "yes"), Some(5.0));
+    
+    let shares = counts.normalize(Normalize::Rows);
+    assert!((shares.get("north", "yes").unwrap() - 2.0 / 3.0).abs() < 1e-12);
+    assert_eq!(counts.normalize(Normalize::Columns).get("south", "no"), Some(0.5));
+    assert_eq!(counts.normalize(Normalize::Total).get("null", "yes"), Some(0.2));
+    
+    let frame = counts.to_frame().unwrap();
+    assert_eq!(frame.get_column_names_str(), vec!["region", "no", "yes"]);
+    assert_eq!(frame.column("yes").unwrap().f64().unwrap().get(0), Some(2.0));
+}
+
+typed_polars::define_schema! {
+    BinnedMeasurements {
+        id: i64,
+        height: Option<f64>,
+        height_bin: Option<String>,
+    }
+}
+
+#[test]
+fn test_cut_and_qcut() {
+    let measurements = TypedDataFrame::<Measurements>::new_lenient(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3, 4, 5]).into_column(),
+        Series::new("height".into(), vec![Some(1.0), Some(2.0), None, Some(3.5), Some(f64::NAN)]).into_column(),
+    ]).unwrap()).unwrap();
+    let bins = |df: &TypedDataFrame<BinnedMeasurements>| -> Vec<Option<String>> {
+        df.inner().column("height_bin").unwrap().str().unwrap().into_iter().map(|bin| bin.map(String::from)).collect()
+    };
+    
+    let labelled: TypedDataFrame<BinnedMeasurements> = measurements
+        .cut(Measurements::height, &[1.0, 3.0], &["low", "mid", "high"])
+        .unwrap();
+    assert_eq!(bins(&labelled), vec![Some(String::from("low")), Some(String::from("mid")), None, Some(String::from("high")), None]);
+    
+    let intervals: TypedDataFrame<BinnedMeasurements> = measurements.cut(Measurements::height, &[2.0], &[]).unwrap();
+    assert_eq!(bins(&intervals)[..2], [Some(String::from("(-inf, 2]")), Some(String::from("(-inf, 2]"))]);
+    assert_eq!(bins(&intervals)[3], Some(String::from("(2, inf]")));
+    assert!(measurements.cut::<f64, BinnedMeasurements>(Measurements::height, &[2.0, 1.0], &[]).is_err());
+    assert!(measurements.cut::<f64, BinnedMeasurements>(Measurements::height, &[2.0], &["one"]).is_err());
+    
+    let halves: TypedDataFrame<BinnedMeasurements> = measurements.qcut(Measurements::height, 2).unwrap();
+    assert_eq!(bins(&halves)[..2], [Some(String::from("(-inf, 2]")), Some(String::from("(-inf, 2]"))]);
+    assert_eq!(bins(&halves)[3], Some(String::from("(2, inf]")));
+}
+
+/// Customers of the shop
+#[cfg(feature = "derive")]
+#[derive(Schema, Debug, PartialEq)]
+#[schema(version = 3, row)]
+struct Customer {
+    id: i64,
+    /// Contact address
+    #[schema(rename = "email_address", pii(hash))]
+    email: String,
+    #[schema(range = 0 ..= 130)]
+    age: i32,
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_schema() {
+    let rows = || vec![
+        Customer { id: 1, email: "ada@example.com".to_string(), age: 36 },
+        Customer { id: 2, email: "alan@example.com".to_string(), age: 41 },
+    ];
+    let customers = TypedDataFrame::from_rows(rows()).unwrap();
+    assert_eq!(Customer::column_names(), vec!["id", "email_address", "age"]);
+    assert_eq!(customers.inner().column("email_address").unwrap().dtype(), &DataType::String);
+    assert_eq!(customers.column(Customer::age).unwrap().len(), 2);
+    assert_eq!(customers.to_rows().unwrap(), rows());
+    
+    let dictionary = Customer::data_dictionary();
+    assert_eq!(dictionary.description, "Customers of the shop");
+    assert_eq!(dictionary.version, 3);
+    assert_eq!(dictionary.columns[1].constraints, vec!["pii(hash)"]);
+    assert_eq!(dictionary.columns[1].description, "Contact address");
+    assert_eq!(dictionary.columns[2].constraints, vec!["range = 0 ..= 130"]);
+    
+    let with_null = TypedDataFrame::<Customer>::new_lenient(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64]).into_column(),
+        Series::new("email_address".into(), vec!["ada@example.com"]).into_column(),
+        Series::new("age".into(), vec![None::<i32>]).into_column(),
+    ]).unwrap()).unwrap();
+    assert!(with_null.to_rows().is_err());
+}
+
+typed_polars::define_schema! {
+    Contacts {
+        id: i64,
+        phone: Option<String>,
+    }
+}
+
+#[test]
+fn test_optional_columns() {
+    let contacts = |ids: Vec<Option<i64>>| {
+        DataFrame::new(vec![
+            Series::new("id".into(), ids).into_column(),
+            Series::new("phone".into(), vec![Some("555-0100"), None]).into_column(),
+        ]).unwrap()
+    };
+    assert_eq!(Contacts::nullable_columns(), vec!["phone"]);
+    assert_eq!(Contacts::schema().get("phone"), Some(&DataType::String));
+    
+    let complete = TypedDataFrame::<Contacts>::new(contacts(vec![Some(1), Some(2)])).unwrap();
+    let phones = complete.column(Contacts::phone).unwrap();
+    assert_eq!(phones.null_count(), 1);
+    assert_eq!(phones.drop_nulls().len(), 1);
+    assert!(phones.require_non_null().is_err());
+    
+    let missing_ids = contacts(vec![Some(1), None]);
+    assert!(TypedDataFrame::<Contacts>::new(missing_ids.clone()).is_err());
+    assert!(matches!(
+        TypedDataFrame::<Contacts>::try_new(missing_ids.clone()),
+        Err(TypedPolarsError::UnexpectedNulls { .. })
+    ));
+    assert!(TypedDataFrame::<Contacts>::new_lenient(missing_ids.clone()).is_ok());
+    match Contacts::check_nulls(&missing_ids) {
+        Err(TypedPolarsError::UnexpectedNulls { column, count, .. }) => assert_eq!((column.as_str(), count), ("id", 1)),
+        other => panic!("expected an UnexpectedNulls error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_typed_lazy_query() {
+    let df = TypedDataFrame::<TestSchema>::new(test_df(&[(1, "a", 10), (2, "b", 30), (3, "c", 20)])).unwrap();
+    
+    let summary: TypedDataFrame<TestSummary> = df
+        .clone()
+        .lazy()
+        .filter(col(TestSchema::value).gt(typed_polars::expr::lit(15)))
+        .sort(TestSchema::value, true)
+        .select((TestSchema::id, TestSchema::value))
+        .collect()
+        .unwrap();
+    let ids: Vec<i64> = summary.inner().column("id").unwrap().i64().unwrap().into_no_null_iter().collect();
+    assert_eq!(ids, vec![2, 3]);
+    assert_eq!(summary.width(), 2);
+    
+    let doubled: TypedDataFrame<TestSchema> = df
+        .lazy()
+        .with_column(TestSchema::value, col(TestSchema::value).mul(typed_polars::expr::lit(2)))
+        .collect()
+        .unwrap();
+    let values: Vec<i32> = doubled.inner().column("value").unwrap().i32().unwrap().into_no_null_iter().collect();
+    assert_eq!(values, vec![20, 60, 40]);
+}
+
+typed_polars::define_schema! {
+    Orders {
+        order_id: i64,
+        customer_id: i64,
+        amount: f64,
+    }
+}
+
+typed_polars::define_schema! {
+    Customers {
+        id: i64,
+        region: String,
+    }
+}
+
+#[test]
+fn test_typed_join() {
+    let orders = TypedDataFrame::<Orders>::new(DataFrame::new(vec![
+        Series::new("order_id".into(), vec![1i64, 2, 3]).into_column(),
+        Series::new("customer_id".into(), vec![10i64, 20, 30]).into_column(),
+        Series::new("amount".into(), vec![5.0, 7.5, 2.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let customers = TypedDataFrame::<Customers>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![10i64, 20, 40]).into_column(),
+        Series::new("region".into(), vec!["north", "south", "west"]).into_column(),
+    ]).unwrap()).unwrap();
+    assert_eq!(Joined::<Orders, Customers>::column_names(), vec!["order_id", "customer_id", "amount", "id", "region"]);
+    assert_eq!(Joined::<Orders, Customers>::nullable_columns(), vec!["id", "region"]);
+    assert_eq!(OuterJoined::<Orders, Customers>::nullable_columns(), OuterJoined::<Orders, Customers>::column_names());
+    
+    let sorted_ids = |df: &DataFrame, column: &str| -> Vec<i64> {
+        let mut ids: Vec<i64> = df.column(column).unwrap().i64().unwrap().into_no_null_iter().collect();
+        ids.sort();
+        ids
+    };
+    let inner = orders.join(&customers, Orders::customer_id, Customers::id, JoinKind::Inner).unwrap();
+    assert_eq!(sorted_ids(inner.inner(), "customer_id"), vec![10, 20]);
+    assert_eq!(inner.width(), 5);
+    
+    let left = orders.join(&customers, Orders::customer_id, Customers::id, JoinKind::Left).unwrap();
+    assert_eq!(left.height(), 3);
+    assert_eq!(left.column(Customers::region).unwrap().inner().null_count(), 1);
+    let all: TypedDataFrame<Joined<Orders, Customers>> = left
+        .select_as(Concat((Orders::order_id, Orders::customer_id, Orders::amount), (Customers::id, Customers::region)))
+        .unwrap();
+    assert_eq!(all.width(), 5);
+    
+    let outer = orders.outer_join(&customers, Orders::customer_id, Customers::id).unwrap();
+    assert_eq!(outer.height(), 4);
+    assert_eq!(sorted_ids(outer.inner(), "customer_id"), vec![10, 20, 30]);
+    assert_eq!(sorted_ids(outer.inner(), "id"), vec![10, 20, 40]);
+    assert_eq!(outer.inner().column("order_id").unwrap().null_count(), 1);
+    assert!(outer.revalidate().is_ok());
+}
+
+#[cfg(feature = "asof_join")]
+#[test]
+fn test_join_asof() {
+    typed_polars::define_schema! {
+        Trades {
+            time: i64,
+            size: f64,
+        }
+    }
+    
+    typed_polars::define_schema! {
+        Quotes {
+            time: i64,
+            price: f64,
+        }
+    }
+    
+    typed_polars::define_schema! {
+        PricedTrades {
+            time: i64,
+            size: f64,
+            price: Option<f64>,
+        }
+    }
+    
+    let trades = TypedDataFrame::<Trades>::new(DataFrame::new(vec![
+        Series::new("time".into(), vec![1i64, 5, 10]).into_column(),
+        Series::new("size".into(), vec![1.0, 2.0, 3.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let quotes = TypedDataFrame::<Quotes>::new_lenient(DataFrame::new(vec![
+        Series::new("time".into(), vec![None, Some(2i64), Some(4), Some(8)]).into_column(),
+        Series::new("price".into(), vec![0.0, 20.0, 40.0, 80.0]).into_column(),
+    ]).unwrap()).unwrap();
+    let trades = trades.sort(Trades::time, false).unwrap();
+    let quotes = quotes.sort(Quotes::time, false).unwrap();
+    assert_eq!(quotes.search_sorted(3i64).unwrap(), 2);
+    assert_eq!(quotes.search_sorted(0i64).unwrap(), 1);
+    
+    let priced: TypedDataFrame<PricedTrades> = trades.join_asof(&quotes, AsofStrategy::Backward).unwrap();
+    let prices: Vec<Option<f64>> = priced.inner().column("price").unwrap().f64().unwrap().into_iter().collect();
+    assert_eq!(prices, vec![None, Some(40.0), Some(80.0)]);
+    
+    let descending = TypedDataFrame::<Quotes>::new_lenient(quotes.inner().clone()).unwrap().sort(Quotes::time, true).unwrap();
+    assert!(trades.join_asof::<Quotes, PricedTrades>(&descending, AsofStrategy::Backward).is_err());
+}
+
+/// A derived schema without `#[schema(row)]`, so its field types need not be row values
+#[cfg(all(feature = "derive", feature = "dtype-full"))]
+#[derive(Schema)]
+struct Segments {
+    id: i64,
+    segment: typed_polars::schema::Categorical,
+}
+
+#[cfg(all(feature = "derive", feature = "dtype-full"))]
+#[test]
+fn test_derive_schema_without_row() {
+    assert_eq!(Segments::column_names(), vec!["id", "segment"]);
+    assert!(matches!(Segments::schema().get("segment"), Some(DataType::Categorical(..))));
+}
+
+#[test]
+fn test_mock_small_integers() {
+    typed_polars::define_schema! {
+        SmallIntegers {
+            tiny: i8,
+            byte: u8,
+        }
+    }
+    
+    // The default range is clamped to what the types can hold
+    let mocked = TypedDataFrame::<SmallIntegers>::mock(200).unwrap();
+    let tiny = mocked.inner().column("tiny").unwrap().i8().unwrap().clone();
+    assert!(tiny.into_no_null_iter().all(|v| v >= 0));
+    let bytes = mocked.inner().column("byte").unwrap().u8().unwrap().clone();
+    assert_eq!(bytes.null_count(), 0);
+}
+
+/// A hand-written schema wide enough to be validated in parallel
+struct WideSchema;
+
+impl WideSchema {
+    const WIDTH: usize = typed_polars::schema::PARALLEL_VALIDATION_COLUMNS + 2;
+    
+    fn names() -> &'static [&'static str] {
+        static NAMES: std::sync::OnceLock<Vec<&'static str>> = std::sync::OnceLock::new();
+        NAMES.get_or_init(|| (0..Self::WIDTH).map(|i| &*Box::leak(format!("c{}", i).into_boxed_str())).collect())
+    }
+}
+
+impl typed_polars::schema::Schema for WideSchema {
+    type Columns = ();
+    
+    fn schema() -> polars::prelude::Schema {
+        polars::prelude::Schema::from_iter(Self::names().iter().map(|name| polars::prelude::Field::new((*name).into(), DataType::Int32)))
+    }
+    
+    fn column_names() -> Vec<&'static str> {
+        Self::names().to_vec()
+    }
+}
+
+#[test]
+fn test_wide_schema_validation() {
+    let columns = |dtype_of: &dyn Fn(usize) -> DataType| -> Vec<polars::prelude::Column> {
+        (0..WideSchema::WIDTH)
+            .map(|i| Series::new(WideSchema::names()[i].into(), vec![1i32, 2]).cast(&dtype_of(i)).unwrap().into_column())
+            .collect()
+    };
+    assert!(TypedDataFrame::<WideSchema>::new(DataFrame::new(columns(&|_| DataType::Int32)).unwrap()).is_ok());
+    
+    // Mismatches are reported in schema order, however the work was split
+    let mistyped = DataFrame::new(columns(&|i| if i % 50 == 3 { DataType::Int64 } else { DataType::Int32 })).unwrap();
+    match WideSchema::check(&mistyped) {
+        Err(TypedPolarsError::Multiple(errors)) => {
+            let names: Vec<_> = errors.iter().map(|err| err.column().unwrap()).collect();
+            assert_eq!(names, vec!["c3", "c53", "c103"]);
+        }
+        other => panic!("expected three mismatches, got {:?}", other),
+    }
+    let missing = DataFrame::new(columns(&|_| DataType::Int32)).unwrap().drop("c129").unwrap();
+    assert!(TypedDataFrame::<WideSchema>::new(missing).is_err());
+}
+
+#[cfg(feature = "sql")]
+#[test]
+fn test_sql_context() {
+    let df = TypedDataFrame::<TestSchema>::new(test_df(&[(1, "a", 10), (2, "b", 20), (3, "c", 30)])).unwrap();
+    
+    let mut ctx = TypedSqlContext::new();
+    ctx.register("test", &df);
+    assert_eq!(ctx.tables(), vec!["test".to_string()]);
+    
+    let summary = ctx.query::<TestSummary>("SELECT id, value FROM test WHERE value > 15").unwrap();
+    assert_eq!(summary.column(TestSummary::id).unwrap().inner().i64().unwrap().into_iter().collect::<Vec<_>>(), vec![Some(2), Some(3)]);
+    
+    // Results that do not match the output schema are rejected
+    assert!(ctx.query::<TestSummary>("SELECT id, name FROM test").is_err());
+    assert!(ctx.query::<TestSummary>("SELECT id, CAST(value AS BIGINT) AS value FROM test").is_err());
+    
+    let lazy = ctx.query_lazy::<TestSchema>("SELECT * FROM test ORDER BY id DESC").unwrap();
+    let sorted = lazy.collect().unwrap();
+    assert_eq!(sorted.column(TestSchema::id).unwrap().inner().i64().unwrap().into_iter().collect::<Vec<_>>(), vec![Some(3), Some(2), Some(1)]);
+    
+    ctx.unregister("test");
+    assert!(ctx.query::<TestSchema>("SELECT * FROM test").is_err());
+}
@@ -0,0 +1,928 @@
+use typed_polars::prelude::*;
+
+typed_polars::define_schema! {
+    IoSchema {
+        id: i64,
+        name: Option<String>,
+        value: i32,
+    }
+}
+
+fn sample_df() -> TypedDataFrame<IoSchema> {
+    let df = DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2, 3]).into_column(),
+        Series::new("name".into(), vec!["a", "b", "c"]).into_column(),
+        Series::new("value".into(), vec![10i32, 20, 30]).into_column(),
+    ]).unwrap();
+    
+    TypedDataFrame::new(df).unwrap()
+}
+
+fn temp_path(file_name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("typed_polars_{}_{}", std::process::id(), file_name))
+}
+
+#[test]
+fn test_json_roundtrip_with_coercion() {
+    let path = temp_path("roundtrip.json");
+    sample_df().write_json(&path).unwrap();
+    
+    // JSON integers come back as Int64, so `value: i32` needs coercion
+    assert!(JsonReader::<IoSchema>::new(&path).finish().is_err());
+    
+    let df = JsonReader::<IoSchema>::new(&path)
+        .coerce(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (3, 3));
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_ndjson_unknown_keys() {
+    let path = temp_path("unknown_keys.ndjson");
+    std::fs::write(
+        &path,
+        "{\"id\":1,\"name\":\"a\",\"value\":10,\"extra\":true}\n{\"id\":2,\"name\":\"b\",\"value\":20,\"extra\":false}\n",
+    ).unwrap();
+    
+    assert!(NdJsonReader::<IoSchema>::new(&path).finish().is_err());
+    
+    let df = NdJsonReader::<IoSchema>::new(&path)
+        .ignore_unknown_keys(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (2, 3));
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_csv_from_bytes() {
+    let csv = b"id,name,value\n1,a,10\n2,b,20\n";
+    let df = CsvReader::<IoSchema>::from_bytes(csv).finish().unwrap();
+    assert_eq!(df.shape(), (2, 3));
+}
+
+#[test]
+fn test_parquet_bytes_roundtrip() {
+    let bytes = ParquetWriter::new(&sample_df()).to_bytes().unwrap();
+    let df = ParquetReader::<IoSchema>::from_bytes(&bytes).finish().unwrap();
+    assert_eq!(df.shape(), (3, 3));
+}
+
+#[test]
+fn test_read_parquet_glob() {
+    let dir = temp_path("glob");
+    std::fs::create_dir_all(&dir).unwrap();
+    sample_df().write_parquet(dir.join("part-0.parquet")).unwrap();
+    sample_df().write_parquet(dir.join("part-1.parquet")).unwrap();
+    
+    let pattern = format!("{}/*.parquet", dir.display());
+    let df = read_parquet_glob::<IoSchema>(&pattern).unwrap();
+    assert_eq!(df.shape(), (6, 3));
+    
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_write_parquet_partitioned() {
+    let dir = temp_path("partitioned");
+    sample_df().write_parquet_partitioned(&dir, IoSchema::name).unwrap();
+    
+    assert!(dir.join("name=a").join("part-0.parquet").exists());
+    assert!(dir.join("name=c").join("part-0.parquet").exists());
+    
+    // A second write adds files instead of overwriting
+    sample_df().write_parquet_partitioned(&dir, IoSchema::name).unwrap();
+    assert!(dir.join("name=a").join("part-1.parquet").exists());
+    
+    // Values cannot leave the dataset directory
+    let escaping = TypedDataFrame::<IoSchema>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2]).into_column(),
+        Series::new("name".into(), vec!["../../etc", ".."]).into_column(),
+        Series::new("value".into(), vec![10i32, 20]).into_column(),
+    ]).unwrap()).unwrap();
+    escaping.write_parquet_partitioned(&dir, IoSchema::name).unwrap();
+    assert!(dir.join("name=..%2F..%2Fetc").join("part-0.parquet").exists());
+    assert!(dir.join("name=%2E%2E").join("part-0.parquet").exists());
+    
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_parquet_reader_projects_schema_columns() {
+    let mut df = sample_df().into_inner();
+    df.with_column(Series::new("extra".into(), vec![1.5f64, 2.5, 3.5])).unwrap();
+    let mut bytes = Vec::new();
+    polars::prelude::ParquetWriter::new(&mut bytes).finish(&mut df).unwrap();
+    
+    let typed = ParquetReader::<IoSchema>::from_bytes(&bytes).finish().unwrap();
+    assert_eq!(typed.width(), 3);
+}
+
+#[test]
+fn test_parquet_reader_with_filter() {
+    let path = temp_path("filtered.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    
+    let df = ParquetReader::<IoSchema>::new(&path)
+        .with_filter(col(IoSchema::value).gt(typed_polars::expr::lit(15i32)))
+        .finish()
+        .unwrap();
+    assert_eq!(df.height(), 2);
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_csv_reader_options() {
+    let csv = b"# exported 2024-01-01\nid;name;value\n1;a;10\n2;NA;20\n3;c;30\n";
+    let df = CsvReader::<IoSchema>::from_bytes(csv)
+        .comment_prefix("#")
+        .separator(b';')
+        .null_values(&["NA"])
+        .n_rows(2)
+        .finish()
+        .unwrap();
+    assert_eq!(df.height(), 2);
+    assert_eq!(df.inner().column("name").unwrap().null_count(), 1);
+}
+
+typed_polars::define_schema! {
+    EventSchema {
+        id: i64,
+        #[format = "%d/%m/%Y"]
+        day: typed_polars::chrono::NaiveDate,
+    }
+}
+
+#[test]
+fn test_csv_reader_parses_declared_date_format() {
+    let csv = b"id,day\n1,31/01/2024\n2,29/02/2024\n";
+    let df = CsvReader::<EventSchema>::from_bytes(csv).finish().unwrap();
+    
+    let days = df.column(EventSchema::day).unwrap();
+    assert_eq!(days.inner().dtype(), &DataType::Date);
+    assert_eq!(days.inner().null_count(), 0);
+}
+
+#[test]
+fn test_scan_parquet_collects_typed_frame() {
+    let path = temp_path("scan.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    
+    let df = scan_parquet::<IoSchema>(&path)
+        .unwrap()
+        .filter(col(IoSchema::id).lt_eq(typed_polars::expr::lit(2i64)))
+        .collect()
+        .unwrap();
+    assert_eq!(df.height(), 2);
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_sink_parquet_from_scan() {
+    let source = temp_path("sink_source.parquet");
+    let target = temp_path("sink_target.parquet");
+    sample_df().write_parquet(&source).unwrap();
+    
+    scan_parquet::<IoSchema>(&source).unwrap().sink_parquet(&target).unwrap();
+    let df = ParquetReader::<IoSchema>::new(&target).finish().unwrap();
+    assert_eq!(df.shape(), (3, 3));
+    
+    std::fs::remove_file(&source).ok();
+    std::fs::remove_file(&target).ok();
+}
+
+#[test]
+fn test_csv_batched_reader() {
+    let mut csv = String::from("id,name,value\n");
+    for i in 0..1000 {
+        csv.push_str(&format!("{},n{},{}\n", i, i, i));
+    }
+    
+    let mut rows = 0;
+    for batch in CsvReader::<IoSchema>::from_bytes(csv.as_bytes()).batched(100).unwrap() {
+        rows += batch.unwrap().height();
+    }
+    assert_eq!(rows, 1000);
+}
+
+#[test]
+fn test_csv_append_writes_header_once() {
+    let path = temp_path("append.csv");
+    std::fs::remove_file(&path).ok();
+    
+    CsvWriter::new(&sample_df()).append(true).finish(&path).unwrap();
+    CsvWriter::new(&sample_df()).append(true).finish(&path).unwrap();
+    
+    let df = CsvReader::<IoSchema>::new(&path).finish().unwrap();
+    assert_eq!(df.height(), 6);
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_parquet_coercion_report() {
+    let mut df = sample_df().into_inner();
+    df.with_column(Series::new("value".into(), vec![10i64, 20, 30])).unwrap();
+    let mut bytes = Vec::new();
+    polars::prelude::ParquetWriter::new(&mut bytes).finish(&mut df).unwrap();
+    
+    assert!(ParquetReader::<IoSchema>::from_bytes(&bytes).finish().is_err());
+    
+    let (typed, coercions) = ParquetReader::<IoSchema>::from_bytes(&bytes)
+        .coerce(true)
+        .finish_with_report()
+        .unwrap();
+    assert_eq!(typed.height(), 3);
+    assert_eq!(coercions.len(), 1);
+    assert_eq!(coercions[0].column, "value");
+    assert_eq!(coercions[0].from, DataType::Int64);
+    assert_eq!(coercions[0].to, DataType::Int32);
+}
+
+#[test]
+fn test_parquet_validate_metadata() {
+    let path = temp_path("metadata.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    
+    let reader = ParquetReader::<IoSchema>::new(&path).validate_metadata().unwrap();
+    assert_eq!(reader.finish().unwrap().height(), 3);
+    assert!(ParquetReader::<EventSchema>::new(&path).validate_metadata().is_err());
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_record_batch_roundtrip() {
+    let batches = sample_df().to_record_batches();
+    let schema = TypedDataFrame::<IoSchema>::arrow_schema();
+    let df = TypedDataFrame::<IoSchema>::from_record_batches(batches, &schema).unwrap();
+    assert_eq!(df.shape(), (3, 3));
+}
+
+#[test]
+fn test_ffi_roundtrip() {
+    let chunks = sample_df().to_ffi();
+    let df = unsafe { TypedDataFrame::<IoSchema>::from_ffi(chunks) }.unwrap();
+    assert_eq!(df.inner(), sample_df().inner());
+}
+
+#[test]
+fn test_memory_mapped_reads() {
+    let parquet_path = temp_path("mapped.parquet");
+    sample_df().write_parquet(&parquet_path).unwrap();
+    let df = ParquetReader::<IoSchema>::new(&parquet_path)
+        .memory_map(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (3, 3));
+    
+    let ipc_path = temp_path("mapped.arrow");
+    sample_df()
+        .lazy()
+        .sink_ipc(&ipc_path)
+        .unwrap();
+    let df = IpcReader::<IoSchema>::new(&ipc_path)
+        .memory_map(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (3, 3));
+    
+    std::fs::remove_file(&parquet_path).ok();
+    std::fs::remove_file(&ipc_path).ok();
+}
+
+#[test]
+fn test_reader_row_limit_and_progress() {
+    let path = temp_path("progress.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    
+    let df = ParquetReader::<IoSchema>::new(&path).with_n_rows(2).finish().unwrap();
+    assert_eq!(df.height(), 2);
+    
+    let reports = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let sink = reports.clone();
+    let df = ParquetReader::<IoSchema>::new(&path)
+        .on_progress(move |progress| sink.borrow_mut().push(progress))
+        .finish()
+        .unwrap();
+    assert_eq!(df.height(), 3);
+    assert_eq!(
+        reports.borrow().last(),
+        Some(&ReadProgress { rows_read: 3, total_rows: Some(3) })
+    );
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_infer_schema_code() {
+    let path = temp_path("inferred_events.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    
+    let code = infer_schema_code(&path).unwrap();
+    assert!(code.contains("InferredEventsSchema {"));
+    assert!(code.contains("        id: i64,\n        name: String,\n        value: i32,\n"));
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_project_to_schema() {
+    let csv = b"id,extra,name,value\n1,x,a,10\n2,y,b,20\n";
+    let df = CsvReader::<IoSchema>::from_bytes(csv)
+        .project_to_schema(true)
+        .finish()
+        .unwrap();
+    assert_eq!(df.shape(), (2, 3));
+    
+    let mut wide = sample_df().into_inner();
+    wide.with_column(Series::new("extra".into(), vec![true, false, true])).unwrap();
+    let df = TypedDataFrame::<IoSchema>::new_projected(wide).unwrap();
+    assert_eq!(df.inner().get_column_names_str(), vec!["id", "name", "value"]);
+}
+
+#[test]
+fn test_compressed_csv_roundtrip() {
+    for file_name in ["compressed.csv.gz", "compressed.csv.zst"] {
+        let path = temp_path(file_name);
+        sample_df().write_csv(&path).unwrap();
+        
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(!bytes.starts_with(b"id,name,value"));
+        
+        let df = CsvReader::<IoSchema>::new(&path).finish().unwrap();
+        assert_eq!(df.shape(), (3, 3));
+        
+        std::fs::remove_file(&path).ok();
+    }
+}
+
+#[test]
+fn test_channel_batch_source() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    for _ in 0..3 {
+        sender.send(sample_df().into_inner()).unwrap();
+    }
+    drop(sender);
+    
+    let mut source = ChannelBatchSource::<IoSchema>::new(receiver).max_rows(5);
+    assert_eq!(source.next_batch().unwrap().unwrap().height(), 6);
+    assert_eq!(source.next_batch().unwrap().unwrap().height(), 3);
+    assert!(source.next_batch().is_none());
+}
+
+#[test]
+fn test_manifest_verification() {
+    let path = temp_path("manifest.parquet");
+    ParquetWriter::new(&sample_df()).write_manifest(true).finish(&path).unwrap();
+    let manifest = Manifest::read(&path).unwrap();
+    assert_eq!(manifest.row_count, 3);
+    assert_eq!(manifest.null_counts[1], ("name".to_string(), 0));
+    
+    let df = ParquetReader::<IoSchema>::new(&path).verify_manifest(true).finish().unwrap();
+    assert_eq!(df.height(), 3);
+    
+    // Rewriting the data without a new manifest is caught on read
+    let changed = sample_df().head(Some(2));
+    ParquetWriter::new(&changed).finish(&path).unwrap();
+    let result = ParquetReader::<IoSchema>::new(&path).verify_manifest(true).finish();
+    assert!(result.is_err());
+    
+    std::fs::remove_file(Manifest::sidecar_path(&path)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+}
+
+typed_polars::define_schema! {
+    #[version = 2]
+    IoSchemaV2 {
+        id: i64,
+        name: String,
+        value: i32,
+    }
+}
+
+#[test]
+fn test_schema_fingerprint() {
+    assert_eq!(IoSchema::fingerprint(), "fp1;v1;id:i64;name:str;value:i32");
+    assert_eq!(IoSchemaV2::version(), 2);
+    
+    // Same columns, different version
+    assert_eq!(IoSchemaV2::fingerprint(), "fp1;v2;id:i64;name:str;value:i32");
+}
+
+#[test]
+fn test_fixed_width_reader() {
+    let data = b"ID  NAME      VALUE\n   1alpha        10\r\n   2             20\n   3gamma     30\n";
+    let df = FixedWidthReader::<IoSchema>::from_bytes(data)
+        .field(IoSchema::id, 0..4)
+        .field(IoSchema::name, 4..14)
+        .field(IoSchema::value, 14..19)
+        .skip_rows(1)
+        .finish()
+        .unwrap();
+    
+    assert_eq!(df.height(), 3);
+    let names = df.inner().column("name").unwrap().str().unwrap().clone();
+    assert_eq!(names.get(0), Some("alpha"));
+    assert_eq!(names.get(1), None);
+    let values = df.inner().column("value").unwrap().i32().unwrap().clone();
+    assert_eq!(values.get(2), Some(30));
+    
+    let missing = FixedWidthReader::<IoSchema>::from_bytes(data).field(IoSchema::id, 0..4).finish();
+    assert!(missing.is_err());
+    
+    // A reversed range fails instead of panicking, even past the end of short lines
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = FixedWidthReader::<IoSchema>::from_bytes(data)
+        .field(IoSchema::id, 0..4)
+        .field(IoSchema::name, 40..30)
+        .field(IoSchema::value, 14..19)
+        .finish();
+    assert!(reversed.is_err());
+}
+
+#[test]
+fn test_dataset_writer_limits() {
+    let dir = temp_path("dataset_writer");
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut writer = DatasetWriter::<IoSchema>::new(&dir, DatasetFormat::Csv).unwrap().max_rows(4);
+    writer.write_batches((0..3).map(|_| Ok(sample_df()))).unwrap();
+    let parts = writer.finish().unwrap();
+    
+    assert_eq!(parts.len(), 3);
+    assert!(parts[2].ends_with("part-00002.csv"));
+    let heights: Vec<usize> = parts
+        .iter()
+        .map(|part| CsvReader::<IoSchema>::new(part).finish().unwrap().height())
+        .collect();
+    assert_eq!(heights, vec![4, 4, 1]);
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_ipc_stream_writer() {
+    let mut buffer = Vec::new();
+    let mut writer = IpcStreamWriter::<IoSchema, _>::new(&mut buffer).unwrap();
+    writer.write(&sample_df()).unwrap();
+    writer.write(&sample_df()).unwrap();
+    writer.finish().unwrap();
+    
+    let df = polars::prelude::IpcStreamReader::new(std::io::Cursor::new(buffer)).finish().unwrap();
+    let df = TypedDataFrame::<IoSchema>::new(df).unwrap();
+    assert_eq!(df.height(), 6);
+}
+
+#[test]
+fn test_ipc_stream_writer_multi_chunk() {
+    // Vstacked frames have several chunks; rechunking one column misaligns them
+    let mut stacked = sample_df().into_inner();
+    stacked.vstack_mut(sample_df().inner()).unwrap();
+    let value = stacked.column("value").unwrap().as_materialized_series().rechunk();
+    stacked.with_column(value).unwrap();
+    assert!(stacked.should_rechunk());
+    let stacked = TypedDataFrame::<IoSchema>::new(stacked).unwrap();
+    
+    let mut buffer = Vec::new();
+    let mut writer = IpcStreamWriter::<IoSchema, _>::new(&mut buffer).unwrap();
+    writer.write(&stacked).unwrap();
+    writer.finish().unwrap();
+    
+    let df = polars::prelude::IpcStreamReader::new(std::io::Cursor::new(buffer)).finish().unwrap();
+    assert!(df.equals_missing(stacked.inner()));
+}
+
+#[test]
+fn test_slice_pushdown() {
+    let bytes = ParquetWriter::new(&sample_df()).to_bytes().unwrap();
+    let page = ParquetReader::<IoSchema>::from_bytes(&bytes)
+        .skip_rows(1)
+        .with_n_rows(1)
+        .with_row_index("row", 0)
+        .finish()
+        .unwrap();
+    assert_eq!(page.height(), 1);
+    assert_eq!(page.inner().column("row").unwrap().get(0).unwrap(), AnyValue::UInt32(1));
+    assert_eq!(page.inner().column("id").unwrap().get(0).unwrap(), AnyValue::Int64(2));
+    
+    let csv = CsvWriter::new(&sample_df()).to_bytes().unwrap();
+    let page = CsvReader::<IoSchema>::from_bytes(&csv)
+        .skip_rows_after_header(2)
+        .with_row_index("row", 10)
+        .finish()
+        .unwrap();
+    assert_eq!(page.height(), 1);
+    assert_eq!(page.inner().column("row").unwrap().get(0).unwrap(), AnyValue::UInt32(12));
+    
+    let path = temp_path("slice.parquet");
+    sample_df().write_parquet(&path).unwrap();
+    let df = scan_parquet::<IoSchema>(&path).unwrap().slice(1, 5).collect().unwrap();
+    assert_eq!(df.height(), 2);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_parallel_parquet_reader() {
+    let dir = temp_path("parallel_parquet");
+    let _ = std::fs::remove_dir_all(&dir);
+    let mut appender = ParquetDatasetAppender::<IoSchema>::new(&dir).unwrap();
+    let paths: Vec<_> = (0..5).map(|_| appender.append(&sample_df()).unwrap()).collect();
+    
+    let df = ParallelParquetReader::<IoSchema>::new(&paths).concurrency(2).finish().unwrap();
+    assert_eq!(df.height(), 15);
+    
+    let missing = ParallelParquetReader::<IoSchema>::new([dir.join("missing.parquet")]).finish();
+    assert!(missing.is_err());
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_writers_handle_misaligned_chunks() {
+    // `id` in two chunks, the other columns in one
+    let mut id = Series::new("id".into(), vec![1i64]);
+    id.append(&Series::new("id".into(), vec![2i64, 3])).unwrap();
+    let df = DataFrame::new(vec![
+        id.into_column(),
+        Series::new("name".into(), vec!["a", "b", "c"]).into_column(),
+        Series::new("value".into(), vec![10i32, 20, 30]).into_column(),
+    ]).unwrap();
+    let typed_df = TypedDataFrame::<IoSchema>::new(df).unwrap();
+    
+    let bytes = ParquetWriter::new(&typed_df).row_group_size(2).to_bytes().unwrap();
+    let metadata = polars::prelude::ParquetReader::new(std::io::Cursor::new(bytes.clone()))
+        .get_metadata()
+        .unwrap()
+        .clone();
+    assert_eq!(metadata.row_groups.len(), 2);
+    assert!(ParquetReader::<IoSchema>::from_bytes(&bytes).finish().unwrap().inner().equals(typed_df.inner()));
+    
+    let csv = CsvWriter::new(&typed_df).to_bytes().unwrap();
+    assert_eq!(String::from_utf8(csv).unwrap(), "id,name,value\n1,a,10\n2,b,20\n3,c,30\n");
+    
+    let ipc = IpcWriter::new(&typed_df).to_bytes().unwrap();
+    assert_eq!(IpcReader::<IoSchema>::from_bytes(&ipc).finish().unwrap().height(), 3);
+}
+
+#[test]
+fn test_collect_cached() {
+    let source = temp_path("cache_source.parquet");
+    let cache_dir = temp_path("cache");
+    ParquetWriter::new(&sample_df()).finish(&source).unwrap();
+    let query = || {
+        let scan = scan_parquet::<IoSchema>(&source).unwrap().into_inner();
+        TypedLazyFrame::<IoSchema>::new(scan.filter(polars::prelude::col("value").gt(polars::prelude::lit(15))))
+    };
+    
+    assert_eq!(query().collect_cached(&cache_dir).unwrap().height(), 2);
+    assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+    
+    assert_eq!(query().collect_cached(&cache_dir).unwrap().height(), 2);
+    assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 1);
+    
+    // Rewriting the source misses the cache
+    let mut changed = sample_df().inner().clone();
+    changed.with_column(Series::new("value".into(), vec![0i32, 0, 0])).unwrap();
+    ParquetWriter::new(&TypedDataFrame::<IoSchema>::new(changed).unwrap()).finish(&source).unwrap();
+    assert_eq!(query().collect_cached(&cache_dir).unwrap().height(), 0);
+    assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 2);
+    
+    // Plans over in-memory frames are collected without caching
+    let in_memory = TypedLazyFrame::<IoSchema>::new(sample_df().inner().clone().lazy());
+    assert_eq!(in_memory.collect_cached(&cache_dir).unwrap().height(), 3);
+    assert_eq!(std::fs::read_dir(&cache_dir).unwrap().count(), 2);
+    
+    std::fs::remove_dir_all(&cache_dir).unwrap();
+    std::fs::remove_file(&source).ok();
+}
+
+#[test]
+fn test_checkpoint_resume() {
+    let dir = temp_path("checkpoints");
+    assert!(TypedDataFrame::<IoSchema>::resume("sample", &dir).unwrap().is_none());
+    sample_df().checkpoint("sample", &dir).unwrap();
+    let resumed = TypedDataFrame::<IoSchema>::resume("sample", &dir).unwrap().unwrap();
+    assert!(resumed.inner().equals(sample_df().inner()));
+    assert!(sample_df().checkpoint("../escape", &dir).is_err());
+    
+    // A resumed lazy checkpoint skips everything upstream of it
+    let filtered = TypedLazyFrame::<IoSchema>::new(sample_df().inner().clone().lazy())
+        .filter(typed_polars::expr::col(IoSchema::value).gt(typed_polars::expr::lit(15)));
+    assert_eq!(filtered.checkpoint("filtered", &dir).unwrap().collect().unwrap().height(), 2);
+    let failing = TypedLazyFrame::<IoSchema>::new(sample_df().inner().clone().lazy().select([polars::prelude::col("missing")]));
+    assert_eq!(failing.checkpoint("filtered", &dir).unwrap().collect().unwrap().height(), 2);
+    
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "database")]
+#[test]
+fn test_read_sql_offline() {
+    // Nothing listens on port 1, so the connection fails without a database
+    let url = "postgres://app@127.0.0.1:1/app";
+    assert!(read_sql::<IoSchema>(url, "SELECT id, name, value FROM t").is_err());
+    assert!(read_sql::<IoSchema>("nosuch://localhost/app", "SELECT 1").is_err());
+    
+    // Also from inside another runtime, where a nested block_on would panic
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    assert!(runtime.block_on(async { read_sql::<IoSchema>(url, "SELECT id, name, value FROM t") }).is_err());
+}
+
+#[cfg(feature = "delta")]
+#[test]
+fn test_read_delta_local_table() {
+    // A one-commit table written by hand, so no Delta writer is needed
+    let table = temp_path("delta_table");
+    std::fs::create_dir_all(table.join("_delta_log")).unwrap();
+    ParquetWriter::new(&sample_df()).finish(&table.join("part-0.parquet")).unwrap();
+    let size = std::fs::metadata(table.join("part-0.parquet")).unwrap().len();
+    let schema = r#"{"type":"struct","fields":[{"name":"id","type":"long","nullable":true,"metadata":{}},{"name":"name","type":"string","nullable":true,"metadata":{}},{"name":"value","type":"integer","nullable":true,"metadata":{}}]}"#;
+    let log = [
+        r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}"#.to_string(),
+        format!(
+            r#"{{"metaData":{{"id":"test","format":{{"provider":"parquet","options":{{}}}},"schemaString":{},"partitionColumns":[],"configuration":{{}},"createdTime":0}}}}"#,
+            serde_json_string(schema),
+        ),
+        format!(r#"{{"add":{{"path":"part-0.parquet","partitionValues":{{}},"size":{},"modificationTime":0,"dataChange":true}}}}"#, size),
+    ];
+    std::fs::write(table.join("_delta_log/00000000000000000000.json"), log.join("\n")).unwrap();
+    
+    let uri = table.to_str().unwrap();
+    let read = read_delta::<IoSchema>(uri, DeltaVersion::Latest).unwrap();
+    assert!(read.inner().equals(sample_df().inner()));
+    assert!(read_delta::<IoSchema>(uri, DeltaVersion::Version(1)).is_err());
+    
+    // Also from inside another runtime, where a nested block_on would panic
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    let nested = runtime.block_on(async { read_delta::<IoSchema>(uri, DeltaVersion::Version(0)) }).unwrap();
+    assert_eq!(nested.height(), 3);
+    
+    std::fs::remove_dir_all(&table).unwrap();
+}
+
+/// `value` as a JSON string literal.
+#[cfg(feature = "delta")]
+fn serde_json_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(feature = "flight")]
+#[test]
+fn test_flight_reader_offline() {
+    assert!(FlightReader::<IoSchema>::new("not a uri", "t").finish().is_err());
+    // Nothing listens on port 1, so the connection fails without a server
+    let reader = || FlightReader::<IoSchema>::new("http://127.0.0.1:1", "t").header("authorization", "Bearer x");
+    assert!(reader().batches().is_err());
+    
+    // Also from inside another runtime, where a nested block_on would panic
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    assert!(runtime.block_on(async { reader().finish() }).is_err());
+}
+
+#[cfg(feature = "orc")]
+#[test]
+fn test_orc_reader() {
+    use arrow::array::{Int32Array, Int64Array, RecordBatch, StringArray};
+    use arrow::datatypes::{DataType as ArrowType, Field, Schema as ArrowSchema};
+    use std::sync::Arc;
+    
+    let schema = Arc::new(ArrowSchema::new(vec![
+        Field::new("id", ArrowType::Int64, false),
+        Field::new("name", ArrowType::Utf8, true),
+        Field::new("value", ArrowType::Int32, false),
+        Field::new("extra", ArrowType::Int32, false),
+    ]));
+    let batch = RecordBatch::try_new(schema.clone(), vec![
+        Arc::new(Int64Array::from(vec![1, 2, 3])),
+        Arc::new(StringArray::from(vec!["a", "b", "c"])),
+        Arc::new(Int32Array::from(vec![10, 20, 30])),
+        Arc::new(Int32Array::from(vec![0, 0, 0])),
+    ]).unwrap();
+    let mut bytes = Vec::new();
+    let mut writer = orc_rust::ArrowWriterBuilder::new(&mut bytes, schema).try_build().unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+    
+    // Two batches, so the C Data Interface import runs more than once
+    let read = OrcReader::<IoSchema>::from_bytes(&bytes).batch_size(2).finish().unwrap();
+    assert!(read.inner().equals(sample_df().inner()));
+    assert!(OrcReader::<IoSchema>::from_bytes(b"not orc").finish().is_err());
+}
+
+#[cfg(feature = "sqlite")]
+typed_polars::define_schema! {
+    SqliteEvents {
+        id: i64,
+        day: chrono::NaiveDate,
+        at: chrono::NaiveDateTime,
+        time: chrono::NaiveTime,
+    }
+}
+
+#[cfg(feature = "sqlite")]
+#[test]
+fn test_sqlite_roundtrip() {
+    use polars::prelude::{DateChunked, DatetimeChunked, TimeChunked, TimeUnit};
+    
+    let path = temp_path("roundtrip.sqlite");
+    let day = chrono::NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    let at = day.and_hms_micro_opt(12, 30, 0, 250).unwrap();
+    let df = TypedDataFrame::<SqliteEvents>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![1i64, 2]).into_column(),
+        DateChunked::from_naive_date("day".into(), [day, day]).into_column(),
+        DatetimeChunked::from_naive_datetime("at".into(), [at, at], TimeUnit::Microseconds).into_column(),
+        TimeChunked::from_naive_time("time".into(), [at.time(), at.time()]).into_column(),
+    ]).unwrap()).unwrap();
+    
+    // A quote in the table name is escaped rather than ending the identifier
+    write_sqlite(&df, &path, "odd\"events").unwrap();
+    let read = read_sqlite::<SqliteEvents>(&path, "SELECT * FROM \"odd\"\"events\"").unwrap();
+    assert!(read.inner().equals(df.inner()));
+    
+    let conn = rusqlite::Connection::open(&path).unwrap();
+    let (stored_day, stored_at): (String, String) = conn
+        .query_row("SELECT day, at FROM \"odd\"\"events\" WHERE id = 1", [], |row| Ok((row.get(0)?, row.get(1)?)))
+        .unwrap();
+    assert_eq!(stored_day, "2024-06-01");
+    assert_eq!(stored_at, "2024-06-01T12:30:00.000250");
+    drop(conn);
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[cfg(feature = "excel")]
+typed_polars::define_schema! {
+    ExcelFloatIds {
+        id: f64,
+        name: Option<String>,
+        value: i32,
+    }
+}
+
+#[cfg(feature = "excel")]
+#[test]
+fn test_excel_roundtrip() {
+    let path = temp_path("roundtrip.xlsx");
+    let mut writer = ExcelWriter::new().bold_header(true);
+    writer.add_sheet("Data", &sample_df()).unwrap();
+    writer.finish(&path).unwrap();
+    let read = ExcelReader::<IoSchema>::new(&path).sheet_name("Data").finish().unwrap();
+    assert!(read.inner().equals(sample_df().inner()));
+    
+    // A fractional float in an integer column is rejected, not truncated
+    let floats = TypedDataFrame::<ExcelFloatIds>::new(DataFrame::new(vec![
+        Series::new("id".into(), vec![1.0f64, 2.5]).into_column(),
+        Series::new("name".into(), vec!["a", "b"]).into_column(),
+        Series::new("value".into(), vec![10i32, 20]).into_column(),
+    ]).unwrap()).unwrap();
+    let mut writer = ExcelWriter::new();
+    writer.add_sheet("Data", &floats).unwrap();
+    writer.finish(&path).unwrap();
+    assert!(ExcelReader::<IoSchema>::new(&path).finish().is_err());
+    
+    // Integers beyond 2^53 do not fit an Excel number exactly
+    let mut large = sample_df().into_inner();
+    large.with_column(Series::new("id".into(), vec![1i64, 2, i64::MAX])).unwrap();
+    let large = TypedDataFrame::<IoSchema>::new(large).unwrap();
+    assert!(ExcelWriter::new().add_sheet("Data", &large).is_err());
+    
+    std::fs::remove_file(&path).ok();
+}
+
+/// Serve canned schema registry responses on a local port, recording each request path.
+#[cfg(feature = "avro")]
+fn mock_registry(schema: &str) -> (String, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+    use std::io::{BufRead, BufReader, Write};
+    
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let paths = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let body = format!("{{\"id\": 7, \"schema\": {}}}", serde_json::to_string(schema).unwrap());
+    let recorded = paths.clone();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            recorded.lock().unwrap().push(request_line.split(' ').nth(1).unwrap_or("").to_string());
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body,
+            ).unwrap();
+        }
+    });
+    (url, paths)
+}
+
+#[cfg(feature = "avro")]
+#[test]
+fn test_schema_registry_decode() {
+    use apache_avro::types::Value;
+    
+    let schema = r#"{"type": "record", "name": "Io", "fields": [
+        {"name": "id", "type": "long"},
+        {"name": "name", "type": ["null", "string"]},
+        {"name": "value", "type": "int"}
+    ]}"#;
+    let (url, paths) = mock_registry(schema);
+    let mut registry = SchemaRegistryClient::new(&url);
+    
+    // The subject is one path segment however many slashes it holds
+    registry.check_compatibility::<IoSchema>("io/value").unwrap();
+    assert_eq!(paths.lock().unwrap()[0], "/subjects/io%2Fvalue/versions/latest");
+    
+    let avro = apache_avro::Schema::parse_str(schema).unwrap();
+    let payloads: Vec<Vec<u8>> = (1..=3)
+        .map(|i| {
+            let record = Value::Record(vec![
+                ("id".to_string(), Value::Long(i)),
+                ("name".to_string(), Value::Union(1, Box::new(Value::String(["a", "b", "c"][i as usize - 1].to_string())))),
+                ("value".to_string(), Value::Int(i as i32 * 10)),
+            ]);
+            let mut payload = vec![0, 0, 0, 0, 7];
+            payload.extend(apache_avro::to_avro_datum(&avro, record).unwrap());
+            payload
+        })
+        .collect();
+    let decoded = registry.decode::<IoSchema>(&payloads).unwrap();
+    assert!(decoded.inner().equals(sample_df().inner()));
+    
+    // The writer schema was cached by the compatibility check
+    assert_eq!(paths.lock().unwrap().len(), 1);
+    assert!(registry.decode::<IoSchema>([b"not framed".to_vec()]).is_err());
+    
+    let mismatched = apache_avro::Schema::parse_str(r#"{"type": "record", "name": "Io", "fields": [
+        {"name": "id", "type": "string"}
+    ]}"#).unwrap();
+    assert!(check_avro_schema::<IoSchema>(&mismatched).is_err());
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn test_async_roundtrip() {
+    let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+    runtime.block_on(async {
+        let path = temp_path("async.csv");
+        write_csv_async(&sample_df(), &path).await.unwrap();
+        let read = read_csv_async::<IoSchema>(&path).await.unwrap();
+        assert!(read.inner().equals(sample_df().inner()));
+        std::fs::remove_file(&path).ok();
+        
+        #[cfg(feature = "parquet")]
+        {
+            let path = temp_path("async.parquet");
+            write_parquet_async(&sample_df(), &path).await.unwrap();
+            let read = read_parquet_async::<IoSchema>(&path).await.unwrap();
+            assert!(read.inner().equals(sample_df().inner()));
+            std::fs::remove_file(&path).ok();
+        }
+        
+        assert!(read_csv_async::<IoSchema>(temp_path("missing.csv")).await.is_err());
+    });
+}
+
+#[test]
+fn test_reader_errors_name_the_source() {
+    let path = temp_path("missing_column.csv");
+    std::fs::write(&path, "id,value\n1,10\n").unwrap();
+    
+    // Coercion reads the columns the file has, so validation sees the gap
+    let err = CsvReader::<IoSchema>::new(&path).coerce(true).finish().unwrap_err();
+    assert!(matches!(err, PolarsError::ColumnNotFound(_)));
+    assert!(err.to_string().contains(&format!("{}: Column 'name' not found in DataFrame", path.display())));
+    
+    let err = CsvReader::<IoSchema>::from_bytes(b"id,value\n1,10\n").coerce(true).finish().unwrap_err();
+    assert!(err.to_string().contains("in-memory bytes: Column 'name' not found"));
+    
+    std::fs::remove_file(&path).ok();
+}
+
+#[test]
+fn test_coerce_float_to_int() {
+    let (df, coercions) = CsvReader::<IoSchema>::from_bytes(b"id,name,value\n1,a,10.0\n2,b,20.0\n")
+        .coerce(true)
+        .finish_with_report()
+        .unwrap();
+    assert_eq!(df.inner().column("value").unwrap().i32().unwrap().get(1), Some(20));
+    assert_eq!(coercions[0].from, DataType::Float64);
+    
+    // Non-integral floats are rejected rather than truncated
+    let err = CsvReader::<IoSchema>::from_bytes(b"id,name,value\n1,a,10.0\n2,b,20.5\n")
+        .coerce(true)
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("non-integral value 20.5"));
+}
@@ -0,0 +1,290 @@
+//! `#[derive(Schema)]` for typed_polars.
+//!
+//! Use it through the `derive` feature of `typed_polars`, which re-exports
+//! the macro next to the `Schema` trait.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Expr, Fields, Ident, LitInt, LitStr, RangeLimits, Type};
+
+/// Implement `Schema` for a struct with named fields, one column per field.
+///
+/// `Option<T>` fields are nullable columns. The struct also gets a const
+/// column accessor per field, like `define_schema!`.
+///
+/// Doc comments on the struct and its fields end up in the data dictionary.
+/// The `#[schema(...)]` attribute takes, on the struct:
+///
+/// - `version = N`: the schema version
+/// - `row`: also implement `Row`, so the struct is the row type of its own
+///   frames and `TypedDataFrame::from_rows` and `to_rows` convert between
+///   the two; every field type must then implement `RowValue`
+///
+/// and on fields:
+///
+/// - `rename = "name"`: the column name, if not the field name
+/// - `format = "%Y-%m-%d"`: the text format of a temporal column
+/// - `range = 0 ..= 130`: the allowed values of a numeric column
+/// - `pii(hash)`, `pii(truncate = 3)`, `pii(redact)`, `pii(bucket = 10)`: how to anonymize the column
+///
+/// # Example
+///
+/// ```ignore
+/// /// Registered users
+/// #[derive(Schema)]
+/// #[schema(version = 2, row)]
+/// struct User {
+///     id: i64,
+///     #[schema(pii(hash))]
+///     email: String,
+///     /// Age in years
+///     #[schema(range = 0 ..= 130)]
+///     age: i32,
+/// }
+///
+/// let users = TypedDataFrame::<User>::new(df)?;
+/// let ages = users.column(User::age)?;
+/// let rows: Vec<User> = users.to_rows()?;
+/// ```
+#[proc_macro_derive(Schema, attributes(schema))]
+pub fn derive_schema(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// A field of the struct with its column attributes.
+struct ColumnField {
+    ident: Ident,
+    ty: Type,
+    name: String,
+    docs: Vec<LitStr>,
+    format: Option<LitStr>,
+    range: Option<(Expr, Expr)>,
+    pii: Option<TokenStream2>,
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&input.generics, "Schema cannot be derived for generic structs"));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => return Err(syn::Error::new_spanned(name, "Schema can only be derived for structs with named fields")),
+        },
+        _ => return Err(syn::Error::new_spanned(name, "Schema can only be derived for structs")),
+    };
+    
+    let mut version: Option<LitInt> = None;
+    let mut row = false;
+    for attr in schema_attrs(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("version") {
+                version = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("row") {
+                row = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `version = N` or `row`"))
+            }
+        })?;
+    }
+    
+    let columns = fields
+        .iter()
+        .map(|field| column_field(field.ident.clone().expect("named field"), field.ty.clone(), &field.attrs))
+        .collect::<syn::Result<Vec<_>>>()?;
+    
+    let schema_docs = docs(&input.attrs);
+    let idents: Vec<&Ident> = columns.iter().map(|column| &column.ident).collect();
+    let types: Vec<&Type> = columns.iter().map(|column| &column.ty).collect();
+    let names: Vec<&String> = columns.iter().map(|column| &column.name).collect();
+    let field_docs: Vec<&Vec<LitStr>> = columns.iter().map(|column| &column.docs).collect();
+    let formats = columns.iter().filter_map(|column| {
+        let (name, format) = (&column.name, column.format.as_ref()?);
+        Some(quote! { (#name, #format) })
+    });
+    let ranges = columns.iter().filter_map(|column| {
+        let (name, (min, max)) = (&column.name, column.range.as_ref()?);
+        Some(quote! { (#name, (#min) as f64, (#max) as f64) })
+    });
+    let pii = columns.iter().filter_map(|column| {
+        let (name, strategy) = (&column.name, column.pii.as_ref()?);
+        Some(quote! { (#name, #strategy) })
+    });
+    let version = version.map(|version| quote! {
+        fn version() -> u32 {
+            #version
+        }
+    });
+    let values: Vec<Ident> = idents.iter().map(|ident| format_ident!("column_{}", ident)).collect();
+    let row = row.then(|| quote! {
+        impl ::typed_polars::row::Row for #name {
+            fn into_frame(rows: Vec<Self>) -> polars::prelude::PolarsResult<polars::prelude::DataFrame> {
+                #(let mut #values = Vec::with_capacity(rows.len());)*
+                for row in rows {
+                    #(#values.push(row.#idents);)*
+                }
+                polars::prelude::DataFrame::new(vec![
+                    #(::typed_polars::row::write_column(#names, #values),)*
+                ])
+            }
+            
+            fn from_frame(df: &polars::prelude::DataFrame) -> polars::prelude::PolarsResult<Vec<Self>> {
+                #(let mut #values = ::typed_polars::row::read_column::<#types>(df, #names)?.into_iter();)*
+                Ok((0..df.height())
+                    .map(|_| Self {
+                        #(#idents: #values.next().expect("columns have the frame's height"),)*
+                    })
+                    .collect())
+            }
+        }
+    });
+    
+    Ok(quote! {
+        impl ::typed_polars::schema::Schema for #name {
+            type Columns = (#(::typed_polars::schema::Column<#types>,)*);
+            
+            const COLUMN_TYPES: &'static [(&'static str, &'static str)] = Self::__COLUMN_TYPES;
+            
+            fn schema() -> polars::prelude::Schema {
+                use ::typed_polars::schema::ColumnType;
+                
+                polars::prelude::Schema::from_iter(vec![
+                    #(polars::prelude::Field::new(#names.into(), <#types>::data_type()),)*
+                ])
+            }
+            
+            fn column_names() -> Vec<&'static str> {
+                vec![#(#names),*]
+            }
+            
+            fn column_formats() -> Vec<(&'static str, &'static str)> {
+                vec![#(#formats),*]
+            }
+            
+            fn column_ranges() -> Vec<(&'static str, f64, f64)> {
+                vec![#(#ranges),*]
+            }
+            
+            fn column_pii() -> Vec<(&'static str, ::typed_polars::anonymize::PiiStrategy)> {
+                vec![#(#pii),*]
+            }
+            
+            fn nullable_columns() -> Vec<&'static str> {
+                vec![
+                    #((#names, <#types as ::typed_polars::schema::ColumnType>::NULLABLE),)*
+                ]
+                .into_iter()
+                .filter(|(_, nullable)| *nullable)
+                .map(|(name, _)| name)
+                .collect()
+            }
+            
+            fn description() -> String {
+                ::typed_polars::schema::doc_text(concat!(#(#schema_docs, "\n",)*))
+            }
+            
+            fn column_docs() -> Vec<(&'static str, String)> {
+                vec![
+                    #((#names, ::typed_polars::schema::doc_text(concat!(#(#field_docs, "\n",)*))),)*
+                ]
+                .into_iter()
+                .filter(|(_, doc)| !doc.is_empty())
+                .collect()
+            }
+            
+            #version
+        }
+        
+        #[allow(non_upper_case_globals)]
+        impl #name {
+            #(
+                #(#[doc = #field_docs])*
+                pub const #idents: ::typed_polars::schema::Column<#types> = ::typed_polars::schema::Column::new(#names);
+            )*
+            
+            /// Column names and types as written, for compile-time schema assertions
+            #[doc(hidden)]
+            pub const __COLUMN_TYPES: &'static [(&'static str, &'static str)] = &[
+                #((#names, stringify!(#types)),)*
+            ];
+        }
+        
+        #row
+    })
+}
+
+fn column_field(ident: Ident, ty: Type, attrs: &[Attribute]) -> syn::Result<ColumnField> {
+    let mut column = ColumnField {
+        name: ident.to_string(),
+        ident,
+        ty,
+        docs: docs(attrs),
+        format: None,
+        range: None,
+        pii: None,
+    };
+    for attr in schema_attrs(attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                column.name = meta.value()?.parse::<LitStr>()?.value();
+            } else if meta.path.is_ident("format") {
+                column.format = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("range") {
+                match meta.value()?.parse::<Expr>()? {
+                    Expr::Range(range) if matches!(range.limits, RangeLimits::Closed(_)) => match (range.start, range.end) {
+                        (Some(min), Some(max)) => column.range = Some((*min, *max)),
+                        _ => return Err(meta.error("expected `range = min ..= max`")),
+                    },
+                    _ => return Err(meta.error("expected `range = min ..= max`")),
+                }
+            } else if meta.path.is_ident("pii") {
+                meta.parse_nested_meta(|strategy| {
+                    let strategy_tokens = if strategy.path.is_ident("hash") {
+                        quote! { ::typed_polars::anonymize::PiiStrategy::Hash }
+                    } else if strategy.path.is_ident("redact") {
+                        quote! { ::typed_polars::anonymize::PiiStrategy::Redact }
+                    } else if strategy.path.is_ident("truncate") {
+                        let length: LitInt = strategy.value()?.parse()?;
+                        quote! { ::typed_polars::anonymize::PiiStrategy::Truncate(#length) }
+                    } else if strategy.path.is_ident("bucket") {
+                        let width: Expr = strategy.value()?.parse()?;
+                        quote! { ::typed_polars::anonymize::PiiStrategy::Bucket((#width) as f64) }
+                    } else {
+                        return Err(strategy.error("expected `hash`, `redact`, `truncate = N` or `bucket = W`"));
+                    };
+                    column.pii = Some(strategy_tokens);
+                    Ok(())
+                })?;
+            } else {
+                return Err(meta.error("expected `rename`, `format`, `range` or `pii`"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(column)
+}
+
+/// The `#[schema(...)]` attributes.
+fn schema_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("schema"))
+}
+
+/// The doc-comment lines, as `define_schema!` collects them.
+fn docs(attrs: &[Attribute]) -> Vec<LitStr> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(doc) => match &doc.value {
+                Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(text), .. }) => Some(text.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}